@@ -0,0 +1,89 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{ArrayLike, ArrayLikeMut};
+use crate::error::PhpError;
+
+/// Split an array into chunks
+///
+/// # Description
+///
+/// Chunks an array into arrays with length elements. The last chunk may contain less than length
+/// elements.
+///
+/// When preserve_keys is true, the keys of the array are preserved. Otherwise every chunk is
+/// reindexed numerically, string keys included.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if length is 0.
+///
+/// # Examples
+///
+/// Example #1 array_chunk() example
+///
+/// ```
+/// use phpify::array::{array_chunk, Key, PhpArray};
+///
+/// let input = PhpArray::from(vec!["a", "b", "c", "d", "e"]);
+///
+/// let chunks = array_chunk(&input, 2, false).unwrap();
+/// assert_eq!(chunks, [PhpArray::from(vec!["a", "b"]), PhpArray::from(vec!["c", "d"]), PhpArray::from(vec!["e"])]);
+///
+/// let chunks = array_chunk(&input, 2, true).unwrap();
+/// assert_eq!(chunks[1].keys().collect::<Vec<&Key>>(), [&Key::Int(2), &Key::Int(3)]);
+/// ```
+pub fn array_chunk<A>(array: &A, length: usize, preserve_keys: bool) -> Result<Vec<A::Mapped<A::Value>>, PhpError>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: From<usize>,
+        A::Value: Clone {
+
+    if length == 0 {
+        return Err(PhpError::ValueError("array_chunk(): Argument #2 ($length) must be greater than 0".to_string()));
+    }
+
+    let entries: Vec<(A::Key, &A::Value)> = array.entries().collect();
+
+    Ok(entries.chunks(length)
+        .map(|chunk| {
+            <A::Mapped<A::Value>>::from_entries(chunk.iter().enumerate().map(|(index, (key, value))| {
+                let key = if preserve_keys { key.clone() } else { A::Key::from(index) };
+                (key, (*value).clone())
+            }))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_chunk, Key, PhpArray};
+    use crate::error::PhpError;
+
+    #[test]
+    fn test() {
+        assert_eq!(array_chunk(&vec![1, 2, 3], 5, false), Ok(vec![vec![1, 2, 3]]));
+        assert_eq!(array_chunk(&Vec::<i32>::new(), 1, false), Ok(vec![]));
+        assert_eq!(array_chunk(&vec![1], 0, false), Err(PhpError::ValueError("array_chunk(): Argument #2 ($length) must be greater than 0".to_string())));
+
+        let array: PhpArray<i32> = vec![("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+        let chunks = array_chunk(&array, 2, false).unwrap();
+        assert_eq!(chunks[0].keys().collect::<Vec<&Key>>(), [&Key::Int(0), &Key::Int(1)]);
+        let chunks = array_chunk(&array, 2, true).unwrap();
+        assert_eq!(chunks[1].keys().collect::<Vec<&Key>>(), [&Key::from("c")]);
+    }
+}
@@ -0,0 +1,318 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::convert::TryFrom;
+
+use crate::array::{ArrayLike, Key, PhpArray};
+use crate::error::PhpError;
+use crate::value::{Object, ToValue, Value};
+
+/// A row from which array_column() can pull a column of values.
+///
+/// # Description
+///
+/// Rows are usually arrays, whose fields are their elements, or objects, whose fields are their
+/// public properties. Implement the trait for a struct to use a vector of records as a result set.
+///
+/// # Examples
+///
+/// Example #1 Implementing Record for a struct
+///
+/// ```
+/// use phpify::array::{array_column, Key, Record};
+/// use phpify::value::Value;
+///
+/// struct User {
+///     id: Value,
+///     name: Value,
+/// }
+///
+/// impl Record for User {
+///     type Field = Value;
+///
+///     fn field(&self, key: &Key) -> Option<&Value> {
+///         match key {
+///             Key::Str(name) if name == "id" => Some(&self.id),
+///             Key::Str(name) if name == "name" => Some(&self.name),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let users = vec![
+///     User { id: Value::from(3), name: Value::from("Peter") },
+///     User { id: Value::from(5), name: Value::from("Mary") },
+/// ];
+///
+/// assert_eq!(array_column(&users, "name"), [Value::from("Peter"), Value::from("Mary")]);
+/// ```
+pub trait Record {
+    /// The type of the fields.
+    type Field;
+
+    /// Returns the field stored under the key, if there is one.
+    fn field(&self, key: &Key) -> Option<&Self::Field>;
+}
+
+impl<V> Record for PhpArray<V> {
+    type Field = V;
+
+    fn field(&self, key: &Key) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<V> Record for Vec<V> {
+    type Field = V;
+
+    fn field(&self, key: &Key) -> Option<&V> {
+        match key {
+            Key::Int(int) => usize::try_from(*int).ok().and_then(|index| self.get(index)),
+            Key::Str(_) => None,
+        }
+    }
+}
+
+impl Record for Object {
+    type Field = Value;
+
+    fn field(&self, key: &Key) -> Option<&Value> {
+        self.get(key)
+    }
+}
+
+/// Arrays are rows of their elements, objects of their properties. Other values have no fields.
+impl Record for Value {
+    type Field = Value;
+
+    fn field(&self, key: &Key) -> Option<&Value> {
+        match self {
+            Value::Array(array) => array.get(key),
+            Value::Object(object) => object.get(key),
+            _ => None,
+        }
+    }
+}
+
+/// Return the values from a single column in the input array
+///
+/// # Description
+///
+/// array_column() returns the values from a single column of the array, identified by the
+/// column_key. Rows that do not have the column are skipped.
+///
+/// The rows may be arrays, objects or any other type implementing Record. For objects, the public
+/// properties are used.
+///
+/// # Examples
+///
+/// Example #1 Get the column of first names from a recordset
+///
+/// ```
+/// use phpify::array::{array_column, PhpArray};
+///
+/// let records: Vec<PhpArray<&str>> = vec![
+///     vec![("id", "2135"), ("first_name", "John"), ("last_name", "Doe")].into_iter().collect(),
+///     vec![("id", "3245"), ("first_name", "Sally"), ("last_name", "Smith")].into_iter().collect(),
+///     vec![("id", "5342"), ("first_name", "Jane"), ("last_name", "Jones")].into_iter().collect(),
+/// ];
+///
+/// assert_eq!(array_column(&records, "first_name"), ["John", "Sally", "Jane"]);
+/// ```
+///
+/// Example #2 Get the column of usernames from the public "username" property of an object
+///
+/// ```
+/// use phpify::array::array_column;
+/// use phpify::value::{Object, Value};
+///
+/// let mut user = Object::new("User");
+/// user.set("username", "user 1");
+///
+/// assert_eq!(array_column(&vec![user], "username"), [Value::from("user 1")]);
+/// ```
+pub fn array_column<A, K>(array: &A, column_key: K) -> Vec<<A::Value as Record>::Field>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Record,
+        <A::Value as Record>::Field: Clone,
+        K: Into<Key> {
+
+    let column_key = column_key.into();
+
+    array.entries()
+        .filter_map(|(_, row)| row.field(&column_key).cloned())
+        .collect()
+}
+
+/// Return the values from a single column in the input array, indexed by another column
+///
+/// # Description
+///
+/// Like array_column(), but the values are stored under the value of the index_key column of their
+/// row. Rows that do not have the index column get the next free integer key.
+///
+/// Index values are converted to keys like array offsets: null becomes the empty string, and bools
+/// and floats are cast to integers.
+///
+/// # Errors
+///
+/// Returns PhpError::TypeError if an index value is an array or object.
+///
+/// # Examples
+///
+/// Example #1 Get the column of last names from a recordset, indexed by the "id" column
+///
+/// ```
+/// use phpify::array::{array_column_indexed, Key, PhpArray};
+///
+/// let records: Vec<PhpArray<&str>> = vec![
+///     vec![("id", "2135"), ("first_name", "John"), ("last_name", "Doe")].into_iter().collect(),
+///     vec![("id", "3245"), ("first_name", "Sally"), ("last_name", "Smith")].into_iter().collect(),
+/// ];
+///
+/// let last_names = array_column_indexed(&records, "last_name", "id").unwrap();
+///
+/// assert_eq!(last_names.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::Int(2135), "Doe"), (Key::Int(3245), "Smith")]);
+/// ```
+pub fn array_column_indexed<A, K, I>(array: &A, column_key: K, index_key: I) -> Result<PhpArray<<A::Value as Record>::Field>, PhpError>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Record,
+        <A::Value as Record>::Field: Clone + ToValue,
+        K: Into<Key>,
+        I: Into<Key> {
+
+    let column_key = column_key.into();
+    let index_key = index_key.into();
+
+    index_rows(array, &index_key, |row| row.field(&column_key).cloned())
+}
+
+/// Return the complete rows of the input array, indexed by one of their columns
+///
+/// # Description
+///
+/// Like array_column() with a null column_key: the rows themselves are returned, stored under the
+/// value of their index_key column. Rows that do not have the index column get the next free
+/// integer key.
+///
+/// # Errors
+///
+/// Returns PhpError::TypeError if an index value is an array or object.
+///
+/// # Examples
+///
+/// Example #1 Index a recordset by the "id" column
+///
+/// ```
+/// use phpify::array::{array_column_rows, PhpArray};
+///
+/// let records: Vec<PhpArray<&str>> = vec![
+///     vec![("id", "a1"), ("name", "John")].into_iter().collect(),
+///     vec![("id", "b2"), ("name", "Sally")].into_iter().collect(),
+/// ];
+///
+/// let by_id = array_column_rows(&records, "id").unwrap();
+///
+/// assert_eq!(by_id["b2"]["name"], "Sally");
+/// ```
+pub fn array_column_rows<A, I>(array: &A, index_key: I) -> Result<PhpArray<A::Value>, PhpError>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Record + Clone,
+        <A::Value as Record>::Field: ToValue,
+        I: Into<Key> {
+
+    let index_key = index_key.into();
+
+    index_rows(array, &index_key, |row| Some(row.clone()))
+}
+
+fn index_rows<A, T, F>(array: &A, index_key: &Key, mut value_of: F) -> Result<PhpArray<T>, PhpError>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Record,
+        <A::Value as Record>::Field: ToValue,
+        F: FnMut(&A::Value) -> Option<T> {
+
+    let mut result = PhpArray::new();
+
+    for (_, row) in array.entries() {
+        let value = match value_of(row) {
+            Some(value) => value,
+            None => continue,
+        };
+
+        match row.field(index_key).map(|index| index.to_value()) {
+            Some(index) => match index.to_key() {
+                Some(key) => {
+                    result.insert(key, value);
+                }
+                None => {
+                    let type_name = match &index {
+                        Value::Object(object) => object.class_name().to_string(),
+                        _ => "array".to_string(),
+                    };
+
+                    return Err(PhpError::TypeError(format!("Cannot access offset of type {} on array", type_name)));
+                }
+            },
+            None => {
+                result.push(value);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_column, array_column_indexed, array_column_rows, Key, PhpArray};
+    use crate::error::PhpError;
+    use crate::value::{Object, Value};
+
+    #[test]
+    fn test() {
+        let rows = vec![vec![1, 2], vec![3], vec![5, 6]];
+        assert_eq!(array_column(&rows, 1), [2, 6]);
+        assert_eq!(array_column(&rows, -1), Vec::<i32>::new());
+        assert_eq!(array_column_indexed(&rows, 0, 1).unwrap().into_iter().collect::<Vec<(Key, i32)>>(), [
+            (Key::Int(2), 1),
+            (Key::Int(3), 3),
+            (Key::Int(6), 5),
+        ]);
+
+        let mut object = Object::new("stdClass");
+        object.set("id", 1.9);
+        object.set("name", "a");
+        let mut array = PhpArray::new();
+        array.insert("id", Value::Null);
+        array.insert("name", Value::from("b"));
+        let rows = vec![Value::from(object), Value::from(array), Value::from(7)];
+
+        assert_eq!(array_column(&rows, "name"), [Value::from("a"), Value::from("b")]);
+        let indexed = array_column_indexed(&rows, "name", "id").unwrap();
+        assert_eq!(indexed.keys().collect::<Vec<&Key>>(), [&Key::Int(1), &Key::from("")]);
+        let indexed = array_column_rows(&rows, "id").unwrap();
+        assert_eq!(indexed.keys().collect::<Vec<&Key>>(), [&Key::Int(1), &Key::from(""), &Key::Int(2)]);
+
+        let rows = vec![PhpArray::from(vec![Value::from(PhpArray::new())])];
+        assert_eq!(array_column_rows(&rows, 0), Err(PhpError::TypeError("Cannot access offset of type array on array".to_string())));
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{ArrayLike, Key, PhpArray};
+use crate::error::PhpError;
+use crate::value::ToValue;
+
+/// Creates an array by using one array for keys and another for its values
+///
+/// # Description
+///
+/// Creates an array by using the values from the keys array as keys and the values from the values
+/// array as the corresponding values.
+///
+/// Integer values are used as keys as they are, other values are converted to strings first. When
+/// a key occurs more than once, the last value stored under it wins.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if the number of elements in keys and values does not match.
+///
+/// # Examples
+///
+/// Example #1 A simple array_combine() example
+///
+/// ```
+/// use phpify::array::{array_combine, Key};
+///
+/// let a = vec!["green", "red", "yellow"];
+/// let b = vec!["avocado", "apple", "banana"];
+/// let c = array_combine(&a, &b).unwrap();
+///
+/// assert_eq!(c.into_iter().collect::<Vec<(Key, &str)>>(), [
+///     (Key::from("green"), "avocado"),
+///     (Key::from("red"), "apple"),
+///     (Key::from("yellow"), "banana"),
+/// ]);
+/// ```
+pub fn array_combine<K, V>(keys: &K, values: &V) -> Result<PhpArray<V::Value>, PhpError>
+    where
+        K: ArrayLike + ?Sized,
+        K::Value: ToValue,
+        V: ArrayLike + ?Sized,
+        V::Value: Clone {
+
+    if keys.len() != values.len() {
+        return Err(PhpError::ValueError("array_combine(): Argument #1 ($keys) and argument #2 ($values) must have the same number of elements".to_string()));
+    }
+
+    Ok(keys.entries()
+        .zip(values.entries())
+        .map(|((_, key), (_, value))| (Key::from_value(&key.to_value()), value.clone()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_combine, Key, PhpArray};
+    use crate::error::PhpError;
+
+    #[test]
+    fn test() {
+        let keys = PhpArray::from(vec![1, 2, 1]);
+        let array = array_combine(&keys, &vec!["a", "b", "c"]).unwrap();
+        assert_eq!(array.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::Int(1), "c"), (Key::Int(2), "b")]);
+
+        assert_eq!(array_combine(&Vec::<i32>::new(), &Vec::<i32>::new()), Ok(PhpArray::new()));
+        assert_eq!(
+            array_combine(&vec![1, 2], &vec![1]),
+            Err(PhpError::ValueError("array_combine(): Argument #1 ($keys) and argument #2 ($values) must have the same number of elements".to_string())),
+        );
+    }
+}
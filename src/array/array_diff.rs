@@ -0,0 +1,252 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::array::sort::sorted_order;
+use crate::array::{ArrayLike, ArrayLikeMut};
+use crate::string::PhpString;
+use crate::value::ToValue;
+
+/// A user supplied comparison function, as taken by by_user_assoc().
+pub(crate) type Compare<'a, T> = &'a mut dyn FnMut(&T, &T) -> Ordering;
+
+/// Computes the difference of arrays
+///
+/// # Description
+///
+/// Compares array against one or more other arrays and returns the values in array that are not
+/// present in any of the other arrays. Keys in the array are preserved.
+///
+/// Two elements are considered equal if and only if their string representations are equal, i.e.
+/// (string) $elem1 === (string) $elem2. The other arrays are hashed, so the difference is computed
+/// in linear time.
+///
+/// # Examples
+///
+/// Example #1 array_diff() example
+///
+/// ```
+/// use phpify::array::{array_diff, Key, PhpArray};
+///
+/// let array1: PhpArray<&str> = vec![("a", "green"), ("0", "red"), ("1", "blue"), ("2", "red")].into_iter().collect();
+/// let array2: PhpArray<&str> = vec![("b", "green"), ("0", "yellow"), ("1", "red")].into_iter().collect();
+///
+/// let result = array_diff(&array1, &[&array2]);
+///
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::Int(1), "blue")]);
+/// ```
+///
+/// Example #2 array_diff() with mismatched types
+///
+/// ```
+/// use phpify::array::array_diff;
+/// use phpify::value::Value;
+///
+/// let source = vec![Value::from(1), Value::from("2"), Value::from(3.0), Value::from("4")];
+/// let filter = vec![Value::from("1"), Value::from(2), Value::from("3")];
+///
+/// assert_eq!(array_diff(&source, &[&filter]), [Value::from("4")]);
+/// ```
+pub fn array_diff<A>(array: &A, arrays: &[&A]) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue + Clone {
+
+    by_value(array, arrays, false)
+}
+
+/// Keeps the entries whose value, compared as string, is found in none or, when intersect is true,
+/// in all of the other arrays.
+pub(crate) fn by_value<A>(array: &A, arrays: &[&A], intersect: bool) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue + Clone {
+
+    let lookups: Vec<HashSet<PhpString>> = arrays.iter()
+        .map(|other| other.entries().map(|(_, value)| string_of(value)).collect())
+        .collect();
+
+    retain(array, arrays.len(), intersect, |index, _, value| lookups[index].contains(&string_of(value)))
+}
+
+/// Keeps the entries whose key is found in none or all of the other arrays.
+pub(crate) fn by_key<A>(array: &A, arrays: &[&A], intersect: bool) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Hash + Eq,
+        A::Value: Clone {
+
+    let lookups: Vec<HashSet<A::Key>> = arrays.iter()
+        .map(|other| other.entries().map(|(key, _)| key).collect())
+        .collect();
+
+    retain(array, arrays.len(), intersect, |index, key, _| lookups[index].contains(key))
+}
+
+/// Keeps the entries whose key and value, compared as string, are found together in none or all
+/// of the other arrays.
+pub(crate) fn by_assoc<A>(array: &A, arrays: &[&A], intersect: bool) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Hash + Eq,
+        A::Value: ToValue + Clone {
+
+    let lookups: Vec<HashMap<A::Key, PhpString>> = arrays.iter()
+        .map(|other| other.entries().map(|(key, value)| (key, string_of(value))).collect())
+        .collect();
+
+    retain(array, arrays.len(), intersect, |index, key, value| {
+        lookups[index].get(key).is_some_and(|other| *other == string_of(value))
+    })
+}
+
+/// Keeps the entries whose value is found in none or all of the other arrays, comparing values
+/// with compare. The other arrays are sorted with compare, so values are found by binary search.
+pub(crate) fn by_user_value<A, F>(array: &A, arrays: &[&A], intersect: bool, mut compare: F) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone,
+        F: FnMut(&A::Value, &A::Value) -> Ordering {
+
+    let lookups: Vec<Vec<&A::Value>> = arrays.iter()
+        .map(|other| sorted(other.entries().map(|(_, value)| value).collect(), &mut |a: &&A::Value, b: &&A::Value| compare(a, b)))
+        .collect();
+
+    retain(array, arrays.len(), intersect, |index, _, value| {
+        lookups[index].binary_search_by(|other| compare(other, value)).is_ok()
+    })
+}
+
+/// Keeps the entries whose key is found in none or all of the other arrays, comparing keys with
+/// compare.
+pub(crate) fn by_user_key<A, F>(array: &A, arrays: &[&A], intersect: bool, mut compare: F) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone,
+        F: FnMut(&A::Key, &A::Key) -> Ordering {
+
+    let lookups: Vec<Vec<A::Key>> = arrays.iter()
+        .map(|other| sorted(other.entries().map(|(key, _)| key).collect(), &mut compare))
+        .collect();
+
+    retain(array, arrays.len(), intersect, |index, key, _| {
+        lookups[index].binary_search_by(|other| compare(other, key)).is_ok()
+    })
+}
+
+/// Keeps the entries whose key and value are found together in none or all of the other arrays,
+/// comparing values with compare_value and keys with compare_key when given, or else by equality of
+/// the key and the string representation of the value.
+pub(crate) fn by_user_assoc<A>(
+    array: &A,
+    arrays: &[&A],
+    intersect: bool,
+    mut compare_value: Option<Compare<'_, A::Value>>,
+    mut compare_key: Option<Compare<'_, A::Key>>,
+) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Hash + Eq,
+        A::Value: ToValue + Clone {
+
+    if compare_key.is_none() {
+        let lookups: Vec<HashMap<A::Key, &A::Value>> = arrays.iter()
+            .map(|other| other.entries().collect())
+            .collect();
+
+        return retain(array, arrays.len(), intersect, |index, key, value| {
+            match (lookups[index].get(key), compare_value.as_mut()) {
+                (Some(other), Some(compare)) => compare(value, other) == Ordering::Equal,
+                (Some(other), None) => string_of(*other) == string_of(value),
+                (None, _) => false,
+            }
+        });
+    }
+
+    let lookups: Vec<Vec<(A::Key, &A::Value)>> = arrays.iter()
+        .map(|other| other.entries().collect())
+        .collect();
+
+    retain(array, arrays.len(), intersect, |index, key, value| {
+        lookups[index].iter().any(|(other_key, other_value)| {
+            let equal_value = match compare_value.as_mut() {
+                Some(compare) => compare(value, other_value) == Ordering::Equal,
+                None => string_of(*other_value) == string_of(value),
+            };
+
+            equal_value && compare_key.as_mut().is_none_or(|compare| compare(key, other_key) == Ordering::Equal)
+        })
+    })
+}
+
+fn string_of<V>(value: &V) -> PhpString
+    where
+        V: ToValue + ?Sized {
+
+    value.to_value().to_php_string()
+}
+
+fn sorted<T, F>(items: Vec<T>, compare: &mut F) -> Vec<T>
+    where
+        F: FnMut(&T, &T) -> Ordering {
+
+    let order = sorted_order(items.len(), |a, b| compare(&items[a], &items[b]));
+    let mut items: Vec<Option<T>> = items.into_iter().map(Some).collect();
+
+    order.into_iter().filter_map(|index| items[index].take()).collect()
+}
+
+/// Keeps the entries of array that are found in none of the count other arrays or, when intersect
+/// is true, in all of them.
+fn retain<A, F>(array: &A, count: usize, intersect: bool, mut found: F) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone,
+        F: FnMut(usize, &A::Key, &A::Value) -> bool {
+
+    <A::Mapped<A::Value>>::from_entries(array.entries()
+        .filter(|(key, value)| {
+            if intersect {
+                (0..count).all(|index| found(index, key, value))
+            } else {
+                !(0..count).any(|index| found(index, key, value))
+            }
+        })
+        .map(|(key, value)| (key, value.clone())))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_diff, Key, PhpArray};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        assert_eq!(array_diff(&vec![1, 2, 3, 4], &[&vec![2], &vec![4, 5]]), [1, 3]);
+        assert_eq!(array_diff(&vec![1, 2], &[]), [1, 2]);
+
+        let array = vec![Value::from(1.0), Value::from("1"), Value::from(true), Value::from(vec![1])];
+        assert_eq!(array_diff(&array, &[&vec![Value::from(1)]]), [Value::from(vec![1])]);
+
+        let array = PhpArray::from(vec!["a", "b", "c"]);
+        let result = array_diff(&array, &[&PhpArray::from(vec!["b"])]);
+        assert_eq!(result.keys().collect::<Vec<&Key>>(), [&Key::Int(0), &Key::Int(2)]);
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::hash::Hash;
+
+use crate::array::array_diff::by_assoc;
+use crate::array::ArrayLike;
+use crate::value::ToValue;
+
+/// Computes the difference of arrays with additional index check
+///
+/// # Description
+///
+/// Compares array against the other arrays and returns the entries of array that are not
+/// present in any of the other arrays. Unlike array_diff() the array keys are also used in the
+/// comparison.
+///
+/// Two values are considered equal if and only if their string representations are equal.
+///
+/// # Examples
+///
+/// Example #1 array_diff_assoc() example
+///
+/// ```
+/// use phpify::array::{array_diff_assoc, Key, PhpArray};
+///
+/// let array1: PhpArray<&str> = vec![("a", "green"), ("b", "brown"), ("c", "blue"), ("0", "red")].into_iter().collect();
+/// let array2: PhpArray<&str> = vec![("a", "green"), ("0", "yellow"), ("1", "red")].into_iter().collect();
+///
+/// let result = array_diff_assoc(&array1, &[&array2]);
+///
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::from("b"), "brown"), (Key::from("c"), "blue"), (Key::Int(0), "red")]);
+/// ```
+///
+/// Example #2 array_diff_assoc() example
+///
+/// ```
+/// use phpify::array::array_diff_assoc;
+/// use phpify::value::Value;
+///
+/// let data1 = vec![Value::from(0), Value::from(1), Value::from(2)];
+/// let data2 = vec![Value::from("00"), Value::from("01"), Value::from("2")];
+///
+/// assert_eq!(array_diff_assoc(&data1, &[&data2]), [Value::from(0), Value::from(1)]);
+/// ```
+pub fn array_diff_assoc<A>(array: &A, arrays: &[&A]) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Hash + Eq,
+        A::Value: ToValue + Clone {
+
+    by_assoc(array, arrays, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_diff_assoc, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        assert_eq!(array_diff_assoc(&vec![1, 2, 3], &[&vec![1, 3, 2], &vec![0, 0, 3]]), [2]);
+
+        let array1: PhpArray<i32> = vec![("a", 1), ("b", 2)].into_iter().collect();
+        let array2: PhpArray<i32> = vec![("b", 1), ("a", 1)].into_iter().collect();
+        assert_eq!(array_diff_assoc(&array1, &[&array2]).keys().collect::<Vec<&Key>>(), [&Key::from("b")]);
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::hash::Hash;
+
+use crate::array::array_diff::by_key;
+use crate::array::ArrayLike;
+
+/// Computes the difference of arrays using keys for comparison
+///
+/// # Description
+///
+/// Compares the keys from array against the keys from the other arrays and returns the entries
+/// of array whose keys are not present in any of the other arrays. Keys in the array are
+/// preserved.
+///
+/// # Examples
+///
+/// Example #1 array_diff_key() example
+///
+/// ```
+/// use phpify::array::{array_diff_key, Key, PhpArray};
+///
+/// let array1: PhpArray<i32> = vec![("blue", 1), ("red", 2), ("green", 3), ("purple", 4)].into_iter().collect();
+/// let array2: PhpArray<i32> = vec![("green", 5), ("yellow", 7), ("cyan", 8)].into_iter().collect();
+///
+/// let result = array_diff_key(&array1, &[&array2]);
+///
+/// assert_eq!(result.keys().collect::<Vec<&Key>>(), [&Key::from("blue"), &Key::from("red"), &Key::from("purple")]);
+/// ```
+pub fn array_diff_key<A>(array: &A, arrays: &[&A]) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Hash + Eq,
+        A::Value: Clone {
+
+    by_key(array, arrays, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_diff_key, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let array1: PhpArray<i32> = vec![("1", 1), ("a", 2), ("b", 3)].into_iter().collect();
+        let array2: PhpArray<i32> = vec![(1, 9)].into_iter().collect();
+        let array3: PhpArray<i32> = vec![("b", 9)].into_iter().collect();
+        assert_eq!(array_diff_key(&array1, &[&array2, &array3]).into_iter().collect::<Vec<(Key, i32)>>(), [(Key::from("a"), 2)]);
+
+        assert_eq!(array_diff_key(&vec![1, 2, 3], &[&vec![0]]), [2, 3]);
+    }
+}
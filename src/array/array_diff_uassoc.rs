@@ -0,0 +1,68 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use crate::array::array_diff::by_user_assoc;
+use crate::array::ArrayLike;
+use crate::value::ToValue;
+
+/// Computes the difference of arrays with additional index check which is performed by a user supplied callback function
+///
+/// # Description
+///
+/// Compares array against the other arrays and returns the entries of array that are not
+/// present in any of the other arrays. Unlike array_diff() the array keys are used in the
+/// comparison, by the user supplied callback function. The values are compared by their string
+/// representations.
+///
+/// # Examples
+///
+/// Example #1 array_diff_uassoc() example
+///
+/// ```
+/// use phpify::array::{array_diff_uassoc, Key, PhpArray};
+///
+/// let array1: PhpArray<&str> = vec![("a", "green"), ("b", "brown"), ("c", "blue"), ("0", "red")].into_iter().collect();
+/// let array2: PhpArray<&str> = vec![("a", "GREEN"), ("B", "brown"), ("0", "yellow"), ("1", "red")].into_iter().collect();
+///
+/// let result = array_diff_uassoc(&array1, &[&array2], |a, b| a.to_string().to_lowercase().cmp(&b.to_string().to_lowercase()));
+///
+/// assert_eq!(result.keys().collect::<Vec<&Key>>(), [&Key::from("a"), &Key::from("c"), &Key::Int(0)]);
+/// ```
+pub fn array_diff_uassoc<A>(array: &A, arrays: &[&A], mut key_compare_func: impl FnMut(&A::Key, &A::Key) -> Ordering) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Hash + Eq,
+        A::Value: ToValue + Clone {
+
+    by_user_assoc(array, arrays, false, None, Some(&mut key_compare_func))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::array::array_diff_uassoc;
+
+    #[test]
+    fn test() {
+        assert_eq!(array_diff_uassoc(&vec![1, 2, 3], &[&vec![2, 1, 3]], |a, b| a.cmp(b)), [1, 2]);
+        assert_eq!(array_diff_uassoc(&vec![1, 2, 3], &[&vec![2, 1, 3]], |_, _| Ordering::Equal), Vec::<i32>::new());
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+
+use crate::array::array_diff::by_user_key;
+use crate::array::ArrayLike;
+
+/// Computes the difference of arrays using a callback function on the keys for comparison
+///
+/// # Description
+///
+/// Compares the keys from array against the keys from the other arrays and returns the entries
+/// of array whose keys are not present in any of the other arrays. This function is like
+/// array_diff_key() except the comparison is done by a user supplied callback function, which
+/// must define a consistent order.
+///
+/// # Examples
+///
+/// Example #1 array_diff_ukey() example
+///
+/// ```
+/// use phpify::array::{array_diff_ukey, Key, PhpArray};
+///
+/// let array1: PhpArray<i32> = vec![("blue", 1), ("red", 2), ("green", 3), ("purple", 4)].into_iter().collect();
+/// let array2: PhpArray<i32> = vec![("green", 5), ("blue", 6), ("yellow", 7), ("cyan", 8)].into_iter().collect();
+///
+/// let result = array_diff_ukey(&array1, &[&array2], |a, b| a.to_string().cmp(&b.to_string()));
+///
+/// assert_eq!(result.keys().collect::<Vec<&Key>>(), [&Key::from("red"), &Key::from("purple")]);
+/// ```
+pub fn array_diff_ukey<A>(array: &A, arrays: &[&A], key_compare_func: impl FnMut(&A::Key, &A::Key) -> Ordering) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone {
+
+    by_user_key(array, arrays, false, key_compare_func)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_diff_ukey, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let array1: PhpArray<i32> = vec![("A", 1), ("b", 2)].into_iter().collect();
+        let array2: PhpArray<i32> = vec![("a", 3)].into_iter().collect();
+        let result = array_diff_ukey(&array1, &[&array2], |a, b| a.to_string().to_lowercase().cmp(&b.to_string().to_lowercase()));
+        assert_eq!(result.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::from("b"), 2)]);
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::php_array::MAX_SIZE;
+use crate::array::PhpArray;
+use crate::error::PhpError;
+
+/// Fill an array with values
+///
+/// # Description
+///
+/// Fills an array with count entries of the value of the value parameter, keys starting at the
+/// start_index parameter.
+///
+/// If start_index is negative, the first key of the returned array will be start_index and the
+/// following keys will count up from there, as they do since PHP 8.0.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if count is larger than the maximum array size, or if the keys
+/// would exceed the integer range.
+///
+/// # Examples
+///
+/// Example #1 array_fill() example
+///
+/// ```
+/// use phpify::array::{array_fill, Key};
+///
+/// let a = array_fill(5, 6, "banana").unwrap();
+///
+/// assert_eq!(a.keys().cloned().collect::<Vec<Key>>(), (5..11).map(Key::Int).collect::<Vec<Key>>());
+/// assert!(a.values().all(|&value| value == "banana"));
+/// ```
+///
+/// Example #2 array_fill() example with a negative start index
+///
+/// ```
+/// use phpify::array::{array_fill, Key};
+///
+/// let a = array_fill(-5, 4, "pear").unwrap();
+///
+/// assert_eq!(a.keys().collect::<Vec<&Key>>(), [&Key::Int(-5), &Key::Int(-4), &Key::Int(-3), &Key::Int(-2)]);
+/// ```
+pub fn array_fill<V>(start_index: i64, count: usize, value: V) -> Result<PhpArray<V>, PhpError>
+    where
+        V: Clone {
+
+    if count > MAX_SIZE || start_index.checked_add(count as i64).is_none() {
+        return Err(PhpError::ValueError("array_fill(): Argument #2 ($count) is too large".to_string()));
+    }
+
+    Ok((0..count as i64).map(|index| (start_index + index, value.clone())).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_fill, Key, PhpArray};
+    use crate::error::PhpError;
+
+    #[test]
+    fn test() {
+        assert_eq!(array_fill(0, 3, 1), Ok(PhpArray::from(vec![1, 1, 1])));
+        assert_eq!(array_fill(0, 0, 1), Ok(PhpArray::new()));
+
+        let mut array = array_fill(-2, 2, 'a').unwrap();
+        array.push('b');
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::Int(-2), &Key::Int(-1), &Key::Int(0)]);
+
+        assert_eq!(array_fill(0, usize::MAX, 1), Err(PhpError::ValueError("array_fill(): Argument #2 ($count) is too large".to_string())));
+        assert!(array_fill(i64::MAX, 2, 1).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{ArrayLike, Key, PhpArray};
+use crate::value::ToValue;
+
+/// Fill an array with values, specifying keys
+///
+/// # Description
+///
+/// Fills an array with the value of the value parameter, using the values of the keys array as
+/// keys.
+///
+/// Integer values are used as keys as they are, other values are converted to strings first.
+/// Illegal values for a key will be converted to string, so numeric strings become integer keys.
+///
+/// # Examples
+///
+/// Example #1 array_fill_keys() example
+///
+/// ```
+/// use phpify::array::{array_fill_keys, Key};
+/// use phpify::value::Value;
+///
+/// let keys = vec![Value::from("foo"), Value::from(5), Value::from(10), Value::from("bar")];
+/// let a = array_fill_keys(&keys, "banana");
+///
+/// assert_eq!(a.keys().collect::<Vec<&Key>>(), [&Key::from("foo"), &Key::Int(5), &Key::Int(10), &Key::from("bar")]);
+/// assert_eq!(a["foo"], "banana");
+/// ```
+pub fn array_fill_keys<A, V>(keys: &A, value: V) -> PhpArray<V>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue,
+        V: Clone {
+
+    keys.entries()
+        .map(|(_, key)| (Key::from_value(&key.to_value()), value.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_fill_keys, Key, PhpArray};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        let array = array_fill_keys(&vec!["a", "1", "01", "a"], 0);
+        assert_eq!(array.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::from("a"), 0), (Key::Int(1), 0), (Key::from("01"), 0)]);
+
+        let keys = vec![Value::from(true), Value::Null, Value::from(1.5)];
+        let array = array_fill_keys(&keys, 'x');
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::Int(1), &Key::from(""), &Key::from("1.5")]);
+
+        assert_eq!(array_fill_keys(&Vec::<i32>::new(), 0), PhpArray::new());
+    }
+}
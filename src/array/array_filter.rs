@@ -0,0 +1,173 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{ArrayLike, ArrayLikeMut};
+use crate::value::ToValue;
+
+/// Filters elements of an array using a callback function
+///
+/// # Description
+///
+/// Iterates over each value in the array passing them to the callback function. If the callback
+/// function returns true, the current value from array is returned into the result array. Array
+/// keys are preserved.
+///
+/// Use array_filter_key() or array_filter_both() to pass the key to the callback, like the
+/// ARRAY_FILTER_USE_KEY and ARRAY_FILTER_USE_BOTH modes do, and array_filter_truthy() to filter
+/// without a callback.
+///
+/// # Examples
+///
+/// Example #1 array_filter() example
+///
+/// ```
+/// use phpify::array::{array_filter, Key, PhpArray};
+///
+/// let array1: PhpArray<i32> = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5)].into_iter().collect();
+/// let array2 = vec![6, 7, 8, 9, 10, 11, 12];
+///
+/// let odd = array_filter(&array1, |value| value & 1 == 1);
+/// let even = array_filter(&array2, |value| value & 1 == 0);
+///
+/// assert_eq!(odd.keys().collect::<Vec<&Key>>(), [&Key::from("a"), &Key::from("c"), &Key::from("e")]);
+/// assert_eq!(even, [6, 8, 10, 12]);
+/// ```
+pub fn array_filter<A>(array: &A, mut callback: impl FnMut(&A::Value) -> bool) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone {
+
+    filter_entries(array, |_, value| callback(value))
+}
+
+/// Filters elements of an array by key using a callback function
+///
+/// # Description
+///
+/// Like array_filter() with the ARRAY_FILTER_USE_KEY mode: the callback receives the key of each
+/// element instead of the value.
+///
+/// # Examples
+///
+/// Example #1 array_filter_key() example
+///
+/// ```
+/// use phpify::array::{array_filter_key, Key, PhpArray};
+///
+/// let arr: PhpArray<i32> = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)].into_iter().collect();
+///
+/// let result = array_filter_key(&arr, |key| *key == Key::from("b"));
+///
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::from("b"), 2)]);
+/// ```
+pub fn array_filter_key<A>(array: &A, mut callback: impl FnMut(&A::Key) -> bool) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone {
+
+    filter_entries(array, |key, _| callback(key))
+}
+
+/// Filters elements of an array by value and key using a callback function
+///
+/// # Description
+///
+/// Like array_filter() with the ARRAY_FILTER_USE_BOTH mode: the callback receives both the value
+/// and the key of each element.
+///
+/// # Examples
+///
+/// Example #1 array_filter_both() example
+///
+/// ```
+/// use phpify::array::{array_filter_both, Key, PhpArray};
+///
+/// let arr: PhpArray<i32> = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)].into_iter().collect();
+///
+/// let result = array_filter_both(&arr, |value, key| *key == Key::from("b") || *value == 4);
+///
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::from("b"), 2), (Key::from("d"), 4)]);
+/// ```
+pub fn array_filter_both<A>(array: &A, mut callback: impl FnMut(&A::Value, &A::Key) -> bool) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone {
+
+    filter_entries(array, |key, value| callback(value, key))
+}
+
+/// Filters the falsy elements out of an array
+///
+/// # Description
+///
+/// Like array_filter() without a callback: all values of array that are equal to false after
+/// conversion to bool, like 0, "", "0", null and empty arrays, are removed. Array keys are
+/// preserved.
+///
+/// # Examples
+///
+/// Example #1 array_filter_truthy() example
+///
+/// ```
+/// use phpify::array::array_filter_truthy;
+/// use phpify::value::Value;
+///
+/// let entry = vec![Value::from("foo"), Value::from(false), Value::from(-1), Value::Null, Value::from(""), Value::from("0"), Value::from(0)];
+///
+/// assert_eq!(array_filter_truthy(&entry), [Value::from("foo"), Value::from(-1)]);
+/// ```
+pub fn array_filter_truthy<A>(array: &A) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue + Clone {
+
+    filter_entries(array, |_, value| value.to_value().to_bool())
+}
+
+fn filter_entries<A>(array: &A, mut keep: impl FnMut(&A::Key, &A::Value) -> bool) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone {
+
+    <A::Mapped<A::Value>>::from_entries(array.entries()
+        .filter(|(key, value)| keep(key, value))
+        .map(|(key, value)| (key, value.clone())))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_filter, array_filter_both, array_filter_key, array_filter_truthy, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let mut seen = Vec::new();
+        let result = array_filter(&vec![1, 2, 3, 4], |value| { seen.push(*value); *value > 2 });
+        assert_eq!(result, [3, 4]);
+        assert_eq!(seen, [1, 2, 3, 4]);
+
+        let array = PhpArray::from(vec!["a", "b", "c"]);
+        let result = array_filter(&array, |value| *value != "b");
+        assert_eq!(result.keys().collect::<Vec<&Key>>(), [&Key::Int(0), &Key::Int(2)]);
+
+        assert_eq!(array_filter_key(&vec![5, 6, 7], |key| *key != 1), [5, 7]);
+        assert_eq!(array_filter_both(&vec![5, 6, 7], |value, key| *key == 0 || *value == 7), [5, 7]);
+
+        let array: PhpArray<&str> = vec![("a", "0"), ("b", "0.0"), ("c", "")].into_iter().collect();
+        assert_eq!(array_filter_truthy(&array).keys().collect::<Vec<&Key>>(), [&Key::from("b")]);
+        assert_eq!(array_filter_truthy(&vec![vec![0], vec![]]), [vec![0]]);
+    }
+}
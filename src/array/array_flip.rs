@@ -0,0 +1,86 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{ArrayLike, Key, PhpArray};
+use crate::value::{ToValue, Value};
+
+/// Exchanges all keys with their associated values in an array
+///
+/// # Description
+///
+/// array_flip() returns an array in flip order, i.e. keys from array become values and values from
+/// array become keys.
+///
+/// Note that the values of array need to be valid keys, i.e. they need to be either integers or
+/// strings. Other values are skipped, where PHP raises a warning.
+///
+/// If a value has several occurrences, the latest key will be used as its value, and all others
+/// will be lost.
+///
+/// # Examples
+///
+/// Example #1 array_flip() example
+///
+/// ```
+/// use phpify::array::{array_flip, Key};
+///
+/// let input = vec!["oranges", "apples", "pears"];
+/// let flipped = array_flip(&input);
+///
+/// assert_eq!(flipped.into_iter().collect::<Vec<(Key, usize)>>(), [
+///     (Key::from("oranges"), 0),
+///     (Key::from("apples"), 1),
+///     (Key::from("pears"), 2),
+/// ]);
+/// ```
+///
+/// Example #2 array_flip() example: collision
+///
+/// ```
+/// use phpify::array::{array_flip, Key, PhpArray};
+///
+/// let input: PhpArray<i32> = vec![("a", 1), ("b", 1), ("c", 2)].into_iter().collect();
+/// let flipped = array_flip(&input);
+///
+/// assert_eq!(flipped.into_iter().collect::<Vec<(Key, Key)>>(), [(Key::Int(1), Key::from("b")), (Key::Int(2), Key::from("c"))]);
+/// ```
+pub fn array_flip<A>(array: &A) -> PhpArray<A::Key>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue {
+
+    array.entries()
+        .filter_map(|(key, value)| match value.to_value() {
+            Value::Int(int) => Some((Key::Int(int), key)),
+            Value::String(string) => Some((Key::from(string), key)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_flip, Key};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        let array = vec![Value::from("1"), Value::from(1.5), Value::Null, Value::from("x"), Value::from(-3)];
+        let flipped = array_flip(&array);
+        assert_eq!(flipped.into_iter().collect::<Vec<(Key, usize)>>(), [(Key::Int(1), 0), (Key::from("x"), 3), (Key::Int(-3), 4)]);
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::array_diff::by_value;
+use crate::array::ArrayLike;
+use crate::value::ToValue;
+
+/// Computes the intersection of arrays
+///
+/// # Description
+///
+/// Returns the values of array that are present in all the other arrays. Keys in the array are
+/// preserved.
+///
+/// Two elements are considered equal if and only if their string representations are equal. The
+/// other arrays are hashed, so the intersection is computed in linear time.
+///
+/// # Examples
+///
+/// Example #1 array_intersect() example
+///
+/// ```
+/// use phpify::array::{array_intersect, Key, PhpArray};
+///
+/// let array1: PhpArray<&str> = vec![("a", "green"), ("0", "red"), ("1", "blue")].into_iter().collect();
+/// let array2: PhpArray<&str> = vec![("b", "green"), ("0", "yellow"), ("1", "red")].into_iter().collect();
+///
+/// let result = array_intersect(&array1, &[&array2]);
+///
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::from("a"), "green"), (Key::Int(0), "red")]);
+/// ```
+pub fn array_intersect<A>(array: &A, arrays: &[&A]) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue + Clone {
+
+    by_value(array, arrays, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::array_intersect;
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        assert_eq!(array_intersect(&vec![1, 2, 3, 4], &[&vec![4, 3, 2], &vec![2, 4]]), [2, 4]);
+        assert_eq!(array_intersect(&vec![1, 2], &[]), [1, 2]);
+
+        let array = vec![Value::from("1.0"), Value::from(1), Value::from("1")];
+        assert_eq!(array_intersect(&array, &[&vec![Value::from(1.0)]]), [Value::from(1), Value::from("1")]);
+    }
+}
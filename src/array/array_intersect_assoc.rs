@@ -0,0 +1,64 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::hash::Hash;
+
+use crate::array::array_diff::by_assoc;
+use crate::array::ArrayLike;
+use crate::value::ToValue;
+
+/// Computes the intersection of arrays with additional index check
+///
+/// # Description
+///
+/// Returns the entries of array that are present in all the other arrays. Unlike
+/// array_intersect() the keys are also used in the comparison.
+///
+/// Two values are considered equal if and only if their string representations are equal.
+///
+/// # Examples
+///
+/// Example #1 array_intersect_assoc() example
+///
+/// ```
+/// use phpify::array::{array_intersect_assoc, Key, PhpArray};
+///
+/// let array1: PhpArray<&str> = vec![("a", "green"), ("b", "brown"), ("c", "blue"), ("0", "red")].into_iter().collect();
+/// let array2: PhpArray<&str> = vec![("a", "green"), ("b", "yellow"), ("0", "blue"), ("1", "red")].into_iter().collect();
+///
+/// let result = array_intersect_assoc(&array1, &[&array2]);
+///
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::from("a"), "green")]);
+/// ```
+pub fn array_intersect_assoc<A>(array: &A, arrays: &[&A]) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Hash + Eq,
+        A::Value: ToValue + Clone {
+
+    by_assoc(array, arrays, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::array_intersect_assoc;
+
+    #[test]
+    fn test() {
+        assert_eq!(array_intersect_assoc(&vec![1, 2, 3], &[&vec![1, 3, 3], &vec![1, 0, 3]]), [1, 3]);
+    }
+}
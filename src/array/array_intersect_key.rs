@@ -0,0 +1,61 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::hash::Hash;
+
+use crate::array::array_diff::by_key;
+use crate::array::ArrayLike;
+
+/// Computes the intersection of arrays using keys for comparison
+///
+/// # Description
+///
+/// Returns the entries of array whose keys are present in all the other arrays. Keys in the
+/// array are preserved.
+///
+/// # Examples
+///
+/// Example #1 array_intersect_key() example
+///
+/// ```
+/// use phpify::array::{array_intersect_key, Key, PhpArray};
+///
+/// let array1: PhpArray<i32> = vec![("blue", 1), ("red", 2), ("green", 3), ("purple", 4)].into_iter().collect();
+/// let array2: PhpArray<i32> = vec![("green", 5), ("blue", 6), ("yellow", 7), ("cyan", 8)].into_iter().collect();
+///
+/// let result = array_intersect_key(&array1, &[&array2]);
+///
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::from("blue"), 1), (Key::from("green"), 3)]);
+/// ```
+pub fn array_intersect_key<A>(array: &A, arrays: &[&A]) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Hash + Eq,
+        A::Value: Clone {
+
+    by_key(array, arrays, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::array_intersect_key;
+
+    #[test]
+    fn test() {
+        assert_eq!(array_intersect_key(&vec![1, 2, 3], &[&vec![0, 0], &vec![0, 0, 0]]), [1, 2]);
+    }
+}
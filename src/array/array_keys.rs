@@ -0,0 +1,102 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::ArrayLike;
+use crate::value::ToValue;
+
+/// Return all the keys of an array
+///
+/// # Description
+///
+/// array_keys() returns all the keys from the array, in order.
+///
+/// # Examples
+///
+/// Example #1 array_keys() example
+///
+/// ```
+/// use phpify::array::{array_keys, Key, PhpArray};
+///
+/// let mut array = PhpArray::new();
+/// array.insert(0, 100);
+/// array.insert("color", 200);
+///
+/// assert_eq!(array_keys(&array), [Key::Int(0), Key::from("color")]);
+/// assert_eq!(array_keys(&vec!["blue", "red"]), [0, 1]);
+/// ```
+pub fn array_keys<A>(array: &A) -> Vec<A::Key>
+    where
+        A: ArrayLike + ?Sized {
+
+    array.entries().map(|(key, _)| key).collect()
+}
+
+/// Return the keys of an array that hold a given value
+///
+/// # Description
+///
+/// Returns the keys of the array whose value equals search_value, in order.
+///
+/// If strict is true, then both the type and the value must match. Otherwise values are compared
+/// loosely, using PHP's comparison rules, like array_search() does.
+///
+/// # Examples
+///
+/// Example #1 array_keys_search() example
+///
+/// ```
+/// use phpify::array::array_keys_search;
+/// use phpify::value::Value;
+///
+/// let array = vec!["blue", "red", "green", "blue", "blue"];
+/// assert_eq!(array_keys_search(&array, &"blue", false), [0, 3, 4]);
+///
+/// let array = vec![Value::from(1), Value::from("1"), Value::from(1.0)];
+/// assert_eq!(array_keys_search(&array, &Value::from("1"), false), [0, 1, 2]);
+/// assert_eq!(array_keys_search(&array, &Value::from("1"), true), [1]);
+/// ```
+pub fn array_keys_search<A>(array: &A, search_value: &A::Value, strict: bool) -> Vec<A::Key>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue {
+
+    let search_value = search_value.to_value();
+
+    array.entries()
+        .filter(|(_, value)| {
+            let value = value.to_value();
+            if strict { search_value.strict_eq(&value) } else { search_value.loose_eq(&value) }
+        })
+        .map(|(key, _)| key)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_keys, array_keys_search, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        assert_eq!(array_keys(&Vec::<i32>::new()), Vec::<usize>::new());
+        assert_eq!(array_keys(&[1, 2][..]), [0, 1]);
+
+        let array: PhpArray<i32> = vec![("a", 1), ("5", 2), ("b", 1)].into_iter().collect();
+        assert_eq!(array_keys(&array), [Key::from("a"), Key::Int(5), Key::from("b")]);
+        assert_eq!(array_keys_search(&array, &1, true), [Key::from("a"), Key::from("b")]);
+        assert_eq!(array_keys_search(&array, &3, false), Vec::<Key>::new());
+    }
+}
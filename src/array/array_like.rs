@@ -0,0 +1,204 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{Key, PhpArray};
+
+/// An iterator over the entries of an ArrayLike.
+pub type Entries<'a, K, V> = Box<dyn Iterator<Item = (K, &'a V)> + 'a>;
+
+/// A mutable iterator over the entries of an ArrayLike.
+pub type EntriesMut<'a, K, V> = Box<dyn Iterator<Item = (K, &'a mut V)> + 'a>;
+
+/// A collection the array functions can operate on.
+///
+/// # Description
+///
+/// Implemented for slices and vectors, which are keyed by their positional index, and for PhpArray,
+/// which is keyed by Key.
+pub trait ArrayLike {
+    /// The type of the keys.
+    type Key: Clone;
+
+    /// The type of the values.
+    type Value;
+
+    /// The collection of the same kind holding values of type T, like array_map() returns.
+    ///
+    /// The functions that return part of an array, like array_filter() and array_diff(), return
+    /// Mapped<Value>, so a slice gives a vector.
+    type Mapped<T>: ArrayLikeMut<Key = Self::Key, Value = T>;
+
+    /// Returns the number of entries.
+    fn len(&self) -> usize;
+
+    /// Returns true if there are no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the entries, in order.
+    fn entries(&self) -> Entries<'_, Self::Key, Self::Value>;
+
+    /// Returns an iterator over the entries with mutable references to the values, in order.
+    fn entries_mut(&mut self) -> EntriesMut<'_, Self::Key, Self::Value>;
+}
+
+/// An ArrayLike that can grow and shrink.
+///
+/// # Description
+///
+/// Vectors renumber their positional keys whenever entries are added or removed, PhpArray follows
+/// the rules of PHP arrays.
+pub trait ArrayLikeMut: ArrayLike + Sized {
+    /// Appends a value.
+    fn push(&mut self, value: Self::Value);
+
+    /// Removes the last entry, returning its value.
+    fn pop(&mut self) -> Option<Self::Value>;
+
+    /// Removes the first entry, returning its value.
+    fn shift(&mut self) -> Option<Self::Value>;
+
+    /// Prepends a value.
+    fn unshift(&mut self, value: Self::Value);
+
+    /// Consumes the collection, returning its entries in order.
+    fn into_entries(self) -> Vec<(Self::Key, Self::Value)>;
+
+    /// Builds a collection of the same kind from entries, in order.
+    ///
+    /// Vectors discard the keys, PhpArray preserves them.
+    fn from_entries<I>(entries: I) -> Self
+        where
+            I: IntoIterator<Item = (Self::Key, Self::Value)>;
+}
+
+impl<T> ArrayLike for [T] {
+    type Key = usize;
+    type Value = T;
+    type Mapped<U> = Vec<U>;
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn entries(&self) -> Entries<'_, usize, T> {
+        Box::new(self.iter().enumerate())
+    }
+
+    fn entries_mut(&mut self) -> EntriesMut<'_, usize, T> {
+        Box::new(self.iter_mut().enumerate())
+    }
+}
+
+impl<T> ArrayLike for Vec<T> {
+    type Key = usize;
+    type Value = T;
+    type Mapped<U> = Vec<U>;
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn entries(&self) -> Entries<'_, usize, T> {
+        Box::new(self.iter().enumerate())
+    }
+
+    fn entries_mut(&mut self) -> EntriesMut<'_, usize, T> {
+        Box::new(self.iter_mut().enumerate())
+    }
+}
+
+impl<T> ArrayLikeMut for Vec<T> {
+    fn push(&mut self, value: T) {
+        Vec::push(self, value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
+
+    fn shift(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.remove(0))
+    }
+
+    fn unshift(&mut self, value: T) {
+        self.insert(0, value);
+    }
+
+    fn into_entries(self) -> Vec<(usize, T)> {
+        self.into_iter().enumerate().collect()
+    }
+
+    fn from_entries<I>(entries: I) -> Self
+        where
+            I: IntoIterator<Item = (usize, T)> {
+
+        entries.into_iter().map(|(_, value)| value).collect()
+    }
+}
+
+impl<V> ArrayLike for PhpArray<V> {
+    type Key = Key;
+    type Value = V;
+    type Mapped<U> = PhpArray<U>;
+
+    fn len(&self) -> usize {
+        PhpArray::len(self)
+    }
+
+    fn entries(&self) -> Entries<'_, Key, V> {
+        Box::new(self.iter().map(|(key, value)| (key.clone(), value)))
+    }
+
+    fn entries_mut(&mut self) -> EntriesMut<'_, Key, V> {
+        Box::new(self.iter_mut().map(|(key, value)| (key.clone(), value)))
+    }
+}
+
+impl<V> ArrayLikeMut for PhpArray<V> {
+    fn push(&mut self, value: V) {
+        PhpArray::push(self, value);
+    }
+
+    fn pop(&mut self) -> Option<V> {
+        PhpArray::pop(self)
+    }
+
+    fn shift(&mut self) -> Option<V> {
+        PhpArray::shift(self)
+    }
+
+    fn unshift(&mut self, value: V) {
+        PhpArray::unshift(self, value);
+    }
+
+    fn into_entries(self) -> Vec<(Key, V)> {
+        self.into_iter().collect()
+    }
+
+    fn from_entries<I>(entries: I) -> Self
+        where
+            I: IntoIterator<Item = (Key, V)> {
+
+        entries.into_iter().collect()
+    }
+}
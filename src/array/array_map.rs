@@ -0,0 +1,184 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{ArrayLike, ArrayLikeMut, Key, PhpArray};
+use crate::value::{ToValue, Value};
+
+/// Applies the callback to the elements of the given array
+///
+/// # Description
+///
+/// Returns an array containing the results of applying the callback to the corresponding value of
+/// array. The keys of array are preserved.
+///
+/// The callback may capture and modify local state. Use array_map_multiple() to map several arrays
+/// at once, and array_map_zip() for PHP's null callback.
+///
+/// # Examples
+///
+/// Example #1 array_map() example
+///
+/// ```
+/// use phpify::array::array_map;
+///
+/// let a = vec![1, 2, 3, 4, 5];
+/// let b = array_map(|n| n * n * n, &a);
+///
+/// assert_eq!(b, [1, 8, 27, 64, 125]);
+/// ```
+///
+/// Example #2 array_map() with string keys
+///
+/// ```
+/// use phpify::array::{array_map, PhpArray};
+///
+/// let arr: PhpArray<&str> = vec![("stringkey", "value")].into_iter().collect();
+///
+/// let mut calls = 0;
+/// let result = array_map(|value| { calls += 1; value.len() }, &arr);
+///
+/// assert_eq!(result["stringkey"], 5);
+/// assert_eq!(calls, 1);
+/// ```
+pub fn array_map<A, R>(mut callback: impl FnMut(&A::Value) -> R, array: &A) -> A::Mapped<R>
+    where
+        A: ArrayLike + ?Sized {
+
+    <A::Mapped<R>>::from_entries(array.entries().map(|(key, value)| (key, callback(value))))
+}
+
+/// Applies the callback to the elements of several arrays
+///
+/// # Description
+///
+/// Returns a vector containing the results of calling the callback with the values of all arrays
+/// at the same position, the way array_map() does with more than one array. The callback receives
+/// one value per array. When the arrays are of unequal length, the shorter ones are padded with
+/// None. The keys are not preserved.
+///
+/// # Examples
+///
+/// Example #1 Performing a sort of array_combine
+///
+/// ```
+/// use phpify::array::array_map_multiple;
+///
+/// let a = vec!["1", "2", "3"];
+/// let b = vec!["one", "two", "three", "four"];
+///
+/// let c = array_map_multiple(|values| format!("{}: {}", values[0].unwrap_or(&""), values[1].unwrap_or(&"")), &[&a, &b]);
+///
+/// assert_eq!(c, ["1: one", "2: two", "3: three", ": four"]);
+/// ```
+pub fn array_map_multiple<A, R>(mut callback: impl FnMut(&[Option<&A::Value>]) -> R, arrays: &[&A]) -> Vec<R>
+    where
+        A: ArrayLike + ?Sized {
+
+    let len = arrays.iter().map(|array| array.len()).max().unwrap_or(0);
+    let mut iterators: Vec<_> = arrays.iter().map(|array| array.entries()).collect();
+    let mut result = Vec::with_capacity(len);
+
+    for _ in 0..len {
+        let values: Vec<Option<&A::Value>> = iterators.iter_mut().map(|iterator| iterator.next().map(|(_, value)| value)).collect();
+        result.push(callback(&values));
+    }
+
+    result
+}
+
+/// Zips several arrays into an array of arrays
+///
+/// # Description
+///
+/// Does what array_map() does when null is passed as the callback. Given more than one array, it
+/// returns a list of lists holding the values of all arrays at the same position. When the arrays
+/// are of unequal length, the shorter ones are padded with null. Given a single array, it returns
+/// that array unchanged, keys included.
+///
+/// As the result holds arrays or the values themselves depending on the number of arrays, it is a
+/// PhpArray of Values.
+///
+/// # Examples
+///
+/// Example #1 Creating an array of arrays
+///
+/// ```
+/// use phpify::array::{array_map_zip, PhpArray};
+/// use phpify::value::Value;
+///
+/// let a = vec![1, 2, 3];
+/// let b = vec![4, 5];
+///
+/// let d = array_map_zip(&[&a, &b]);
+///
+/// assert_eq!(d, PhpArray::from(vec![Value::from(vec![1, 4]), Value::from(vec![2, 5]), Value::from(vec![Some(3), None])]));
+/// ```
+///
+/// Example #2 array_map() with only one array
+///
+/// ```
+/// use phpify::array::{array_map_zip, PhpArray};
+/// use phpify::value::Value;
+///
+/// let a: PhpArray<i32> = vec![("x", 1), ("y", 2)].into_iter().collect();
+///
+/// assert_eq!(array_map_zip(&[&a]), vec![("x", Value::from(1)), ("y", Value::from(2))].into_iter().collect());
+/// ```
+pub fn array_map_zip<A>(arrays: &[&A]) -> PhpArray<Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Into<Key>,
+        A::Value: ToValue {
+
+    if let [array] = arrays {
+        return array.entries().map(|(key, value)| (key, value.to_value())).collect();
+    }
+
+    PhpArray::from(array_map_multiple(|values| {
+        Value::from(values.iter().map(|value| value.map_or(Value::Null, ToValue::to_value)).collect::<Vec<Value>>())
+    }, arrays))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_map, array_map_multiple, array_map_zip, Key, PhpArray};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        let array: PhpArray<i32> = vec![("a", 1), ("5", 2)].into_iter().collect();
+        let mapped = array_map(|value| value.to_string(), &array);
+        assert_eq!(mapped.into_iter().collect::<Vec<(Key, String)>>(), [(Key::from("a"), "1".to_string()), (Key::Int(5), "2".to_string())]);
+
+        let slice: &[i32] = &[1, 2];
+        assert_eq!(array_map(|value| value + 1, slice), [2, 3]);
+
+        let mut total = 0;
+        array_map(|value| total += value, &vec![1, 2, 3]);
+        assert_eq!(total, 6);
+
+        let a: PhpArray<i32> = vec![("x", 1), ("y", 2)].into_iter().collect();
+        let b: PhpArray<i32> = vec![("z", 10)].into_iter().collect();
+        assert_eq!(array_map_multiple(|values| values.iter().map(|value| value.copied().unwrap_or(0)).sum::<i32>(), &[&a, &b]), [11, 2]);
+        assert_eq!(array_map_zip(&[&a]), vec![("x", Value::from(1)), ("y", Value::from(2))].into_iter().collect());
+        assert_eq!(array_map_zip(&[&a, &b]), PhpArray::from(vec![Value::from(vec![1, 10]), Value::from(vec![Some(2), None])]));
+
+        let empty: Vec<Vec<i32>> = Vec::new();
+        assert!(array_map_zip::<Vec<i32>>(&[]).is_empty());
+        assert!(array_map(|value: &Vec<i32>| value.len(), &empty).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{Key, PhpArray};
+
+/// Merge one or more arrays
+///
+/// # Description
+///
+/// Merges the elements of one or more arrays together so that the values of one are appended to
+/// the end of the previous one.
+///
+/// If the input arrays have the same string keys, then the later value for that key will overwrite
+/// the previous one, keeping the position of the first. Values with integer keys are appended, and
+/// the keys of the result are renumbered to start counting from zero.
+///
+/// To keep the first value of string and integer keys alike, use the + operator instead.
+///
+/// # Examples
+///
+/// Example #1 array_merge() example
+///
+/// ```
+/// use phpify::array::{array_merge, Key, PhpArray};
+///
+/// let mut array1 = PhpArray::new();
+/// array1.insert("color", "red");
+/// array1.push("2");
+/// array1.push("4");
+///
+/// let mut array2 = PhpArray::from(vec!["a", "b"]);
+/// array2.insert("color", "green");
+/// array2.insert("shape", "trapezoid");
+/// array2.push("4");
+///
+/// let result = array_merge(&[&array1, &array2]);
+///
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, &str)>>(), [
+///     (Key::from("color"), "green"),
+///     (Key::Int(0), "2"),
+///     (Key::Int(1), "4"),
+///     (Key::Int(2), "a"),
+///     (Key::Int(3), "b"),
+///     (Key::from("shape"), "trapezoid"),
+///     (Key::Int(4), "4"),
+/// ]);
+/// ```
+///
+/// Example #2 array_merge() with non-sequential integer keys
+///
+/// ```
+/// use phpify::array::{array_merge, Key, PhpArray};
+///
+/// let array: PhpArray<&str> = vec![(3, "data")].into_iter().collect();
+/// let result = array_merge(&[&array]);
+///
+/// assert_eq!(result.keys().collect::<Vec<&Key>>(), [&Key::Int(0)]);
+/// ```
+pub fn array_merge<V>(arrays: &[&PhpArray<V>]) -> PhpArray<V>
+    where
+        V: Clone {
+
+    let mut result = PhpArray::new();
+
+    for array in arrays {
+        for (key, value) in array.iter() {
+            match key {
+                Key::Int(_) => {
+                    result.push(value.clone());
+                }
+                Key::Str(_) => {
+                    result.insert(key.clone(), value.clone());
+                }
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_merge, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        assert_eq!(array_merge::<i32>(&[]), PhpArray::new());
+        assert_eq!(array_merge(&[&PhpArray::from(vec![1]), &PhpArray::from(vec![2])]), PhpArray::from(vec![1, 2]));
+
+        let a: PhpArray<i32> = vec![("x", 1), ("y", 2)].into_iter().collect();
+        let b: PhpArray<i32> = vec![("y", 3), ("z", 4)].into_iter().collect();
+        let result = array_merge(&[&a, &b]);
+        assert_eq!(result.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::from("x"), 1), (Key::from("y"), 3), (Key::from("z"), 4)]);
+    }
+}
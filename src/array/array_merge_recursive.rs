@@ -0,0 +1,130 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::PhpArray;
+use crate::value::Value;
+
+/// Merge one or more arrays recursively
+///
+/// # Description
+///
+/// array_merge_recursive() merges the elements of one or more arrays together so that the values
+/// of one are appended to the end of the previous one.
+///
+/// If the input arrays have the same string keys, then the values for these keys are merged
+/// together into an array, and this is done recursively, so that if one of the values is an array
+/// itself, the function will merge it with a corresponding entry in another array too. If, however,
+/// the arrays have the same integer key, the later value will not overwrite the original value, but
+/// will be appended.
+///
+/// # Examples
+///
+/// Example #1 array_merge_recursive() example
+///
+/// ```
+/// use phpify::array::{array_merge_recursive, Key, PhpArray};
+/// use phpify::value::Value;
+///
+/// let mut favorite = PhpArray::new();
+/// favorite.insert("color", Value::from("red"));
+/// favorite.push(Value::from(5));
+///
+/// let mut ar1 = PhpArray::new();
+/// ar1.insert("color", Value::from(favorite));
+///
+/// let mut ar2 = PhpArray::new();
+/// ar2.insert(10, Value::from(PhpArray::new()));
+/// ar2.insert("color", Value::from(PhpArray::from(vec![Value::from("blue")])));
+/// ar2.push(Value::from(9));
+///
+/// let result = array_merge_recursive(&[&ar1, &ar2]);
+///
+/// let mut color = PhpArray::new();
+/// color.insert("color", Value::from("red"));
+/// color.push(Value::from(5));
+/// color.push(Value::from("blue"));
+///
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, Value)>>(), [
+///     (Key::from("color"), Value::from(color)),
+///     (Key::Int(0), Value::from(PhpArray::new())),
+///     (Key::Int(1), Value::from(9)),
+/// ]);
+/// ```
+pub fn array_merge_recursive(arrays: &[&PhpArray<Value>]) -> PhpArray<Value> {
+    let mut result = PhpArray::new();
+
+    for array in arrays {
+        merge_into(&mut result, array);
+    }
+
+    result
+}
+
+fn merge_into(destination: &mut PhpArray<Value>, source: &PhpArray<Value>) {
+    for (key, value) in source.iter() {
+        if key.is_int() {
+            destination.push(value.clone());
+            continue;
+        }
+
+        if !destination.contains_key(key) {
+            destination.insert(key.clone(), value.clone());
+            continue;
+        }
+
+        let existing = &mut destination[key];
+
+        let mut merged = match std::mem::take(existing) {
+            Value::Array(array) => array,
+            Value::Object(object) => object.into_properties(),
+            value => PhpArray::from(vec![value]),
+        };
+
+        match value {
+            Value::Array(array) => merge_into(&mut merged, array),
+            value => {
+                merged.push(value.clone());
+            }
+        }
+
+        *existing = Value::Array(merged);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_merge_recursive, PhpArray};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        let mut a = PhpArray::new();
+        a.insert("x", Value::from(1));
+        a.insert("y", Value::Null);
+        let mut b = PhpArray::new();
+        b.insert("x", Value::from(2));
+        b.insert("y", Value::from(3));
+        let mut c = PhpArray::new();
+        c.insert("x", Value::from(PhpArray::from(vec![Value::from(4)])));
+
+        let result = array_merge_recursive(&[&a, &b, &c]);
+        assert_eq!(result["x"], Value::from(PhpArray::from(vec![Value::from(1), Value::from(2), Value::from(4)])));
+        assert_eq!(result["y"], Value::from(PhpArray::from(vec![Value::Null, Value::from(3)])));
+
+        assert_eq!(array_merge_recursive(&[&PhpArray::from(vec![Value::from(1)]); 2]), PhpArray::from(vec![Value::from(1), Value::from(1)]));
+    }
+}
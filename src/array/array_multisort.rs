@@ -0,0 +1,212 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+
+use crate::array::sort::{compare_by_flags, sorted_order};
+use crate::array::{ArrayLikeMut, SORT_ASC, SORT_DESC, SORT_FLAG_CASE, SORT_LOCALE_STRING, SORT_NATURAL, SORT_NUMERIC, SORT_REGULAR, SORT_STRING};
+use crate::error::PhpError;
+use crate::value::{ToValue, Value};
+
+/// An array passed to array_multisort(), together with its sort order and sort flags.
+///
+/// # Description
+///
+/// The order is either SORT_ASC or SORT_DESC. The flags modify the comparison behavior, see
+/// sort().
+pub struct SortColumn<'a> {
+    array: &'a mut dyn MultisortArray,
+    order: u32,
+    flags: u32,
+}
+
+impl<'a> SortColumn<'a> {
+    /// Creates a column from an array, its sort order and its sort flags.
+    pub fn new<A>(array: &'a mut A, order: u32, flags: u32) -> Self
+        where
+            A: ArrayLikeMut + 'a,
+            A::Key: ToValue + From<usize>,
+            A::Value: ToValue {
+
+        SortColumn { array, order, flags }
+    }
+}
+
+/// An array that array_multisort() can compare and reorder.
+trait MultisortArray {
+    fn values(&self) -> Vec<Value>;
+
+    fn reorder(&mut self, order: &[usize]);
+}
+
+impl<A> MultisortArray for A
+    where
+        A: ArrayLikeMut,
+        A::Key: ToValue + From<usize>,
+        A::Value: ToValue {
+
+    fn values(&self) -> Vec<Value> {
+        self.entries().map(|(_, value)| value.to_value()).collect()
+    }
+
+    fn reorder(&mut self, order: &[usize]) {
+        let entries = std::mem::replace(self, A::from_entries(std::iter::empty())).into_entries();
+        let mut entries: Vec<Option<(A::Key, A::Value)>> = entries.into_iter().map(Some).collect();
+        let mut next_index = 0;
+
+        *self = A::from_entries(order.iter().filter_map(|&index| entries[index].take()).map(|(key, value)| {
+            if let Value::String(_) = key.to_value() {
+                return (key, value);
+            }
+
+            next_index += 1;
+            (A::Key::from(next_index - 1), value)
+        }));
+    }
+}
+
+/// Sort multiple or multi-dimensional arrays
+///
+/// # Description
+///
+/// Can be used to sort several arrays at once, or a multi-dimensional array by one or more
+/// dimensions.
+///
+/// The arrays are treated as columns of a table to be sorted by rows. The first array is the
+/// primary one to sort by. Rows whose values in the first array compare equal are sorted by the
+/// next array, and so on. Rows that compare equal in all arrays keep their original order.
+///
+/// Associative (string) keys will be maintained, but numeric keys will be re-indexed.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if the arrays do not have the same size, or if a sort order or sort
+/// flag is not valid. Returns PhpError::ArgumentCountError if no array is given.
+///
+/// # Examples
+///
+/// Example #1 Sorting multiple arrays
+///
+/// ```
+/// use phpify::array::{array_multisort, SortColumn, SORT_ASC, SORT_REGULAR};
+/// use phpify::value::Value;
+///
+/// let mut ar1 = vec![Value::from(10), Value::from(100), Value::from(100), Value::from(0)];
+/// let mut ar2 = vec![Value::from(1), Value::from(3), Value::from(2), Value::from(4)];
+///
+/// array_multisort(&mut [
+///     SortColumn::new(&mut ar1, SORT_ASC, SORT_REGULAR),
+///     SortColumn::new(&mut ar2, SORT_ASC, SORT_REGULAR),
+/// ]).unwrap();
+///
+/// assert_eq!(ar1, [Value::from(0), Value::from(10), Value::from(100), Value::from(100)]);
+/// assert_eq!(ar2, [Value::from(4), Value::from(1), Value::from(2), Value::from(3)]);
+/// ```
+///
+/// Example #2 Sorting database results
+///
+/// ```
+/// use phpify::array::{array_multisort, SortColumn, SORT_ASC, SORT_DESC, SORT_REGULAR};
+///
+/// let mut volume = vec![67, 86, 85, 98, 86, 67];
+/// let mut edition = vec![2, 1, 6, 2, 6, 7];
+/// let mut data = vec!["a", "b", "c", "d", "e", "f"];
+///
+/// array_multisort(&mut [
+///     SortColumn::new(&mut volume, SORT_DESC, SORT_REGULAR),
+///     SortColumn::new(&mut edition, SORT_ASC, SORT_REGULAR),
+///     SortColumn::new(&mut data, SORT_ASC, SORT_REGULAR),
+/// ]).unwrap();
+///
+/// assert_eq!(volume, [98, 86, 86, 85, 67, 67]);
+/// assert_eq!(edition, [2, 1, 6, 6, 2, 7]);
+/// assert_eq!(data, ["d", "b", "e", "c", "a", "f"]);
+/// ```
+pub fn array_multisort(columns: &mut [SortColumn<'_>]) -> Result<(), PhpError> {
+    if columns.is_empty() {
+        return Err(PhpError::ArgumentCountError("array_multisort() expects at least 1 argument, 0 given".to_string()));
+    }
+
+    for (index, column) in columns.iter().enumerate() {
+        if column.order != SORT_ASC && column.order != SORT_DESC {
+            return Err(PhpError::ValueError(format!("array_multisort(): Argument #{} must be a valid sort flag", index * 3 + 2)));
+        }
+
+        match column.flags & !SORT_FLAG_CASE {
+            SORT_REGULAR | SORT_NUMERIC | SORT_STRING | SORT_NATURAL | SORT_LOCALE_STRING => {},
+            _ => return Err(PhpError::ValueError(format!("array_multisort(): Argument #{} must be a valid sort flag", index * 3 + 3))),
+        }
+    }
+
+    let values: Vec<Vec<Value>> = columns.iter().map(|column| column.array.values()).collect();
+    let len = values[0].len();
+
+    if values.iter().any(|column| column.len() != len) {
+        return Err(PhpError::ValueError("Array sizes are inconsistent".to_string()));
+    }
+
+    let order = sorted_order(len, |a, b| {
+        for (column, values) in columns.iter().zip(&values) {
+            let ordering = compare_by_flags(&values[a], &values[b], column.flags);
+
+            if ordering != Ordering::Equal {
+                return if column.order == SORT_DESC { ordering.reverse() } else { ordering };
+            }
+        }
+
+        Ordering::Equal
+    });
+
+    for column in columns.iter_mut() {
+        column.array.reorder(&order);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_multisort, Key, PhpArray, SortColumn, SORT_ASC, SORT_DESC, SORT_FLAG_CASE, SORT_NUMERIC, SORT_REGULAR, SORT_STRING};
+    use crate::error::PhpError;
+
+    #[test]
+    fn test() {
+        let mut array: PhpArray<i32> = vec![("x", 3), ("5", 1), ("y", 2), ("9", 0)].into_iter().collect();
+        array_multisort(&mut [SortColumn::new(&mut array, SORT_ASC, SORT_REGULAR)]).unwrap();
+        assert_eq!(array.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::Int(0), 0), (Key::Int(1), 1), (Key::from("y"), 2), (Key::from("x"), 3)]);
+
+        let mut names = vec!["b", "A", "a", "B"];
+        let mut ids = vec![1, 2, 3, 4];
+        array_multisort(&mut [
+            SortColumn::new(&mut names, SORT_ASC, SORT_STRING | SORT_FLAG_CASE),
+            SortColumn::new(&mut ids, SORT_DESC, SORT_NUMERIC),
+        ]).unwrap();
+        assert_eq!(names, ["a", "A", "B", "b"]);
+        assert_eq!(ids, [3, 2, 4, 1]);
+
+        let mut a = vec![1, 2];
+        let mut b = vec![1];
+        assert_eq!(array_multisort(&mut [
+            SortColumn::new(&mut a, SORT_ASC, SORT_REGULAR),
+            SortColumn::new(&mut b, SORT_ASC, SORT_REGULAR),
+        ]), Err(PhpError::ValueError("Array sizes are inconsistent".to_string())));
+
+        assert_eq!(array_multisort(&mut [SortColumn::new(&mut a, SORT_REGULAR, SORT_REGULAR)]), Err(PhpError::ValueError("array_multisort(): Argument #2 must be a valid sort flag".to_string())));
+        assert_eq!(array_multisort(&mut [SortColumn::new(&mut a, SORT_ASC, 42)]), Err(PhpError::ValueError("array_multisort(): Argument #3 must be a valid sort flag".to_string())));
+        assert!(array_multisort(&mut []).is_err());
+    }
+}
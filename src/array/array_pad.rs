@@ -0,0 +1,99 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::array_slice::renumber;
+use crate::array::php_array::MAX_SIZE;
+use crate::array::{ArrayLike, ArrayLikeMut};
+use crate::error::PhpError;
+use crate::value::ToValue;
+
+/// Pad array to the specified length with a value
+///
+/// # Description
+///
+/// array_pad() returns a copy of the array padded to size specified by length with value value.
+/// If length is positive then the array is padded on the right, if it's negative then on the left.
+/// If the absolute value of length is less than or equal to the length of the array then no
+/// padding takes place.
+///
+/// When padding takes place, integer keys are renumbered to start counting from zero and string
+/// keys are preserved.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if length exceeds the maximum array size.
+///
+/// # Examples
+///
+/// Example #1 array_pad() example
+///
+/// ```
+/// use phpify::array::array_pad;
+///
+/// let input = vec![12, 10, 9];
+///
+/// assert_eq!(array_pad(&input, 5, 0).unwrap(), [12, 10, 9, 0, 0]);
+/// assert_eq!(array_pad(&input, -7, -1).unwrap(), [-1, -1, -1, -1, 12, 10, 9]);
+/// assert_eq!(array_pad(&input, 2, 99).unwrap(), [12, 10, 9]);
+/// ```
+pub fn array_pad<A>(array: &A, length: isize, value: A::Value) -> Result<A::Mapped<A::Value>, PhpError>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: ToValue + From<usize>,
+        A::Value: Clone {
+
+    let size = length.unsigned_abs();
+
+    if size > MAX_SIZE {
+        return Err(PhpError::ValueError("array_pad(): Argument #2 ($length) must not exceed the maximum allowed array size".to_string()));
+    }
+
+    let entries = array.entries().map(|(key, value)| (key, value.clone()));
+
+    if size <= array.len() {
+        return Ok(<A::Mapped<A::Value>>::from_entries(entries));
+    }
+
+    let pads = std::iter::repeat_n(value, size - array.len()).map(|value| (A::Key::from(0), value));
+
+    if length > 0 {
+        Ok(<A::Mapped<A::Value>>::from_entries(renumber(entries.chain(pads))))
+    } else {
+        Ok(<A::Mapped<A::Value>>::from_entries(renumber(pads.chain(entries))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_pad, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        assert_eq!(array_pad(&Vec::new(), 2, 'a').unwrap(), ['a', 'a']);
+        assert_eq!(array_pad(&vec![1], 0, 0).unwrap(), [1]);
+        assert!(array_pad(&vec![1], isize::MIN, 0).is_err());
+
+        let mut array: PhpArray<i32> = vec![(5, 1)].into_iter().collect();
+        array.insert("a", 2);
+
+        let padded = array_pad(&array, -3, 0).unwrap();
+        assert_eq!(padded.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::Int(0), 0), (Key::Int(1), 1), (Key::from("a"), 2)]);
+
+        let padded = array_pad(&array, 2, 0).unwrap();
+        assert_eq!(padded.keys().collect::<Vec<&Key>>(), [&Key::Int(5), &Key::from("a")]);
+    }
+}
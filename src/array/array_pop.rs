@@ -15,13 +15,18 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-/// Pop the element off the end of vector
+use crate::array::ArrayLikeMut;
+
+/// Pop the element off the end of array
 ///
 /// # Description
 ///
-/// array_pop() pops and returns the value of the last element of vector, shortening the vector by
+/// array_pop() pops and returns the value of the last element of array, shortening the array by
 /// one element.
 ///
+/// When array is a PhpArray and the popped key is the most recently assigned integer key, that key
+/// is reused by the next push.
+///
 /// # Examples
 ///
 /// Example #1 array_pop() example
@@ -35,19 +40,38 @@
 /// assert_eq!(stack, vec!["orange", "banana", "apple"]);
 /// assert_eq!(fruit, "raspberry");
 /// ```
-pub fn array_pop<T>(array: &mut Vec<T>) -> Option<T> {
+///
+/// Example #2 array_pop() with an associative array
+///
+/// ```
+/// use phpify::array::{array_pop, PhpArray};
+///
+/// let mut stack: PhpArray<&str> = vec![("a", "orange"), ("b", "banana")].into_iter().collect();
+/// let fruit = array_pop(&mut stack).unwrap();
+///
+/// assert_eq!(stack.len(), 1);
+/// assert_eq!(fruit, "banana");
+/// ```
+pub fn array_pop<A>(array: &mut A) -> Option<A::Value>
+    where
+        A: ArrayLikeMut {
+
     array.pop()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::array::array_pop;
+    use crate::array::{array_pop, PhpArray};
 
     #[test]
     fn test() {
         let mut vec = vec!["a", "b", "c"];
         assert_eq!(array_pop(&mut vec), Some("c"));
         assert_eq!(vec, vec!["a", "b"]);
-        assert_eq!(array_pop::<usize>(&mut Vec::new()), None);
+        assert_eq!(array_pop::<Vec<usize>>(&mut Vec::new()), None);
+
+        let mut array = PhpArray::from(vec!["a", "b", "c"]);
+        assert_eq!(array_pop(&mut array), Some("c"));
+        assert_eq!(array.next_index(), 2);
     }
 }
@@ -15,12 +15,16 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-/// Push one or more elements onto the end of vector
+use crate::array::ArrayLikeMut;
+
+/// Push one or more elements onto the end of array
 ///
 /// # Description
 ///
-/// array_push() treats vector as a stack, and pushes the passed variable onto the end of vector.
-/// The length of vector increases by one.
+/// array_push() treats array as a stack, and pushes the passed variable onto the end of array. The
+/// length of array increases by one.
+///
+/// When array is a PhpArray, the value is stored under the next free integer key.
 ///
 /// # Examples
 ///
@@ -34,18 +38,39 @@
 ///
 /// assert_eq!(stack, vec!["orange", "banana", "apple"]);
 /// ```
-pub fn array_push<T>(array: &mut Vec<T>, value: T) {
+///
+/// Example #2 array_push() with an associative array
+///
+/// ```
+/// use phpify::array::{array_push, PhpArray};
+///
+/// let mut stack = PhpArray::new();
+/// stack.insert("a", "orange");
+/// stack.insert(5, "banana");
+/// array_push(&mut stack, "apple");
+///
+/// assert_eq!(stack[6], "apple");
+/// ```
+pub fn array_push<A>(array: &mut A, value: A::Value)
+    where
+        A: ArrayLikeMut {
+
     array.push(value)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::array::array_push;
+    use crate::array::{array_push, Key, PhpArray};
 
     #[test]
     fn test() {
         let mut vec = vec!["a", "b"];
         array_push(&mut vec, "c");
         assert_eq!(vec, vec!["a", "b", "c"]);
+
+        let mut array = PhpArray::new();
+        array.insert("x", "a");
+        array_push(&mut array, "b");
+        assert_eq!(array.last(), Some((&Key::Int(0), &"b")));
     }
 }
@@ -15,15 +15,33 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use rand::Rng;
+use crate::array::ArrayLike;
+use crate::error::PhpError;
+use crate::random::{with_generator, Engine};
 
-/// Pick one random key out of a vector
+/// Pick one or more random keys out of an array
 ///
 /// # Description
 ///
-/// Picks one entry out of a vector, and returns the index of the random entry.
+/// Picks one or more random entries out of an array, and returns the keys of the random entries,
+/// in the order they appear in the array.
 ///
-/// @TODO: It uses a pseudo random number generator that is not suitable for cryptographic purposes.
+/// It uses the Mersenne Twister shared with mt_rand(), so after mt_srand() it picks the same keys
+/// as PHP does for an array without holes. Use array_rand_with() to pick with another engine.
+///
+/// The Mersenne Twister is not suitable for cryptographic purposes, use array_rand_with() with the
+/// Secure engine instead.
+///
+/// # Parameters
+///
+/// **num**
+///
+/// Specifies how many entries should be picked.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if array is empty, or if num is 0 or greater than the number of
+/// entries in array.
 ///
 /// # Examples
 ///
@@ -33,37 +51,149 @@ use rand::Rng;
 /// use phpify::array::array_rand;
 ///
 /// let input = vec!["Neo", "Morpheus", "Trinity", "Cypher", "Tank"];
-/// let rand_key = array_rand(&input).unwrap();
+/// let rand_keys = array_rand(&input, 2).unwrap();
 ///
-/// assert_eq!(rand_key <= 4, true);
+/// assert_eq!(rand_keys.len(), 2);
+/// assert!(rand_keys[0] < rand_keys[1] && rand_keys[1] <= 4);
 /// ```
-pub fn array_rand<T>(array: &Vec<T>) -> Option<usize> {
-    if array.is_empty() {
-        return None;
-    }
+pub fn array_rand<A>(array: &A, num: usize) -> Result<Vec<A::Key>, PhpError>
+    where
+        A: ArrayLike + ?Sized {
+
+    with_generator(|generator| array_rand_with(array, num, generator))
+}
+
+/// Pick one or more random keys out of an array, using the given engine
+///
+/// # Description
+///
+/// Like array_rand(), but draws the random numbers from engine instead of the Mersenne Twister
+/// shared with mt_rand().
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if array is empty, or if num is 0 or greater than the number of
+/// entries in array.
+///
+/// # Examples
+///
+/// Example #1 array_rand_with() example
+///
+/// ```
+/// use phpify::array::array_rand_with;
+/// use phpify::random::{Mt19937, MT_RAND_MT19937};
+///
+/// let input = vec!["Neo", "Morpheus", "Trinity", "Cypher", "Tank"];
+///
+/// let mut engine = Mt19937::new(1, MT_RAND_MT19937);
+/// let first = array_rand_with(&input, 3, &mut engine).unwrap();
+///
+/// let mut engine = Mt19937::new(1, MT_RAND_MT19937);
+/// let second = array_rand_with(&input, 3, &mut engine).unwrap();
+///
+/// assert_eq!(first, second);
+/// ```
+pub fn array_rand_with<A, E>(array: &A, num: usize, engine: &mut E) -> Result<Vec<A::Key>, PhpError>
+    where
+        A: ArrayLike + ?Sized,
+        E: Engine + ?Sized {
+
+    pick_keys("array_rand", array, num, engine)
+}
+
+/// Picks num random keys out of the array, the way PHP's php_array_pick_keys() does. Errors are
+/// reported on behalf of function.
+pub(crate) fn pick_keys<A, E>(function: &str, array: &A, num: usize, engine: &mut E) -> Result<Vec<A::Key>, PhpError>
+    where
+        A: ArrayLike + ?Sized,
+        E: Engine + ?Sized {
 
     let length = array.len();
 
-    if length == 1 {
-        return Some(0);
+    if length == 0 {
+        return Err(PhpError::ValueError(format!("{}(): Argument #1 ($array) cannot be empty", function)));
+    }
+
+    if num == 0 || num > length {
+        return Err(PhpError::ValueError(format!("{}(): Argument #2 ($num) must be between 1 and the number of elements in argument #1 ($array)", function)));
+    }
+
+    if num == 1 {
+        let index = engine.range(0, length as i64 - 1) as usize;
+
+        return Ok(array.entries().nth(index).map(|(key, _)| key).into_iter().collect());
     }
 
-    let mut rng = rand::thread_rng();
-    let index = rng.gen_range(0, length - 1);
+    // Like PHP, mark the entries to skip rather than to pick when more than half are picked.
+    let negative = num > length / 2;
+    let mut remaining = if negative { length - num } else { num };
+    let mut selected = vec![false; length];
+
+    while remaining > 0 {
+        let index = engine.range(0, length as i64 - 1) as usize;
+
+        if !selected[index] {
+            selected[index] = true;
+            remaining -= 1;
+        }
+    }
 
-    Some(index)
+    Ok(array.entries()
+        .zip(selected)
+        .filter(|(_, selected)| *selected != negative)
+        .map(|((key, _), _)| key)
+        .collect())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::array::array_rand;
+    use crate::array::{array_rand, array_rand_with, Key, PhpArray};
+    use crate::error::PhpError;
+    use crate::random::{mt_rand_range, mt_srand, Mt19937, MT_RAND_MT19937};
 
     #[test]
     fn test() {
-        let rand = array_rand(&vec!["a", "b", "c"]);
-        assert_eq!(rand.is_some(), true);
-        assert_eq!(rand.unwrap() <= 2, true);
-        assert_eq!(array_rand(&vec!["a"]), Some(0));
-        assert_eq!(array_rand::<usize>(&Vec::new()), None);
+        let rand = array_rand(&vec!["a", "b", "c"], 1).unwrap();
+        assert_eq!(rand.len(), 1);
+        assert!(rand[0] <= 2);
+        assert_eq!(array_rand(&vec!["a"], 1), Ok(vec![0]));
+        assert_eq!(array_rand(&vec!["a", "b", "c"], 3), Ok(vec![0, 1, 2]));
+
+        let array: PhpArray<&str> = vec![("x", "a")].into_iter().collect();
+        assert_eq!(array_rand(&array, 1), Ok(vec![Key::from("x")]));
+
+        let input = vec![0, 1];
+        assert!((0..100).any(|_| array_rand(&input, 1) == Ok(vec![1])));
+
+        mt_srand(1, MT_RAND_MT19937);
+        let first: Vec<usize> = (0..10).map(|_| array_rand(&vec![0; 5], 1).unwrap()[0]).collect();
+
+        mt_srand(1, MT_RAND_MT19937);
+        assert_eq!((0..10).map(|_| mt_rand_range(0, 4).unwrap() as usize).collect::<Vec<usize>>(), first);
+
+        let array: PhpArray<i32> = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5), ("f", 6)].into_iter().collect();
+        for num in 1..=6 {
+            let keys = array_rand_with(&array, num, &mut Mt19937::new(num as u32, MT_RAND_MT19937)).unwrap();
+            assert_eq!(keys.len(), num);
+            assert_eq!(array.keys().filter(|key| keys.contains(key)).cloned().collect::<Vec<Key>>(), keys);
+        }
+
+        let mut engine = Mt19937::new(9, MT_RAND_MT19937);
+        let keys = array_rand_with(&(0..10).collect::<Vec<i32>>(), 3, &mut engine).unwrap();
+        let mut engine = Mt19937::new(9, MT_RAND_MT19937);
+        let mut expected: Vec<usize> = Vec::new();
+        while expected.len() < 3 {
+            let index = engine.range(0, 9) as usize;
+            if !expected.contains(&index) {
+                expected.push(index);
+            }
+        }
+        expected.sort();
+        assert_eq!(keys, expected);
+
+        let empty: Vec<usize> = Vec::new();
+        assert_eq!(array_rand(&empty, 1), Err(PhpError::ValueError("array_rand(): Argument #1 ($array) cannot be empty".to_string())));
+        assert!(array_rand(&vec!["a"], 0).is_err());
+        assert!(array_rand(&vec!["a"], 2).is_err());
     }
 }
@@ -0,0 +1,73 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::ArrayLike;
+
+/// Iteratively reduce the array to a single value using a callback function
+///
+/// # Description
+///
+/// Iteratively applies the callback function to the elements of the array, so as to reduce the
+/// array to a single value.
+///
+/// **callback**
+///
+/// The callback receives the carry, holding the return value of the previous iteration (initial in
+/// the first iteration), and the value of the current iteration.
+///
+/// **initial**
+///
+/// The value that is used at the beginning of the process, and returned when the array is empty.
+///
+/// # Examples
+///
+/// Example #1 array_reduce() example
+///
+/// ```
+/// use phpify::array::array_reduce;
+///
+/// let a = vec![1, 2, 3, 4, 5];
+/// let x: Vec<i32> = Vec::new();
+///
+/// assert_eq!(array_reduce(&a, |carry, item| carry + item, 0), 15);
+/// assert_eq!(array_reduce(&a, |carry, item| carry * item, 10), 1200);
+/// assert_eq!(array_reduce(&x, |carry, _| carry, "No data to reduce"), "No data to reduce");
+/// ```
+pub fn array_reduce<A, R>(array: &A, mut callback: impl FnMut(R, &A::Value) -> R, initial: R) -> R
+    where
+        A: ArrayLike + ?Sized {
+
+    array.entries().fold(initial, |carry, (_, value)| callback(carry, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_reduce, PhpArray};
+
+    #[test]
+    fn test() {
+        let array: PhpArray<&str> = vec![("a", "x"), ("b", "y")].into_iter().collect();
+        assert_eq!(array_reduce(&array, |carry, item| carry + item, String::new()), "xy");
+
+        let mut calls = 0;
+        let sum = array_reduce(&vec![1, 2, 3], |carry, item| { calls += 1; carry + item }, 0);
+        assert_eq!((sum, calls), (6, 3));
+
+        let empty: Vec<i32> = Vec::new();
+        assert_eq!(array_reduce(&empty, |carry, item| carry + item, 7), 7);
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::PhpArray;
+
+/// Replaces elements from passed arrays into the first array
+///
+/// # Description
+///
+/// array_replace() replaces the values of array with values having the same keys in each of the
+/// following arrays. If a key from the first array exists in the second array, its value will be
+/// replaced by the value from the second array. If the key exists in the second array, and not the
+/// first, it will be created in the first array. If a key only exists in the first array, it will
+/// be left as is. If several arrays are passed for replacement, they will be processed in order,
+/// the later arrays overwriting the previous values.
+///
+/// Unlike array_merge(), integer keys are not renumbered.
+///
+/// # Examples
+///
+/// Example #1 array_replace() example
+///
+/// ```
+/// use phpify::array::{array_replace, Key, PhpArray};
+///
+/// let base = PhpArray::from(vec!["orange", "banana", "apple", "raspberry"]);
+/// let replacements: PhpArray<&str> = vec![(0, "pineapple"), (4, "cherry")].into_iter().collect();
+/// let replacements2: PhpArray<&str> = vec![(0, "grape")].into_iter().collect();
+///
+/// let basket = array_replace(&base, &[&replacements, &replacements2]);
+///
+/// assert_eq!(basket.into_iter().collect::<Vec<(Key, &str)>>(), [
+///     (Key::Int(0), "grape"),
+///     (Key::Int(1), "banana"),
+///     (Key::Int(2), "apple"),
+///     (Key::Int(3), "raspberry"),
+///     (Key::Int(4), "cherry"),
+/// ]);
+/// ```
+pub fn array_replace<V>(array: &PhpArray<V>, replacements: &[&PhpArray<V>]) -> PhpArray<V>
+    where
+        V: Clone {
+
+    let mut result = array.clone();
+
+    for replacement in replacements {
+        result.extend(replacement.iter().map(|(key, value)| (key, value.clone())));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_replace, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let array: PhpArray<i32> = vec![("a", 1), ("5", 2)].into_iter().collect();
+        assert_eq!(array_replace(&array, &[]), array);
+
+        let replacement: PhpArray<i32> = vec![("b", 3), ("a", 4), ("5", 5)].into_iter().collect();
+        let result = array_replace(&array, &[&replacement]);
+        assert_eq!(result.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::from("a"), 4), (Key::Int(5), 5), (Key::from("b"), 3)]);
+    }
+}
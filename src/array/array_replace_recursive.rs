@@ -0,0 +1,111 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::PhpArray;
+use crate::value::Value;
+
+/// Replaces elements from passed arrays into the first array recursively
+///
+/// # Description
+///
+/// array_replace_recursive() replaces the values of array with the same values from all the
+/// following arrays. If a key from the first array exists in the second array, its value will be
+/// replaced by the value from the second array. If the key exists in the second array, and not the
+/// first, it will be created in the first array. If a key only exists in the first array, it will
+/// be left as is. If several arrays are passed for replacement, they will be processed in order,
+/// the later array overwriting the previous values.
+///
+/// array_replace_recursive() is recursive: it will recurse into arrays and apply the same process
+/// to the inner value.
+///
+/// When the value in the first array is scalar, it will be replaced by the value in the second
+/// array, may it be scalar or array. When the value in the first array and the second array are
+/// both arrays, array_replace_recursive() will replace their respective value recursively.
+///
+/// # Examples
+///
+/// Example #1 array_replace_recursive() example
+///
+/// ```
+/// use phpify::array::{array_replace_recursive, PhpArray};
+/// use phpify::value::Value;
+///
+/// let mut base = PhpArray::new();
+/// base.insert("citrus", Value::from(PhpArray::from(vec![Value::from("orange")])));
+/// base.insert("berries", Value::from(PhpArray::from(vec![Value::from("blackberry"), Value::from("raspberry")])));
+///
+/// let mut replacements = PhpArray::new();
+/// replacements.insert("citrus", Value::from(PhpArray::from(vec![Value::from("pineapple")])));
+/// replacements.insert("berries", Value::from(PhpArray::from(vec![Value::from("blueberry")])));
+///
+/// let basket = array_replace_recursive(&base, &[&replacements]);
+///
+/// assert_eq!(basket["citrus"], Value::from(PhpArray::from(vec![Value::from("pineapple")])));
+/// assert_eq!(basket["berries"], Value::from(PhpArray::from(vec![Value::from("blueberry"), Value::from("raspberry")])));
+/// ```
+pub fn array_replace_recursive(array: &PhpArray<Value>, replacements: &[&PhpArray<Value>]) -> PhpArray<Value> {
+    let mut result = array.clone();
+
+    for replacement in replacements {
+        replace_into(&mut result, replacement);
+    }
+
+    result
+}
+
+fn replace_into(destination: &mut PhpArray<Value>, source: &PhpArray<Value>) {
+    for (key, value) in source.iter() {
+        match (destination.get_mut(key), value) {
+            (Some(Value::Array(existing)), Value::Array(array)) => replace_into(existing, array),
+            _ => {
+                destination.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_replace_recursive, PhpArray};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        let mut inner = PhpArray::new();
+        inner.insert("x", Value::from(1));
+        inner.insert("y", Value::from(2));
+
+        let mut array = PhpArray::new();
+        array.insert("a", Value::from(inner));
+        array.insert("b", Value::from(3));
+
+        let mut replacement = PhpArray::new();
+        replacement.insert("y", Value::from(4));
+
+        let mut replacements = PhpArray::new();
+        replacements.insert("a", Value::from(replacement.clone()));
+        replacements.insert("b", Value::from(replacement.clone()));
+
+        let result = array_replace_recursive(&array, &[&replacements]);
+
+        let mut expected = PhpArray::new();
+        expected.insert("x", Value::from(1));
+        expected.insert("y", Value::from(4));
+        assert_eq!(result["a"], Value::from(expected));
+        assert_eq!(result["b"], Value::from(replacement));
+    }
+}
@@ -15,12 +15,19 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-/// Searches the vector for a given value and returns the first corresponding index if successful
+use crate::array::ArrayLike;
+use crate::value::ToValue;
+
+/// Searches the array for a given value and returns the first corresponding key if successful
 ///
 /// # Description
 ///
 /// Searches for needle in haystack.
 ///
+/// If strict is true, then array_search() will search for identical elements in the haystack,
+/// meaning both the type and the value must match. Otherwise values are compared loosely, using
+/// PHP's comparison rules, e.g. "1" matches "01" and 1.
+///
 /// # Examples
 ///
 /// Example #1 array_search() example
@@ -29,29 +36,75 @@
 /// use phpify::array::array_search;
 ///
 /// let array = vec!["blue", "red", "green", "red"];
-/// assert_eq!(array_search(&"green", &array).unwrap(), 2);
-/// assert_eq!(array_search(&"red", &array).unwrap(), 1);
+/// assert_eq!(array_search(&"green", &array, false).unwrap(), 2);
+/// assert_eq!(array_search(&"red", &array, false).unwrap(), 1);
+/// ```
+///
+/// Example #2 array_search() with an associative array
+///
 /// ```
-pub fn array_search<T>(needle: &T, haystack: &Vec<T>) -> Option<usize>
+/// use phpify::array::{array_search, Key, PhpArray};
+///
+/// let mut array = PhpArray::new();
+/// array.insert("first", "blue");
+/// array.insert("second", "red");
+///
+/// assert_eq!(array_search(&"red", &array, false).unwrap(), Key::from("second"));
+/// ```
+///
+/// Example #3 Loose and strict comparison
+///
+/// ```
+/// use phpify::array::array_search;
+/// use phpify::value::Value;
+///
+/// let array = vec![Value::from("01"), Value::from(1)];
+/// assert_eq!(array_search(&Value::from("1"), &array, false), Some(0));
+/// assert_eq!(array_search(&Value::from("1"), &array, true), None);
+/// assert_eq!(array_search(&Value::from(1), &array, true), Some(1));
+/// ```
+pub fn array_search<A>(needle: &A::Value, haystack: &A, strict: bool) -> Option<A::Key>
     where
-        T: PartialEq {
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue {
 
     if haystack.is_empty() {
         return None;
     }
 
-    haystack.iter().position(|x| x == needle)
+    let needle = needle.to_value();
+
+    haystack.entries()
+        .find(|(_, value)| {
+            let value = value.to_value();
+            if strict { needle.strict_eq(&value) } else { needle.loose_eq(&value) }
+        })
+        .map(|(key, _)| key)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::array::array_search;
+    use crate::array::{array_search, Key, PhpArray};
+    use crate::value::Value;
 
     #[test]
     fn test() {
-        assert_eq!(array_search(&"a", &vec!["a", "b", "c"]), Some(0));
-        assert_eq!(array_search(&"c", &vec!["a", "b", "c"]), Some(2));
-        assert_eq!(array_search(&"d", &vec!["a", "b", "c"]), None);
-        assert_eq!(array_search(&"a", &Vec::new()), None);
+        assert_eq!(array_search(&"a", &vec!["a", "b", "c"], false), Some(0));
+        assert_eq!(array_search(&"c", &vec!["a", "b", "c"], false), Some(2));
+        assert_eq!(array_search(&"d", &vec!["a", "b", "c"], false), None);
+        assert_eq!(array_search(&"a", &Vec::<&str>::new(), false), None);
+
+        let array: PhpArray<&str> = vec![("x", "a"), ("y", "b")].into_iter().collect();
+        assert_eq!(array_search(&"b", &array, false), Some(Key::from("y")));
+        assert_eq!(array_search(&"c", &array, false), None);
+
+        let array = vec![Value::from("01"), Value::from(1)];
+        assert_eq!(array_search(&Value::from("1"), &array, false), Some(0));
+        assert_eq!(array_search(&Value::from("1"), &array, true), None);
+        assert_eq!(array_search(&Value::from(1), &array, true), Some(1));
+        assert_eq!(array_search(&Value::from(1.0), &array, true), None);
+        assert_eq!(array_search(&Value::Null, &array, false), None);
+        assert_eq!(array_search(&Value::from(true), &array, false), Some(0));
+        assert_eq!(array_search(&Value::from("abc"), &[Value::from(0)][..], false), None);
     }
 }
@@ -15,12 +15,15 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-/// Shift an element off the beginning of vector
+use crate::array::ArrayLikeMut;
+
+/// Shift an element off the beginning of array
 ///
 /// # Description
 ///
-/// array_shift() shifts the first value of the vector off and returns it, shortening the vector by
-/// one element and moving everything down.
+/// array_shift() shifts the first value of the array off and returns it, shortening the array by
+/// one element and moving everything down. All numerical array keys will be modified to start
+/// counting from zero while literal keys won't be affected.
 ///
 /// # Examples
 ///
@@ -35,23 +38,39 @@
 /// assert_eq!(stack, vec!["banana", "apple", "raspberry"]);
 /// assert_eq!(fruit, "orange");
 /// ```
-pub fn array_shift<T>(array: &mut Vec<T>) -> Option<T> {
-    if array.is_empty() {
-        return None;
-    }
+///
+/// Example #2 array_shift() with an associative array
+///
+/// ```
+/// use phpify::array::{array_shift, Key, PhpArray};
+///
+/// let mut stack = PhpArray::from(vec!["orange", "banana"]);
+/// stack.insert("x", "apple");
+/// array_shift(&mut stack);
+///
+/// assert_eq!(stack.keys().collect::<Vec<_>>(), [&Key::Int(0), &Key::from("x")]);
+/// ```
+pub fn array_shift<A>(array: &mut A) -> Option<A::Value>
+    where
+        A: ArrayLikeMut {
 
-    Some(array.remove(0))
+    array.shift()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::array::array_shift;
+    use crate::array::{array_shift, PhpArray};
 
     #[test]
     fn test() {
         let mut vec = vec!["a", "b", "c"];
         assert_eq!(array_shift(&mut vec), Some("a"));
         assert_eq!(vec, vec!["b", "c"]);
-        assert_eq!(array_shift::<usize>(&mut Vec::new()), None);
+        assert_eq!(array_shift::<Vec<usize>>(&mut Vec::new()), None);
+
+        let mut array = PhpArray::from(vec!["a", "b", "c"]);
+        assert_eq!(array_shift(&mut array), Some("a"));
+        assert_eq!(array, PhpArray::from(vec!["b", "c"]));
+        assert_eq!(array_shift::<PhpArray<usize>>(&mut PhpArray::new()), None);
     }
 }
@@ -0,0 +1,172 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{ArrayLike, ArrayLikeMut};
+use crate::value::{ToValue, Value};
+
+/// Extract a slice of the array
+///
+/// # Description
+///
+/// array_slice() returns the sequence of elements from the array as specified by the offset and
+/// length parameters.
+///
+/// # Parameters
+///
+/// **offset**
+///
+/// If offset is non-negative, the sequence will start at that offset in the array.
+///
+/// If offset is negative, the sequence will start that far from the end of the array.
+///
+/// The offset parameter denotes the position in the array, not the key.
+///
+/// **length**
+///
+/// If length is positive, then the sequence will have up to that many elements in it.
+///
+/// If the array is shorter than the length, then only the available array elements will be
+/// present.
+///
+/// If length is negative then the sequence will stop that many elements from the end of the array.
+///
+/// Pass isize::MAX to get everything from offset up until the end of the array.
+///
+/// **preserve_keys**
+///
+/// array_slice() will reorder and reset the integer array indices by default. This behaviour can
+/// be changed by setting preserve_keys to true. String keys are always preserved, regardless of
+/// this parameter.
+///
+/// # Examples
+///
+/// Example #1 array_slice() examples
+///
+/// ```
+/// use phpify::array::array_slice;
+///
+/// let input = vec!["a", "b", "c", "d", "e"];
+///
+/// assert_eq!(array_slice(&input, 2, isize::MAX, false), ["c", "d", "e"]);
+/// assert_eq!(array_slice(&input, -2, 1, false), ["d"]);
+/// assert_eq!(array_slice(&input, 0, 3, false), ["a", "b", "c"]);
+/// ```
+///
+/// Example #2 array_slice() and one-based array
+///
+/// ```
+/// use phpify::array::{array_slice, Key, PhpArray};
+///
+/// let input: PhpArray<&str> = vec![(1, "a"), (2, "b"), (3, "c"), (4, "d")].into_iter().collect();
+///
+/// let result = array_slice(&input, 1, 2, false);
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::Int(0), "b"), (Key::Int(1), "c")]);
+///
+/// let result = array_slice(&input, 1, 2, true);
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::Int(2), "b"), (Key::Int(3), "c")]);
+/// ```
+///
+/// Example #3 array_slice() and array with mixed keys
+///
+/// ```
+/// use phpify::array::{array_slice, Key, PhpArray};
+///
+/// let mut input = PhpArray::new();
+/// input.insert("a", "apple");
+/// input.insert("b", "banana");
+/// input.insert("42", "pear");
+/// input.insert("d", "orange");
+///
+/// let result = array_slice(&input, 0, 3, false);
+///
+/// assert_eq!(result.keys().collect::<Vec<&Key>>(), [&Key::from("a"), &Key::from("b"), &Key::Int(0)]);
+/// ```
+pub fn array_slice<A>(array: &A, offset: isize, length: isize, preserve_keys: bool) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: ToValue + From<usize>,
+        A::Value: Clone {
+
+    let (start, end) = slice_bounds(array.len(), offset, length);
+
+    let entries = array.entries()
+        .skip(start)
+        .take(end - start)
+        .map(|(key, value)| (key, value.clone()));
+
+    if preserve_keys {
+        <A::Mapped<A::Value>>::from_entries(entries)
+    } else {
+        <A::Mapped<A::Value>>::from_entries(renumber(entries))
+    }
+}
+
+/// Resolves offset and length the way substr() does, into the start and end positions of the
+/// slice of an array of len elements.
+pub(crate) fn slice_bounds(len: usize, offset: isize, length: isize) -> (usize, usize) {
+    let len = len as isize;
+
+    let start = if offset < 0 { (len + offset).max(0) } else { offset.min(len) };
+
+    let length = if length < 0 {
+        (len - start + length).max(0)
+    } else {
+        length.min(len - start)
+    };
+
+    (start as usize, (start + length) as usize)
+}
+
+/// Renumbers the integer keys of the entries to start counting from zero, leaving string keys
+/// untouched.
+pub(crate) fn renumber<K, V>(entries: impl Iterator<Item = (K, V)>) -> impl Iterator<Item = (K, V)>
+    where
+        K: ToValue + From<usize> {
+
+    let mut next_index = 0;
+
+    entries.map(move |(key, value)| {
+        if let Value::String(_) = key.to_value() {
+            return (key, value);
+        }
+
+        next_index += 1;
+        (K::from(next_index - 1), value)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_slice, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let vec = vec![1, 2, 3, 4, 5];
+        assert_eq!(array_slice(&vec, 1, 3, false), [2, 3, 4]);
+        assert_eq!(array_slice(&vec, -3, -1, false), [3, 4]);
+        assert_eq!(array_slice(&vec, -10, 2, false), [1, 2]);
+        assert_eq!(array_slice(&vec, 5, 1, false), Vec::<i32>::new());
+        assert_eq!(array_slice(&vec, 10, 1, false), Vec::<i32>::new());
+        assert_eq!(array_slice(&vec, 3, -3, false), Vec::<i32>::new());
+        assert_eq!(array_slice(&vec, 0, 0, false), Vec::<i32>::new());
+
+        let array: PhpArray<i32> = vec![(5, 1), (-2, 2), (9, 3)].into_iter().collect();
+        let keys = |array: PhpArray<i32>| array.keys().cloned().collect::<Vec<Key>>();
+        assert_eq!(keys(array_slice(&array, -2, isize::MAX, false)), [Key::Int(0), Key::Int(1)]);
+        assert_eq!(keys(array_slice(&array, -2, isize::MAX, true)), [Key::Int(-2), Key::Int(9)]);
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::array_slice::{renumber, slice_bounds};
+use crate::array::ArrayLikeMut;
+use crate::value::ToValue;
+
+/// Remove a portion of the array and replace it with something else
+///
+/// # Description
+///
+/// Removes the elements designated by offset and length from the array, and replaces them with
+/// the elements of the replacement, if supplied. The removed elements are returned.
+///
+/// Integer keys in the array and in the returned elements are renumbered to start counting from
+/// zero, string keys are preserved. The keys of the replacement are not preserved.
+///
+/// # Parameters
+///
+/// **offset**
+///
+/// If offset is positive then the start of the removed portion is at that offset from the
+/// beginning of the array.
+///
+/// If offset is negative then the start of the removed portion is at that offset from the end of
+/// the array.
+///
+/// **length**
+///
+/// If length is specified and is positive, then that many elements will be removed.
+///
+/// If length is specified and is negative, then the end of the removed portion will be that many
+/// elements from the end of the array.
+///
+/// If length is specified and is zero, no elements will be removed.
+///
+/// Pass isize::MAX to remove everything from offset to the end of the array.
+///
+/// **replacement**
+///
+/// The removed elements are replaced with the elements from this array. If offset and length are
+/// such that nothing is removed, then the elements from the replacement are inserted in the place
+/// specified by the offset.
+///
+/// # Examples
+///
+/// Example #1 array_splice() examples
+///
+/// ```
+/// use phpify::array::array_splice;
+///
+/// let mut input = vec!["red", "green", "blue", "yellow"];
+/// array_splice(&mut input, 2, isize::MAX, vec![]);
+/// assert_eq!(input, ["red", "green"]);
+///
+/// let mut input = vec!["red", "green", "blue", "yellow"];
+/// array_splice(&mut input, 1, -1, vec![]);
+/// assert_eq!(input, ["red", "yellow"]);
+///
+/// let mut input = vec!["red", "green", "blue", "yellow"];
+/// array_splice(&mut input, 1, 4, vec!["orange"]);
+/// assert_eq!(input, ["red", "orange"]);
+///
+/// let mut input = vec!["red", "green", "blue", "yellow"];
+/// array_splice(&mut input, -1, 1, vec!["black", "maroon"]);
+/// assert_eq!(input, ["red", "green", "blue", "black", "maroon"]);
+/// ```
+///
+/// Example #2 Inserting an element at a position
+///
+/// ```
+/// use phpify::array::array_splice;
+///
+/// let mut input = vec!["red", "green", "blue", "yellow"];
+/// let removed = array_splice(&mut input, 3, 0, vec!["purple"]);
+///
+/// assert_eq!(input, ["red", "green", "blue", "purple", "yellow"]);
+/// assert!(removed.is_empty());
+/// ```
+///
+/// Example #3 array_splice() with string keys
+///
+/// ```
+/// use phpify::array::{array_splice, Key, PhpArray};
+///
+/// let mut input: PhpArray<&str> = vec![(3, "a"), (4, "b")].into_iter().collect();
+/// input.insert("c", "c");
+///
+/// let removed = array_splice(&mut input, 1, 1, vec!["x", "y"]);
+///
+/// assert_eq!(removed.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::Int(0), "b")]);
+/// assert_eq!(input.keys().collect::<Vec<&Key>>(), [&Key::Int(0), &Key::Int(1), &Key::Int(2), &Key::from("c")]);
+/// ```
+pub fn array_splice<A, I>(array: &mut A, offset: isize, length: isize, replacement: I) -> A
+    where
+        A: ArrayLikeMut,
+        A::Key: ToValue + From<usize>,
+        I: IntoIterator<Item = A::Value> {
+
+    let (start, end) = slice_bounds(array.len(), offset, length);
+
+    let mut entries = std::mem::replace(array, A::from_entries(std::iter::empty())).into_entries();
+    let tail = entries.split_off(end);
+    let removed = entries.split_off(start);
+
+    let replacement = replacement.into_iter().map(|value| (A::Key::from(0), value));
+
+    *array = A::from_entries(renumber(entries.into_iter().chain(replacement).chain(tail)));
+
+    A::from_entries(renumber(removed.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_splice, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let mut vec = vec![1, 2, 3, 4, 5];
+        assert_eq!(array_splice(&mut vec, -3, -1, vec![9]), [3, 4]);
+        assert_eq!(vec, [1, 2, 9, 5]);
+
+        let mut vec = vec![1, 2];
+        assert_eq!(array_splice(&mut vec, 5, 5, vec![3]), Vec::<i32>::new());
+        assert_eq!(vec, [1, 2, 3]);
+
+        let mut vec = vec![1, 2];
+        assert_eq!(array_splice(&mut vec, -5, 1, Vec::new()), [1]);
+        assert_eq!(vec, [2]);
+
+        let mut array = PhpArray::new();
+        array.insert("a", 1);
+        array.insert(7, 2);
+        array.insert("b", 3);
+        let removed = array_splice(&mut array, 0, 2, Vec::new());
+        assert_eq!(removed.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::from("a"), 1), (Key::Int(0), 2)]);
+        assert_eq!(array.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::from("b"), 3)]);
+    }
+}
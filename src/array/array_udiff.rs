@@ -0,0 +1,71 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+
+use crate::array::array_diff::by_user_value;
+use crate::array::ArrayLike;
+
+/// Computes the difference of arrays by using a callback function for data comparison
+///
+/// # Description
+///
+/// Computes the difference of arrays by using a callback function for data comparison. This is
+/// unlike array_diff() which uses an internal function for comparing the data. Keys in the
+/// array are preserved.
+///
+/// The comparison function must return Less, Equal or Greater if the first argument is
+/// considered to be respectively less than, equal to, or greater than the second. Like PHP, the
+/// other arrays are sorted with it, so it must define a consistent order.
+///
+/// # Examples
+///
+/// Example #1 array_udiff() example
+///
+/// ```
+/// use phpify::array::array_udiff;
+///
+/// let array1 = vec![(2, 1.5), (4, 2.0), (5, 3.0)];
+/// let array2 = vec![(2, 1.0), (5, 3.0), (6, 5.0)];
+///
+/// let result = array_udiff(&array1, &[&array2], |a, b| a.0.cmp(&b.0));
+///
+/// assert_eq!(result, [(4, 2.0)]);
+/// ```
+pub fn array_udiff<A>(array: &A, arrays: &[&A], value_compare_func: impl FnMut(&A::Value, &A::Value) -> Ordering) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone {
+
+    by_user_value(array, arrays, false, value_compare_func)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_udiff, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let array1 = vec!["Apple", "banana", "Cherry"];
+        let array2 = vec!["apple", "CHERRY"];
+        assert_eq!(array_udiff(&array1, &[&array2], |a, b| a.to_lowercase().cmp(&b.to_lowercase())), ["banana"]);
+
+        let array: PhpArray<i32> = vec![("x", 1), ("y", 2)].into_iter().collect();
+        let other = PhpArray::from(vec![2]);
+        assert_eq!(array_udiff(&array, &[&other], |a, b| a.cmp(b)).keys().collect::<Vec<&Key>>(), [&Key::from("x")]);
+    }
+}
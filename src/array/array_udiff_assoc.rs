@@ -0,0 +1,65 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use crate::array::array_diff::by_user_assoc;
+use crate::array::ArrayLike;
+use crate::value::ToValue;
+
+/// Computes the difference of arrays with additional index check, compares data by a callback function
+///
+/// # Description
+///
+/// Computes the difference of arrays with additional index check, compares data by a callback
+/// function. The keys are compared by equality, the values with the callback.
+///
+/// # Examples
+///
+/// Example #1 array_udiff_assoc() example
+///
+/// ```
+/// use phpify::array::{array_udiff_assoc, Key, PhpArray};
+///
+/// let array1: PhpArray<f64> = vec![("0.1", 9.0), ("0.5", 12.0), ("0", 23.0), ("1", 4.0), ("2", -15.0)].into_iter().collect();
+/// let array2: PhpArray<f64> = vec![("0.2", 9.0), ("0.5", 22.0), ("0", 3.0), ("1", 4.0), ("2", -15.0)].into_iter().collect();
+///
+/// let result = array_udiff_assoc(&array1, &[&array2], |a, b| a.partial_cmp(b).unwrap());
+///
+/// assert_eq!(result.keys().collect::<Vec<&Key>>(), [&Key::from("0.1"), &Key::from("0.5"), &Key::Int(0)]);
+/// ```
+pub fn array_udiff_assoc<A>(array: &A, arrays: &[&A], mut value_compare_func: impl FnMut(&A::Value, &A::Value) -> Ordering) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Hash + Eq,
+        A::Value: ToValue + Clone {
+
+    by_user_assoc(array, arrays, false, Some(&mut value_compare_func), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::array_udiff_assoc;
+
+    #[test]
+    fn test() {
+        let array1 = vec!["a", "B", "c"];
+        let array2 = vec!["A", "c", "C"];
+        assert_eq!(array_udiff_assoc(&array1, &[&array2], |a, b| a.to_lowercase().cmp(&b.to_lowercase())), ["B"]);
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use crate::array::array_diff::by_user_assoc;
+use crate::array::ArrayLike;
+use crate::value::ToValue;
+
+/// Computes the difference of arrays with additional index check, compares data and indexes by a callback function
+///
+/// # Description
+///
+/// Computes the difference of arrays with additional index check, compares data and indexes by
+/// separate callback functions. Every entry of array is compared with every entry of the other
+/// arrays, as no order can be relied on.
+///
+/// # Examples
+///
+/// Example #1 array_udiff_uassoc() example
+///
+/// ```
+/// use phpify::array::{array_udiff_uassoc, Key, PhpArray};
+///
+/// let array1: PhpArray<&str> = vec![("a", "green"), ("b", "brown"), ("c", "blue"), ("0", "red")].into_iter().collect();
+/// let array2: PhpArray<&str> = vec![("a", "GREEN"), ("B", "brown"), ("0", "yellow"), ("1", "red")].into_iter().collect();
+///
+/// let result = array_udiff_uassoc(
+///     &array1,
+///     &[&array2],
+///     |a, b| a.to_lowercase().cmp(&b.to_lowercase()),
+///     |a, b| a.to_string().to_lowercase().cmp(&b.to_string().to_lowercase()),
+/// );
+///
+/// assert_eq!(result.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::from("c"), "blue"), (Key::Int(0), "red")]);
+/// ```
+pub fn array_udiff_uassoc<A>(array: &A, arrays: &[&A], mut value_compare_func: impl FnMut(&A::Value, &A::Value) -> Ordering, mut key_compare_func: impl FnMut(&A::Key, &A::Key) -> Ordering) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Key: Hash + Eq,
+        A::Value: ToValue + Clone {
+
+    by_user_assoc(array, arrays, false, Some(&mut value_compare_func), Some(&mut key_compare_func))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::array_udiff_uassoc;
+
+    #[test]
+    fn test() {
+        let result = array_udiff_uassoc(&vec![1, 2, 3], &[&vec![3, 2, 1]], |a, b| a.cmp(b), |a, b| a.cmp(b));
+        assert_eq!(result, [1, 3]);
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+
+use crate::array::array_diff::by_user_value;
+use crate::array::ArrayLike;
+
+/// Computes the intersection of arrays, compares data by a callback function
+///
+/// # Description
+///
+/// Returns the values of array that are present in all the other arrays, comparing the data
+/// with a callback function. Keys in the array are preserved.
+///
+/// Like PHP, the other arrays are sorted with the callback, so it must define a consistent
+/// order.
+///
+/// # Examples
+///
+/// Example #1 array_uintersect() example
+///
+/// ```
+/// use phpify::array::{array_uintersect, Key, PhpArray};
+///
+/// let array1: PhpArray<&str> = vec![("a", "green"), ("b", "brown"), ("c", "blue"), ("0", "red")].into_iter().collect();
+/// let array2: PhpArray<&str> = vec![("a", "GREEN"), ("B", "brown"), ("0", "yellow"), ("1", "red")].into_iter().collect();
+///
+/// let result = array_uintersect(&array1, &[&array2], |a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+///
+/// assert_eq!(result.values().collect::<Vec<&&str>>(), [&"green", &"brown", &"red"]);
+/// ```
+pub fn array_uintersect<A>(array: &A, arrays: &[&A], value_compare_func: impl FnMut(&A::Value, &A::Value) -> Ordering) -> A::Mapped<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone {
+
+    by_user_value(array, arrays, true, value_compare_func)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::array_uintersect;
+
+    #[test]
+    fn test() {
+        assert_eq!(array_uintersect(&vec![5, 12, 7], &[&vec![2, 17]], |a, b| (a % 5).cmp(&(b % 5))), [12, 7]);
+    }
+}
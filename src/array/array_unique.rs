@@ -15,43 +15,156 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-/// Removes duplicate values from a vector
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::array::sort::sorted_order;
+use crate::array::{ArrayLike, ArrayLikeMut, SORT_NUMERIC, SORT_REGULAR};
+use crate::value::{ToValue, Value};
+
+/// Removes duplicate values from an array
 ///
 /// # Description
 ///
-/// Takes an input vector and returns a new vector without duplicate values.
+/// Takes an input array and returns a new array without duplicate values.
+///
+/// Note that keys are preserved. If multiple elements compare equal under the given flags, then the
+/// key and value of the first equal element will be retained.
+///
+/// The flags may be used to modify the comparison behavior:
+///
+/// - SORT_REGULAR - compare items normally (don't change types)
+/// - SORT_NUMERIC - compare items numerically
+/// - SORT_STRING - compare items as strings (this is the default in PHP)
+/// - SORT_LOCALE_STRING - compare items as strings, treated the same as SORT_STRING
 ///
 /// # Examples
 ///
 /// Example #1 array_unique() example
 ///
 /// ```
-/// use phpify::array::array_unique;
+/// use phpify::array::{array_unique, SORT_STRING};
 ///
 /// let input = vec!["green", "red", "green", "blue", "red"];
-/// assert_eq!(array_unique(&input), [&"green", &"red", &"blue"]);
+/// assert_eq!(array_unique(&input, SORT_STRING), ["green", "red", "blue"]);
+/// ```
+///
+/// Example #2 array_unique() with an associative array
+///
+/// ```
+/// use phpify::array::{array_unique, Key, PhpArray, SORT_STRING};
+///
+/// let mut input = PhpArray::new();
+/// input.insert("a", "green");
+/// input.insert(0, "red");
+/// input.insert("b", "green");
+/// input.insert(1, "blue");
+/// input.insert(2, "red");
+///
+/// let result = array_unique(&input, SORT_STRING);
+///
+/// assert_eq!(result.keys().collect::<Vec<_>>(), [&Key::from("a"), &Key::Int(0), &Key::Int(1)]);
+/// ```
+///
+/// Example #3 array_unique() and types
+///
+/// ```
+/// use phpify::array::{array_unique, SORT_REGULAR, SORT_STRING};
+/// use phpify::value::Value;
+///
+/// let input = vec![Value::from(4), Value::from("4"), Value::from("3"), Value::from(4), Value::from(3), Value::from("3")];
+/// assert_eq!(array_unique(&input, SORT_STRING), [Value::from(4), Value::from("3")]);
+/// assert_eq!(array_unique(&input, SORT_REGULAR), [Value::from(4), Value::from("3")]);
 /// ```
-pub fn array_unique<T>(array: &Vec<T>) -> Vec<&T>
+pub fn array_unique<A>(array: &A, flags: u32) -> A::Mapped<A::Value>
     where
-        T: PartialEq {
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue + Clone {
+
+    let values: Vec<Value> = array.entries().map(|(_, value)| value.to_value()).collect();
+    let mut keep = vec![true; values.len()];
 
-    let mut new_array = Vec::new();
+    if flags == SORT_REGULAR || flags == SORT_NUMERIC {
+        let compare = |a: &Value, b: &Value| -> Ordering {
+            if flags == SORT_REGULAR {
+                return a.compare(b);
+            }
+            match (a, b) {
+                (Value::Int(a), Value::Int(b)) => a.cmp(b),
+                _ => a.to_float().partial_cmp(&b.to_float()).unwrap_or(Ordering::Greater),
+            }
+        };
+
+        let sorted = sorted_order(values.len(), |a, b| compare(&values[a], &values[b]));
+
+        let mut iter = sorted.into_iter();
+        if let Some(mut last_kept) = iter.next() {
+            for index in iter {
+                if compare(&values[last_kept], &values[index]) != Ordering::Equal {
+                    last_kept = index;
+                } else if last_kept > index {
+                    keep[last_kept] = false;
+                    last_kept = index;
+                } else {
+                    keep[index] = false;
+                }
+            }
+        }
+    } else {
+        let mut seen = HashSet::new();
 
-    for value in array {
-        if !new_array.contains(&value) {
-            new_array.push(value);
+        for (index, value) in values.iter().enumerate() {
+            keep[index] = seen.insert(value.to_php_string());
         }
     }
 
-    new_array
+    <A::Mapped<A::Value>>::from_entries(array.entries()
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|((key, value), _)| (key, value.clone())))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::array::array_unique;
+    use crate::array::{array_unique, PhpArray, SORT_NUMERIC, SORT_REGULAR, SORT_STRING};
+    use crate::random::{Mt19937, MT_RAND_MT19937};
+    use crate::value::Value;
 
     #[test]
     fn test() {
-        assert_eq!(array_unique(&vec!["a", "b", "a", "c", "b"]), [&"a", &"b", &"c"]);
+        assert_eq!(array_unique(&vec!["a", "b", "a", "c", "b"], SORT_STRING), ["a", "b", "c"]);
+
+        let array = PhpArray::from(vec!["a", "b", "a", "c", "b"]);
+        let expected: PhpArray<&str> = vec![(0, "a"), (1, "b"), (3, "c")].into_iter().collect();
+        assert_eq!(array_unique(&array, SORT_STRING), expected);
+
+        let array = vec![Value::from("1"), Value::from("01"), Value::from(1), Value::from(1.0), Value::from("a")];
+        assert_eq!(array_unique(&array, SORT_STRING), [Value::from("1"), Value::from("01"), Value::from("a")]);
+        assert_eq!(array_unique(&array, SORT_REGULAR), [Value::from("1"), Value::from("a")]);
+        assert_eq!(array_unique(&array, SORT_NUMERIC), [Value::from("1"), Value::from("a")]);
+
+        let array = vec![Value::from("a"), Value::from("b"), Value::from(0)];
+        assert_eq!(array_unique(&array, SORT_NUMERIC), [Value::from("a")]);
+
+        let array = vec![Value::from(vec![1]), Value::from(vec!["1"]), Value::from(vec![2])];
+        assert_eq!(array_unique(&array, SORT_REGULAR), [Value::from(vec![1]), Value::from(vec![2])]);
+
+        let mixed = [
+            Value::from(f64::NAN), Value::from("abc"), Value::from("9"), Value::from("10"), Value::from("1e1"),
+            Value::from(""), Value::from(-3.5), Value::from(10), Value::from(0), Value::from(true), Value::Null,
+            Value::from(vec![1]), Value::Array(vec![("a", Value::from(1))].into_iter().collect()),
+        ];
+        for seed in 0..20 {
+            let mut engine = Mt19937::new(seed, MT_RAND_MT19937);
+            let length = engine.range(30, 130) as usize;
+            let array: Vec<Value> = (0..length)
+                .map(|_| mixed[engine.range(0, mixed.len() as i64 - 1) as usize].clone())
+                .collect();
+
+            for flags in [SORT_REGULAR, SORT_NUMERIC] {
+                let result = array_unique(&array, flags);
+                assert!(!result.is_empty() && result.len() < array.len());
+            }
+        }
     }
 }
@@ -15,11 +15,14 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-/// Prepend one element to the beginning of an vector
+use crate::array::ArrayLikeMut;
+
+/// Prepend one element to the beginning of an array
 ///
 /// # Description
 ///
-/// array_unshift() prepends passed element to the front of the vector.
+/// array_unshift() prepends passed element to the front of the array. All numerical array keys
+/// will be modified to start counting from zero while literal keys won't be changed.
 ///
 /// # Examples
 ///
@@ -33,18 +36,25 @@
 ///
 /// assert_eq!(queue, vec!["apple", "orange", "banana"]);
 /// ```
-pub fn array_unshift<T>(array: &mut Vec<T>, value: T) {
-    array.insert(0, value);
+pub fn array_unshift<A>(array: &mut A, value: A::Value)
+    where
+        A: ArrayLikeMut {
+
+    array.unshift(value);
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::array::array_unshift;
+    use crate::array::{array_unshift, PhpArray};
 
     #[test]
     fn test() {
         let mut vec = vec!["b", "c"];
         array_unshift(&mut vec, "a");
         assert_eq!(vec, vec!["a", "b", "c"]);
+
+        let mut array: PhpArray<&str> = vec![(3, "b"), (4, "c")].into_iter().collect();
+        array_unshift(&mut array, "a");
+        assert_eq!(array, PhpArray::from(vec!["a", "b", "c"]));
     }
 }
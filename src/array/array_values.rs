@@ -0,0 +1,59 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::ArrayLike;
+
+/// Return all the values of an array
+///
+/// # Description
+///
+/// array_values() returns all the values from the array and indexes the array numerically.
+///
+/// # Examples
+///
+/// Example #1 array_values() example
+///
+/// ```
+/// use phpify::array::{array_values, PhpArray};
+///
+/// let mut array = PhpArray::new();
+/// array.insert("size", "XL");
+/// array.insert("color", "gold");
+///
+/// assert_eq!(array_values(&array), ["XL", "gold"]);
+/// ```
+pub fn array_values<A>(array: &A) -> Vec<A::Value>
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: Clone {
+
+    array.entries().map(|(_, value)| value.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_values, PhpArray};
+
+    #[test]
+    fn test() {
+        assert_eq!(array_values(&vec![1, 2]), [1, 2]);
+        assert_eq!(array_values(&PhpArray::<i32>::new()), Vec::<i32>::new());
+
+        let array: PhpArray<char> = vec![(9, 'a'), (-1, 'b')].into_iter().collect();
+        assert_eq!(array_values(&array), ['a', 'b']);
+    }
+}
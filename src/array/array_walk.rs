@@ -15,20 +15,26 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-/// Apply a user supplied function to every member of a vector
+use std::ops::ControlFlow;
+
+use crate::array::ArrayLike;
+
+/// Apply a user supplied function to every member of an array
 ///
 /// # Description
 ///
-/// Applies the user-defined callback function to each element of the vector.
+/// Applies the user-defined callback function to each element of the array.
 ///
 /// **callback**
 ///
-/// Callback takes on two parameters. The vector parameter's value being the first, and the index
-/// second.
+/// Callback takes on two parameters. The array parameter's value being the first, and the key
+/// second. The callback may capture and modify local state.
 ///
-/// Only the values of the vector may potentially be changed, i.e., the programmer cannot add, unset
+/// Only the values of the array may potentially be changed, i.e., the programmer cannot add, unset
 /// or reorder elements.
 ///
+/// Use array_walk_with() to pass an extra argument to the callback or to stop walking early.
+///
 /// # Examples
 ///
 /// Example #1 array_walk() example
@@ -54,20 +60,84 @@
 /// assert_eq!(fruits[2], "fruit: banana");
 /// assert_eq!(fruits[3], "fruit: apple");
 /// ```
-pub fn array_walk<T>(array: &mut Vec<T>, callback: impl Fn(&mut T, usize) + 'static) {
-    for (index, value) in array.iter_mut().enumerate() {
-        callback(value, index);
+pub fn array_walk<A>(array: &mut A, mut callback: impl FnMut(&mut A::Value, A::Key))
+    where
+        A: ArrayLike + ?Sized {
+
+    for (key, value) in array.entries_mut() {
+        callback(value, key);
     }
 }
 
+/// Apply a user supplied function with an extra argument to every member of an array
+///
+/// # Description
+///
+/// Like array_walk(), but passes arg as the third parameter to the callback, the way PHP's optional
+/// arg parameter does.
+///
+/// The callback returns ControlFlow::Continue(()) to go on with the next element, or
+/// ControlFlow::Break(value) to stop walking. The result of the last callback is returned.
+///
+/// # Examples
+///
+/// Example #1 array_walk_with() example
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use phpify::array::array_walk_with;
+///
+/// let mut fruits = vec!["lemon".to_string(), "orange".to_string(), "banana".to_string()];
+///
+/// let result = array_walk_with(&mut fruits, |item, key, prefix| {
+///     *item = format!("{}: {}", prefix, item);
+///
+///     if key == 1 { ControlFlow::Break(key) } else { ControlFlow::Continue(()) }
+/// }, "fruit");
+///
+/// assert_eq!(result, ControlFlow::Break(1));
+/// assert_eq!(fruits, ["fruit: lemon", "fruit: orange", "banana"]);
+/// ```
+pub fn array_walk_with<A, T, B>(array: &mut A, mut callback: impl FnMut(&mut A::Value, A::Key, &T) -> ControlFlow<B>, arg: T) -> ControlFlow<B>
+    where
+        A: ArrayLike + ?Sized {
+
+    for (key, value) in array.entries_mut() {
+        callback(value, key, &arg)?;
+    }
+
+    ControlFlow::Continue(())
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::array::array_walk;
+    use std::ops::ControlFlow;
+
+    use crate::array::{array_walk, array_walk_with, Key, PhpArray};
 
     #[test]
     fn test() {
         let mut vec = vec![1, 2, 3];
-        array_walk(&mut vec, |value, index| *value = *value * index);
+        array_walk(&mut vec, |value, index| *value *= index);
         assert_eq!(vec, [0, 2, 6]);
+
+        let mut array: PhpArray<String> = vec![("a", "x".to_string())].into_iter().collect();
+        array_walk(&mut array, |value, key: Key| *value = format!("{}{}", key, value));
+        assert_eq!(array["a"], "ax");
+
+        let mut keys = Vec::new();
+        array_walk(&mut vec![1, 2], |_, key| keys.push(key));
+        assert_eq!(keys, [0, 1]);
+
+        let mut vec = vec![1, 2, 3];
+        let result: ControlFlow<()> = array_walk_with(&mut vec, |value, _, factor| { *value *= factor; ControlFlow::Continue(()) }, 10);
+        assert_eq!(result, ControlFlow::Continue(()));
+        assert_eq!(vec, [10, 20, 30]);
+
+        let result = array_walk_with(&mut vec, |value, key, limit| {
+            if *value > *limit { ControlFlow::Break(key) } else { *value = 0; ControlFlow::Continue(()) }
+        }, 15);
+        assert_eq!(result, ControlFlow::Break(1));
+        assert_eq!(vec, [0, 20, 30]);
     }
 }
@@ -0,0 +1,160 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::ops::ControlFlow;
+
+use crate::array::{ArrayLike, Key};
+use crate::value::Value;
+
+/// Apply a user function recursively to every member of an array
+///
+/// # Description
+///
+/// Applies the user-defined callback function to each element of the array. This function will
+/// recurse into deeper arrays: values that are arrays are not passed to the callback, their
+/// members are.
+///
+/// **callback**
+///
+/// Callback takes on two parameters. The value being the first, and the path of keys leading to
+/// the value second. The last key of the path is the key of the value in its own array.
+///
+/// Use array_walk_recursive_with() to pass an extra argument to the callback or to stop walking
+/// early.
+///
+/// # Examples
+///
+/// Example #1 array_walk_recursive() example
+///
+/// ```
+/// use phpify::array::{array_walk_recursive, PhpArray};
+/// use phpify::value::Value;
+///
+/// let sweet: PhpArray<Value> = vec![("a", Value::from("apple")), ("b", Value::from("banana"))].into_iter().collect();
+/// let fruits: PhpArray<Value> = vec![("sweet", Value::from(sweet)), ("sour", Value::from("lemon"))].into_iter().collect();
+///
+/// let mut copy = fruits.clone();
+/// let mut lines = Vec::new();
+///
+/// array_walk_recursive(&mut copy, |item, path| {
+///     lines.push(format!("{} holds {}", path.last().unwrap(), item));
+/// });
+///
+/// assert_eq!(lines, ["a holds apple", "b holds banana", "sour holds lemon"]);
+/// ```
+pub fn array_walk_recursive<A>(array: &mut A, mut callback: impl FnMut(&mut Value, &[Key]))
+    where
+        A: ArrayLike<Value = Value> + ?Sized,
+        A::Key: Into<Key> {
+
+    let _: ControlFlow<()> = array_walk_recursive_with(array, |value, path, _| {
+        callback(value, path);
+        ControlFlow::Continue(())
+    }, ());
+}
+
+/// Apply a user function with an extra argument recursively to every member of an array
+///
+/// # Description
+///
+/// Like array_walk_recursive(), but passes arg as the third parameter to the callback, the way
+/// PHP's optional arg parameter does.
+///
+/// The callback returns ControlFlow::Continue(()) to go on with the next element, or
+/// ControlFlow::Break(value) to stop walking. The result of the last callback is returned.
+///
+/// # Examples
+///
+/// Example #1 array_walk_recursive_with() example
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use phpify::array::{array_walk_recursive_with, Key};
+/// use phpify::value::Value;
+///
+/// let mut matrix = vec![Value::from(vec![1, 2]), Value::from(vec![3, 4])];
+///
+/// let found = array_walk_recursive_with(&mut matrix, |item, path, needle| {
+///     if item.loose_eq(needle) { ControlFlow::Break(path.to_vec()) } else { ControlFlow::Continue(()) }
+/// }, Value::from(3));
+///
+/// assert_eq!(found, ControlFlow::Break(vec![Key::Int(1), Key::Int(0)]));
+/// ```
+pub fn array_walk_recursive_with<A, T, B>(array: &mut A, mut callback: impl FnMut(&mut Value, &[Key], &T) -> ControlFlow<B>, arg: T) -> ControlFlow<B>
+    where
+        A: ArrayLike<Value = Value> + ?Sized,
+        A::Key: Into<Key> {
+
+    let mut path = Vec::new();
+
+    for (key, value) in array.entries_mut() {
+        path.push(key.into());
+        walk(value, &mut path, &mut callback, &arg)?;
+        path.pop();
+    }
+
+    ControlFlow::Continue(())
+}
+
+fn walk<T, B>(value: &mut Value, path: &mut Vec<Key>, callback: &mut impl FnMut(&mut Value, &[Key], &T) -> ControlFlow<B>, arg: &T) -> ControlFlow<B> {
+    let array = match value {
+        Value::Array(array) => array,
+        _ => return callback(value, path, arg),
+    };
+
+    for (key, value) in array.iter_mut() {
+        path.push(key.clone());
+        walk(value, path, callback, arg)?;
+        path.pop();
+    }
+
+    ControlFlow::Continue(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use crate::array::{array_walk_recursive, array_walk_recursive_with, Key, PhpArray};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        let inner: PhpArray<Value> = vec![("x", Value::from(1)), ("y", Value::from(vec![2, 3]))].into_iter().collect();
+        let mut array: PhpArray<Value> = vec![("a", Value::from(inner)), ("b", Value::from(4)), ("c", Value::from(PhpArray::new()))].into_iter().collect();
+
+        let mut paths = Vec::new();
+        array_walk_recursive(&mut array, |value, path| {
+            *value = Value::from(value.to_int() * 10);
+            paths.push(path.iter().map(|key| key.to_string()).collect::<Vec<String>>().join("."));
+        });
+        assert_eq!(paths, ["a.x", "a.y.0", "a.y.1", "b"]);
+
+        let mut sum = 0;
+        array_walk_recursive(&mut array, |value, _| sum += value.to_int());
+        assert_eq!(sum, 100);
+
+        let result = array_walk_recursive_with(&mut array, |value, path, limit| {
+            if value.to_int() >= *limit { ControlFlow::Break(path.to_vec()) } else { ControlFlow::Continue(()) }
+        }, 20);
+        assert_eq!(result, ControlFlow::Break(vec![Key::from("a"), Key::from("y"), Key::Int(0)]));
+
+        let mut vec = vec![Value::from(1), Value::from(vec![2])];
+        array_walk_recursive(&mut vec, |value, _| *value = Value::Null);
+        assert_eq!(vec, [Value::Null, Value::from(vec![Value::Null])]);
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::sort::sort_values;
+use crate::array::ArrayLikeMut;
+use crate::value::ToValue;
+
+/// Sort an array in descending order and maintain index association
+///
+/// # Description
+///
+/// Sorts array in place in descending order, such that its keys maintain their correlation with the
+/// values they are associated with. Elements that compare equal keep their original order.
+///
+/// Vectors are keyed by position, so for them this is the same as rsort().
+///
+/// The flags modify the comparison behavior, see sort().
+///
+/// # Examples
+///
+/// Example #1 arsort() example
+///
+/// ```
+/// use phpify::array::{arsort, Key, PhpArray, SORT_REGULAR};
+///
+/// let mut fruits: PhpArray<&str> = vec![("d", "lemon"), ("a", "orange"), ("b", "banana"), ("c", "apple")]
+///     .into_iter()
+///     .collect();
+///
+/// arsort(&mut fruits, SORT_REGULAR);
+///
+/// assert_eq!(fruits.keys().collect::<Vec<&Key>>(), [&Key::from("a"), &Key::from("d"), &Key::from("b"), &Key::from("c")]);
+/// ```
+pub fn arsort<A>(array: &mut A, flags: u32)
+    where
+        A: ArrayLikeMut,
+        A::Value: ToValue {
+
+    sort_values(array, flags, true, true);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{arsort, Key, PhpArray, SORT_REGULAR};
+
+    #[test]
+    fn test() {
+        let mut array: PhpArray<i32> = vec![("a", 1), ("b", 2), ("c", 1), ("d", 3)].into_iter().collect();
+        arsort(&mut array, SORT_REGULAR);
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::from("d"), &Key::from("b"), &Key::from("a"), &Key::from("c")]);
+
+        let mut array = vec![1, 3, 2];
+        arsort(&mut array, SORT_REGULAR);
+        assert_eq!(array, [3, 2, 1]);
+    }
+}
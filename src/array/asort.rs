@@ -0,0 +1,77 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::sort::sort_values;
+use crate::array::ArrayLikeMut;
+use crate::value::ToValue;
+
+/// Sort an array in ascending order and maintain index association
+///
+/// # Description
+///
+/// Sorts array in place in ascending order, such that its keys maintain their correlation with the
+/// values they are associated with. This is used mainly when sorting associative arrays where the
+/// actual element order is significant. Elements that compare equal keep their original order.
+///
+/// Vectors are keyed by position, so for them this is the same as sort().
+///
+/// The flags modify the comparison behavior, see sort().
+///
+/// # Examples
+///
+/// Example #1 asort() example
+///
+/// ```
+/// use phpify::array::{asort, Key, PhpArray, SORT_REGULAR};
+///
+/// let mut fruits: PhpArray<&str> = vec![("d", "lemon"), ("a", "orange"), ("b", "banana"), ("c", "apple")]
+///     .into_iter()
+///     .collect();
+///
+/// asort(&mut fruits, SORT_REGULAR);
+///
+/// assert_eq!(fruits.keys().collect::<Vec<&Key>>(), [&Key::from("c"), &Key::from("b"), &Key::from("d"), &Key::from("a")]);
+/// ```
+pub fn asort<A>(array: &mut A, flags: u32)
+    where
+        A: ArrayLikeMut,
+        A::Value: ToValue {
+
+    sort_values(array, flags, false, true);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{asort, Key, PhpArray, SORT_NUMERIC, SORT_STRING};
+
+    #[test]
+    fn test() {
+        let mut array: PhpArray<&str> = vec![(5, "10"), (3, "9"), (8, "1e1")].into_iter().collect();
+        asort(&mut array, SORT_NUMERIC);
+        assert_eq!(array.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::Int(3), "9"), (Key::Int(5), "10"), (Key::Int(8), "1e1")]);
+
+        let mut array: PhpArray<&str> = vec![(5, "10"), (3, "9")].into_iter().collect();
+        asort(&mut array, SORT_STRING);
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::Int(5), &Key::Int(3)]);
+        array.push("x");
+        assert!(array.contains_key(6));
+
+        let mut array = vec![2, 1];
+        asort(&mut array, SORT_NUMERIC);
+        assert_eq!(array, [1, 2]);
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Compare items normally (don't change types).
+pub const SORT_REGULAR: u32 = 0;
+
+/// Compare items numerically.
+pub const SORT_NUMERIC: u32 = 1;
+
+/// Compare items as strings.
+pub const SORT_STRING: u32 = 2;
+
+/// Compare items as strings, based on the current locale.
+pub const SORT_LOCALE_STRING: u32 = 5;
+
+/// Compare items as strings using "natural ordering" like natsort().
+pub const SORT_NATURAL: u32 = 6;
+
+/// Can be combined (bitwise OR) with SORT_STRING or SORT_NATURAL to sort strings case-insensitively.
+pub const SORT_FLAG_CASE: u32 = 8;
+
+/// Sort in ascending order.
+pub const SORT_ASC: u32 = 4;
+
+/// Sort in descending order.
+pub const SORT_DESC: u32 = 3;
@@ -0,0 +1,78 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{array_search, ArrayLike};
+use crate::value::ToValue;
+
+/// Checks if a value exists in an array
+///
+/// # Description
+///
+/// Searches for needle in haystack.
+///
+/// If strict is true, then in_array() will also check the types of the needle in the haystack.
+/// Otherwise values are compared loosely, using PHP's comparison rules.
+///
+/// # Examples
+///
+/// Example #1 in_array() example
+///
+/// ```
+/// use phpify::array::in_array;
+///
+/// let os = vec!["Mac", "NT", "Irix", "Linux"];
+/// assert!(in_array(&"Irix", &os, false));
+/// assert!(!in_array(&"mac", &os, false));
+/// ```
+///
+/// Example #2 in_array() with strict example
+///
+/// ```
+/// use phpify::array::in_array;
+/// use phpify::value::Value;
+///
+/// let a = vec![Value::from("1.10"), Value::from(12.4), Value::from(1.13)];
+/// assert!(!in_array(&Value::from("12.4"), &a, true));
+/// assert!(in_array(&Value::from(1.13), &a, true));
+/// assert!(in_array(&Value::from("12.4"), &a, false));
+/// ```
+pub fn in_array<A>(needle: &A::Value, haystack: &A, strict: bool) -> bool
+    where
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue {
+
+    array_search(needle, haystack, strict).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{in_array, PhpArray};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        assert!(in_array(&"a", &vec!["a", "b", "c"], false));
+        assert!(!in_array(&"d", &vec!["a", "b", "c"], false));
+        assert!(in_array(&1, &PhpArray::from(vec![1, 2, 3]), true));
+
+        let array = vec![Value::from("1e1"), Value::Null];
+        assert!(in_array(&Value::from(10), &array, false));
+        assert!(!in_array(&Value::from(10), &array, true));
+        assert!(in_array(&Value::from(0), &array, false));
+        assert!(!in_array(&Value::from(0), &array, true));
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::fmt;
+
+use crate::string::PhpString;
+use crate::value::Value;
+
+/// The key of a PhpArray entry.
+///
+/// # Description
+///
+/// A key may either be an integer or a string. Strings containing a valid decimal integer, unless
+/// the number is preceded by a + sign or contains leading zeros, will be cast to the integer type.
+/// E.g. the key "8" will actually be stored under 8. On the other hand "08" will not be cast, as it
+/// isn't a valid decimal integer.
+///
+/// String keys are binary-safe PhpStrings.
+///
+/// Bools are cast to integers, too, i.e. the key true will actually be stored under 1 and the key
+/// false under 0.
+///
+/// # Examples
+///
+/// Example #1 Type casting
+///
+/// ```
+/// use phpify::array::Key;
+///
+/// assert_eq!(Key::from("8"), Key::Int(8));
+/// assert_eq!(Key::from("08"), Key::Str("08".into()));
+/// assert_eq!(Key::from(true), Key::Int(1));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    Int(i64),
+    Str(PhpString),
+}
+
+impl Key {
+    /// Returns true if the key is an integer.
+    pub fn is_int(&self) -> bool {
+        matches!(self, Key::Int(_))
+    }
+
+    /// Returns true if the key is a string.
+    pub fn is_str(&self) -> bool {
+        matches!(self, Key::Str(_))
+    }
+
+    /// Converts a value to a key the way array_combine() and array_fill_keys() do: integers are
+    /// used as they are, other values are converted to strings first.
+    pub(crate) fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Int(int) => Key::Int(*int),
+            value => Key::from(value.to_php_string()),
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Key::Int(int) => write!(f, "{}", int),
+            Key::Str(str) => write!(f, "{}", str),
+        }
+    }
+}
+
+impl From<PhpString> for Key {
+    fn from(string: PhpString) -> Self {
+        match parse_integer_key(&string) {
+            Some(int) => Key::Int(int),
+            None => Key::Str(string),
+        }
+    }
+}
+
+impl From<&PhpString> for Key {
+    fn from(string: &PhpString) -> Self {
+        match parse_integer_key(string) {
+            Some(int) => Key::Int(int),
+            None => Key::Str(string.clone()),
+        }
+    }
+}
+
+impl From<&[u8]> for Key {
+    fn from(bytes: &[u8]) -> Self {
+        Key::from(PhpString::from(bytes))
+    }
+}
+
+impl From<String> for Key {
+    fn from(string: String) -> Self {
+        Key::from(PhpString::from(string))
+    }
+}
+
+impl From<&str> for Key {
+    fn from(string: &str) -> Self {
+        Key::from(PhpString::from(string))
+    }
+}
+
+impl From<&String> for Key {
+    fn from(string: &String) -> Self {
+        Key::from(string.as_str())
+    }
+}
+
+impl From<&Key> for Key {
+    fn from(key: &Key) -> Self {
+        key.clone()
+    }
+}
+
+impl From<bool> for Key {
+    fn from(bool: bool) -> Self {
+        Key::Int(bool as i64)
+    }
+}
+
+macro_rules! impl_from_int_for_key {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Key {
+                fn from(int: $int) -> Self {
+                    Key::Int(int as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int_for_key!(i8, i16, i32, i64, isize, u8, u16, u32, usize);
+
+/// Parses a string that is a canonical decimal integer, the way PHP does for array keys.
+fn parse_integer_key(string: &[u8]) -> Option<i64> {
+    let digits = string.strip_prefix(b"-").unwrap_or(string);
+
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    if digits[0] == b'0' && (digits.len() > 1 || digits.len() != string.len()) {
+        return None;
+    }
+
+    std::str::from_utf8(string).ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::Key;
+
+    #[test]
+    fn test() {
+        assert_eq!(Key::from("5"), Key::Int(5));
+        assert_eq!(Key::from("-5"), Key::Int(-5));
+        assert_eq!(Key::from("0"), Key::Int(0));
+        assert_eq!(Key::from("-0"), Key::Str("-0".into()));
+        assert_eq!(Key::from("05"), Key::Str("05".into()));
+        assert_eq!(Key::from("+5"), Key::Str("+5".into()));
+        assert_eq!(Key::from(" 5"), Key::Str(" 5".into()));
+        assert_eq!(Key::from("5.0"), Key::Str("5.0".into()));
+        assert_eq!(Key::from(""), Key::Str("".into()));
+        assert_eq!(Key::from("9223372036854775807"), Key::Int(i64::MAX));
+        assert_eq!(Key::from("-9223372036854775808"), Key::Int(i64::MIN));
+        assert_eq!(Key::from("9223372036854775808"), Key::Str("9223372036854775808".into()));
+        assert_eq!(Key::from(false), Key::Int(0));
+        assert_eq!(Key::from(3usize), Key::Int(3));
+        assert_eq!(Key::from("a").to_string(), "a");
+        assert_eq!(Key::from(-1).to_string(), "-1");
+        assert_eq!(Key::from(&b"\xff"[..]), Key::Str(b"\xff"[..].into()));
+    }
+}
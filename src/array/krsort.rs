@@ -0,0 +1,69 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::sort::sort_keys;
+use crate::array::ArrayLikeMut;
+use crate::value::ToValue;
+
+/// Sort an array by key in descending order
+///
+/// # Description
+///
+/// Sorts array in place by keys in descending order.
+///
+/// Vectors are keyed by position, so for them this reverses the order of the elements.
+///
+/// The flags modify the comparison behavior, see sort().
+///
+/// # Examples
+///
+/// Example #1 krsort() example
+///
+/// ```
+/// use phpify::array::{krsort, PhpArray, SORT_REGULAR};
+///
+/// let mut fruits: PhpArray<&str> = vec![("d", "lemon"), ("a", "orange"), ("b", "banana"), ("c", "apple")]
+///     .into_iter()
+///     .collect();
+///
+/// krsort(&mut fruits, SORT_REGULAR);
+///
+/// assert_eq!(fruits.values().collect::<Vec<&&str>>(), [&"lemon", &"apple", &"banana", &"orange"]);
+/// ```
+pub fn krsort<A>(array: &mut A, flags: u32)
+    where
+        A: ArrayLikeMut,
+        A::Key: ToValue {
+
+    sort_keys(array, flags, true);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{krsort, Key, PhpArray, SORT_REGULAR};
+
+    #[test]
+    fn test() {
+        let mut array: PhpArray<i32> = vec![("b", 1), ("10", 2), ("9", 3)].into_iter().collect();
+        krsort(&mut array, SORT_REGULAR);
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::from("b"), &Key::Int(10), &Key::Int(9)]);
+
+        let mut array = vec![3, 1, 2];
+        krsort(&mut array, SORT_REGULAR);
+        assert_eq!(array, [2, 1, 3]);
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::sort::sort_keys;
+use crate::array::ArrayLikeMut;
+use crate::value::ToValue;
+
+/// Sort an array by key in ascending order
+///
+/// # Description
+///
+/// Sorts array in place by keys in ascending order. Like PHP 8, integer and string keys are
+/// compared with the regular comparison rules, so numeric string keys sort among the integers.
+///
+/// Vectors are keyed by position and are always sorted by key.
+///
+/// The flags modify the comparison behavior, see sort().
+///
+/// # Examples
+///
+/// Example #1 ksort() example
+///
+/// ```
+/// use phpify::array::{ksort, Key, PhpArray, SORT_REGULAR};
+///
+/// let mut fruits: PhpArray<&str> = vec![("d", "lemon"), ("a", "orange"), ("b", "banana"), ("c", "apple")]
+///     .into_iter()
+///     .collect();
+///
+/// ksort(&mut fruits, SORT_REGULAR);
+///
+/// assert_eq!(fruits.values().collect::<Vec<&&str>>(), [&"orange", &"banana", &"apple", &"lemon"]);
+/// ```
+///
+/// Example #2 ksort() with int keys
+///
+/// ```
+/// use phpify::array::{ksort, Key, PhpArray, SORT_REGULAR};
+///
+/// let mut a: PhpArray<&str> = vec![("0", "Last"), ("1", "First"), ("2", "Middle")].into_iter().collect();
+/// let mut b: PhpArray<&str> = vec![("-1", "Last"), ("0", "First"), ("1", "Middle")].into_iter().collect();
+///
+/// ksort(&mut a, SORT_REGULAR);
+/// ksort(&mut b, SORT_REGULAR);
+///
+/// assert_eq!(a.keys().collect::<Vec<&Key>>(), [&Key::Int(0), &Key::Int(1), &Key::Int(2)]);
+/// assert_eq!(b.keys().collect::<Vec<&Key>>(), [&Key::Int(-1), &Key::Int(0), &Key::Int(1)]);
+/// ```
+pub fn ksort<A>(array: &mut A, flags: u32)
+    where
+        A: ArrayLikeMut,
+        A::Key: ToValue {
+
+    sort_keys(array, flags, false);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{ksort, Key, PhpArray, SORT_NATURAL, SORT_NUMERIC, SORT_REGULAR, SORT_STRING};
+
+    #[test]
+    fn test() {
+        let mut array: PhpArray<i32> = vec![("b", 1), ("10", 2), ("9", 3), ("a", 4), ("1e1", 5)].into_iter().collect();
+        ksort(&mut array, SORT_REGULAR);
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::Int(9), &Key::Int(10), &Key::from("1e1"), &Key::from("a"), &Key::from("b")]);
+
+        ksort(&mut array, SORT_STRING);
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::Int(10), &Key::from("1e1"), &Key::Int(9), &Key::from("a"), &Key::from("b")]);
+
+        ksort(&mut array, SORT_NUMERIC);
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::from("a"), &Key::from("b"), &Key::Int(9), &Key::Int(10), &Key::from("1e1")]);
+
+        let mut array: PhpArray<i32> = vec![("x10", 1), ("x9", 2)].into_iter().collect();
+        ksort(&mut array, SORT_NATURAL);
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::from("x9"), &Key::from("x10")]);
+
+        let mut array = vec![3, 1, 2];
+        ksort(&mut array, SORT_REGULAR);
+        assert_eq!(array, [3, 1, 2]);
+    }
+}
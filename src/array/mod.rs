@@ -15,20 +15,132 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+mod array_chunk;
+mod array_column;
+mod array_combine;
+mod array_diff;
+mod array_diff_assoc;
+mod array_diff_key;
+mod array_diff_uassoc;
+mod array_diff_ukey;
+mod array_fill;
+mod array_fill_keys;
+mod array_filter;
+mod array_flip;
+mod array_intersect;
+mod array_intersect_assoc;
+mod array_intersect_key;
+mod array_intersect_uassoc;
+mod array_intersect_ukey;
+mod array_keys;
+mod array_like;
+mod array_map;
+mod array_merge;
+mod array_merge_recursive;
+mod array_multisort;
+mod array_pad;
 mod array_pop;
 mod array_push;
 mod array_rand;
+mod array_reduce;
+mod array_replace;
+mod array_replace_recursive;
 mod array_search;
 mod array_shift;
+mod array_slice;
+mod array_splice;
+mod array_udiff;
+mod array_udiff_assoc;
+mod array_udiff_uassoc;
+mod array_uintersect;
+mod array_uintersect_assoc;
+mod array_uintersect_uassoc;
 mod array_unique;
 mod array_unshift;
+mod array_values;
 mod array_walk;
+mod array_walk_recursive;
+mod arsort;
+mod asort;
+mod constants;
+mod in_array;
+mod key;
+mod krsort;
+mod ksort;
+mod natcasesort;
+mod natsort;
+mod php_array;
+mod range;
+mod rsort;
+mod shuffle;
+mod sort;
+mod uasort;
+mod uksort;
+mod usort;
 
+pub(crate) use array_rand::pick_keys;
+pub(crate) use shuffle::{shuffle_slice, shuffle_slice_with};
+pub(crate) use sort::{sort_slice, sort_slice_by};
+
+pub use array_chunk::array_chunk;
+pub use array_column::{array_column, array_column_indexed, array_column_rows, Record};
+pub use array_combine::array_combine;
+pub use array_diff::array_diff;
+pub use array_diff_assoc::array_diff_assoc;
+pub use array_diff_key::array_diff_key;
+pub use array_diff_uassoc::array_diff_uassoc;
+pub use array_diff_ukey::array_diff_ukey;
+pub use array_fill::array_fill;
+pub use array_fill_keys::array_fill_keys;
+pub use array_filter::{array_filter, array_filter_both, array_filter_key, array_filter_truthy};
+pub use array_flip::array_flip;
+pub use array_intersect::array_intersect;
+pub use array_intersect_assoc::array_intersect_assoc;
+pub use array_intersect_key::array_intersect_key;
+pub use array_intersect_uassoc::array_intersect_uassoc;
+pub use array_intersect_ukey::array_intersect_ukey;
+pub use array_keys::{array_keys, array_keys_search};
+pub use array_like::{ArrayLike, ArrayLikeMut, Entries, EntriesMut};
+pub use array_map::{array_map, array_map_multiple, array_map_zip};
+pub use array_merge::array_merge;
+pub use array_merge_recursive::array_merge_recursive;
+pub use array_multisort::{array_multisort, SortColumn};
+pub use array_pad::array_pad;
 pub use array_pop::array_pop;
 pub use array_push::array_push;
-pub use array_rand::array_rand;
+pub use array_rand::{array_rand, array_rand_with};
+pub use array_reduce::array_reduce;
+pub use array_replace::array_replace;
+pub use array_replace_recursive::array_replace_recursive;
 pub use array_search::array_search;
 pub use array_shift::array_shift;
+pub use array_slice::array_slice;
+pub use array_splice::array_splice;
+pub use array_udiff::array_udiff;
+pub use array_udiff_assoc::array_udiff_assoc;
+pub use array_udiff_uassoc::array_udiff_uassoc;
+pub use array_uintersect::array_uintersect;
+pub use array_uintersect_assoc::array_uintersect_assoc;
+pub use array_uintersect_uassoc::array_uintersect_uassoc;
 pub use array_unique::array_unique;
 pub use array_unshift::array_unshift;
-pub use array_walk::array_walk;
+pub use array_values::array_values;
+pub use array_walk::{array_walk, array_walk_with};
+pub use array_walk_recursive::{array_walk_recursive, array_walk_recursive_with};
+pub use arsort::arsort;
+pub use asort::asort;
+pub use constants::{SORT_ASC, SORT_DESC, SORT_FLAG_CASE, SORT_LOCALE_STRING, SORT_NATURAL, SORT_NUMERIC, SORT_REGULAR, SORT_STRING};
+pub use in_array::in_array;
+pub use key::Key;
+pub use krsort::krsort;
+pub use ksort::ksort;
+pub use natcasesort::natcasesort;
+pub use natsort::natsort;
+pub use php_array::{IntoIter, Iter, IterMut, Keys, PhpArray, Values, ValuesMut};
+pub use range::range;
+pub use rsort::rsort;
+pub use shuffle::shuffle;
+pub use sort::sort;
+pub use uasort::uasort;
+pub use uksort::uksort;
+pub use usort::usort;
@@ -0,0 +1,62 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::sort::sort_values;
+use crate::array::{ArrayLikeMut, SORT_FLAG_CASE, SORT_NATURAL};
+use crate::value::ToValue;
+
+/// Sort an array using a case insensitive "natural order" algorithm
+///
+/// # Description
+///
+/// This function implements a sort algorithm that orders alphanumeric strings in the way a human
+/// being would while maintaining key/value associations. This is described as a "natural ordering".
+/// The values are compared as strings with strnatcasecmp().
+///
+/// Vectors are keyed by position, so for them the keys are not preserved.
+///
+/// # Examples
+///
+/// Example #1 natcasesort() example
+///
+/// ```
+/// use phpify::array::natcasesort;
+///
+/// let mut array = vec!["IMG0.png", "img12.png", "img10.png", "img2.png", "img1.png", "IMG3.png"];
+/// natcasesort(&mut array);
+///
+/// assert_eq!(array, ["IMG0.png", "img1.png", "img2.png", "IMG3.png", "img10.png", "img12.png"]);
+/// ```
+pub fn natcasesort<A>(array: &mut A)
+    where
+        A: ArrayLikeMut,
+        A::Value: ToValue {
+
+    sort_values(array, SORT_NATURAL | SORT_FLAG_CASE, false, true);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{natcasesort, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let mut array: PhpArray<&str> = vec![("x", "b2"), ("y", "B1"), ("z", "a10")].into_iter().collect();
+        natcasesort(&mut array);
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::from("z"), &Key::from("y"), &Key::from("x")]);
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::sort::sort_values;
+use crate::array::{ArrayLikeMut, SORT_NATURAL};
+use crate::value::ToValue;
+
+/// Sort an array using a "natural order" algorithm
+///
+/// # Description
+///
+/// This function implements a sort algorithm that orders alphanumeric strings in the way a human
+/// being would while maintaining key/value associations. This is described as a "natural ordering".
+/// The values are compared as strings with strnatcmp(), which is case sensitive.
+///
+/// Vectors are keyed by position, so for them the keys are not preserved.
+///
+/// # Examples
+///
+/// Example #1 natsort() examples demonstrating basic usage
+///
+/// ```
+/// use phpify::array::{natsort, Key, PhpArray};
+///
+/// let mut array1 = PhpArray::from(vec!["img12.png", "img10.png", "img2.png", "img1.png"]);
+/// natsort(&mut array1);
+///
+/// assert_eq!(array1.values().collect::<Vec<&&str>>(), [&"img1.png", &"img2.png", &"img10.png", &"img12.png"]);
+/// assert_eq!(array1.keys().collect::<Vec<&Key>>(), [&Key::Int(3), &Key::Int(2), &Key::Int(1), &Key::Int(0)]);
+/// ```
+///
+/// Example #2 natsort() examples demonstrating potential gotchas
+///
+/// ```
+/// use phpify::array::natsort;
+///
+/// let mut negative = vec!["-5", "3", "-2", "0", "-1000", "9", "1"];
+/// natsort(&mut negative);
+///
+/// assert_eq!(negative, ["-2", "-5", "-1000", "0", "1", "3", "9"]);
+///
+/// let mut zeros = vec!["09", "8", "10", "009", "011", "0"];
+/// natsort(&mut zeros);
+///
+/// assert_eq!(zeros, ["0", "8", "09", "009", "10", "011"]);
+/// ```
+pub fn natsort<A>(array: &mut A)
+    where
+        A: ArrayLikeMut,
+        A::Value: ToValue {
+
+    sort_values(array, SORT_NATURAL, false, true);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{natsort, Key, PhpArray};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        let mut array: PhpArray<&str> = vec![("a", "IMG0.png"), ("b", "img12.png"), ("c", "img10.png"), ("d", "img2.png")].into_iter().collect();
+        natsort(&mut array);
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::from("a"), &Key::from("d"), &Key::from("c"), &Key::from("b")]);
+
+        let mut array = vec![Value::from(10), Value::from("9"), Value::from(1.5)];
+        natsort(&mut array);
+        assert_eq!(array, [Value::from(1.5), Value::from("9"), Value::from(10)]);
+    }
+}
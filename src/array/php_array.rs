@@ -0,0 +1,786 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{Add, AddAssign, Index, IndexMut};
+
+use crate::array::Key;
+
+/// The largest number of elements PHP allows in an array.
+pub(crate) const MAX_SIZE: usize = 0x4000_0000;
+
+/// An ordered map, the way PHP implements its arrays.
+///
+/// # Description
+///
+/// A PhpArray associates values to keys. The keys can either be integers or strings (see Key),
+/// numeric strings are normalized to integers. Entries keep the order in which they were inserted
+/// and lookups by key take constant time.
+///
+/// The array keeps track of the next free integer key, which is one more than the largest integer
+/// key ever used. Pushing a value without a key uses that next free key.
+///
+/// # Examples
+///
+/// Example #1 A simple array
+///
+/// ```
+/// use phpify::array::PhpArray;
+///
+/// let mut array = PhpArray::new();
+/// array.insert("foo", "bar");
+/// array.insert("bar", "foo");
+///
+/// assert_eq!(array["foo"], "bar");
+/// assert_eq!(array["bar"], "foo");
+/// ```
+///
+/// Example #2 Type casting and overwriting
+///
+/// ```
+/// use phpify::array::PhpArray;
+///
+/// let mut array = PhpArray::new();
+/// array.insert(1, "a");
+/// array.insert("1", "b");
+/// array.insert(true, "c");
+///
+/// assert_eq!(array.len(), 1);
+/// assert_eq!(array[1], "c");
+/// ```
+///
+/// Example #3 Mixed integer and string keys
+///
+/// ```
+/// use phpify::array::{Key, PhpArray};
+///
+/// let mut array = PhpArray::new();
+/// array.insert("foo", "bar");
+/// array.insert(42, "24");
+/// array.push("last");
+///
+/// let keys: Vec<&Key> = array.keys().collect();
+///
+/// assert_eq!(keys, [&Key::from("foo"), &Key::Int(42), &Key::Int(43)]);
+/// ```
+#[derive(Clone)]
+pub struct PhpArray<V> {
+    entries: Vec<Option<(Key, V)>>,
+    indices: HashMap<Key, usize>,
+    len: usize,
+    next_index: i64,
+}
+
+impl<V> PhpArray<V> {
+    /// Creates an empty array.
+    pub fn new() -> Self {
+        PhpArray {
+            entries: Vec::new(),
+            indices: HashMap::new(),
+            len: 0,
+            next_index: i64::MIN,
+        }
+    }
+
+    /// Creates an empty array with space for at least capacity entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        PhpArray {
+            entries: Vec::with_capacity(capacity),
+            indices: HashMap::with_capacity(capacity),
+            len: 0,
+            next_index: i64::MIN,
+        }
+    }
+
+    /// Returns the number of entries in the array.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the array contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the key that push() will use for the next value.
+    pub fn next_index(&self) -> i64 {
+        if self.next_index == i64::MIN {
+            return 0;
+        }
+
+        self.next_index
+    }
+
+    /// Returns true if the array contains a value for the key.
+    pub fn contains_key<K>(&self, key: K) -> bool
+        where
+            K: Into<Key> {
+
+        self.indices.contains_key(&key.into())
+    }
+
+    /// Returns a reference to the value stored under the key.
+    pub fn get<K>(&self, key: K) -> Option<&V>
+        where
+            K: Into<Key> {
+
+        let position = *self.indices.get(&key.into())?;
+
+        self.entries[position].as_ref().map(|(_, value)| value)
+    }
+
+    /// Returns a mutable reference to the value stored under the key.
+    pub fn get_mut<K>(&mut self, key: K) -> Option<&mut V>
+        where
+            K: Into<Key> {
+
+        let position = *self.indices.get(&key.into())?;
+
+        self.entries[position].as_mut().map(|(_, value)| value)
+    }
+
+    /// Stores a value under the key, returning the value that was stored under it before.
+    ///
+    /// Overwriting a value keeps the position of the entry.
+    pub fn insert<K>(&mut self, key: K, value: V) -> Option<V>
+        where
+            K: Into<Key> {
+
+        let key = key.into();
+
+        if let Some(&position) = self.indices.get(&key) {
+            let entry = self.entries[position].as_mut().expect("indexed entry");
+            return Some(std::mem::replace(&mut entry.1, value));
+        }
+
+        if let Key::Int(int) = key {
+            if int >= self.next_index {
+                self.next_index = int.saturating_add(1);
+            }
+        }
+
+        self.indices.insert(key.clone(), self.entries.len());
+        self.entries.push(Some((key, value)));
+        self.len += 1;
+
+        None
+    }
+
+    /// Appends a value using the next free integer key, and returns that key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the next free key is already occupied, which only happens after i64::MAX has been
+    /// used as a key.
+    pub fn push(&mut self, value: V) -> Key {
+        let key = Key::Int(self.next_index());
+
+        if self.indices.contains_key(&key) {
+            panic!("Cannot add element to the array as the next element is already occupied");
+        }
+
+        self.insert(key.clone(), value);
+
+        key
+    }
+
+    /// Removes the entry stored under the key, returning its value.
+    pub fn remove<K>(&mut self, key: K) -> Option<V>
+        where
+            K: Into<Key> {
+
+        let position = self.indices.remove(&key.into())?;
+        let (_, value) = self.entries[position].take().expect("indexed entry");
+        self.len -= 1;
+
+        self.trim();
+
+        Some(value)
+    }
+
+    /// Removes the last entry, returning its value.
+    ///
+    /// If the key of the last entry is the most recently assigned integer key, the next free key is
+    /// decremented, so the key gets reused by the next push().
+    pub fn pop(&mut self) -> Option<V> {
+        let (key, value) = loop {
+            match self.entries.pop()? {
+                Some(entry) => break entry,
+                None => continue,
+            }
+        };
+
+        self.indices.remove(&key);
+        self.len -= 1;
+
+        if let Key::Int(int) = key {
+            if self.next_index != i64::MIN && int == self.next_index - 1 {
+                self.next_index -= 1;
+            }
+        }
+
+        self.trim();
+
+        Some(value)
+    }
+
+    /// Removes the first entry, returning its value.
+    ///
+    /// All integer keys are renumbered to start counting from zero, string keys are left untouched.
+    pub fn shift(&mut self) -> Option<V> {
+        let position = self.entries.iter().position(Option::is_some)?;
+        let (_, value) = self.entries[position].take().expect("existing entry");
+        self.len -= 1;
+
+        self.renumber();
+
+        Some(value)
+    }
+
+    /// Prepends a value.
+    ///
+    /// All integer keys are renumbered to start counting from zero, string keys are left untouched.
+    pub fn unshift(&mut self, value: V) {
+        self.entries.insert(0, Some((Key::Int(0), value)));
+        self.len += 1;
+
+        self.renumber();
+    }
+
+    /// Removes all entries, and resets the next free key.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.indices.clear();
+        self.len = 0;
+        self.next_index = i64::MIN;
+    }
+
+    /// Returns the first entry.
+    pub fn first(&self) -> Option<(&Key, &V)> {
+        self.iter().next()
+    }
+
+    /// Returns the last entry.
+    pub fn last(&self) -> Option<(&Key, &V)> {
+        self.iter().next_back()
+    }
+
+    /// Returns true if the keys are consecutive integers counting from zero, in order.
+    pub fn is_list(&self) -> bool {
+        self.keys().enumerate().all(|(index, key)| *key == Key::Int(index as i64))
+    }
+
+    /// Returns an iterator over the entries, in order.
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter { entries: self.entries.iter(), len: self.len }
+    }
+
+    /// Returns an iterator over the entries with mutable references to the values, in order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        IterMut { entries: self.entries.iter_mut(), len: self.len }
+    }
+
+    /// Returns an iterator over the keys, in order.
+    pub fn keys(&self) -> Keys<'_, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Returns an iterator over the values, in order.
+    pub fn values(&self) -> Values<'_, V> {
+        Values { iter: self.iter() }
+    }
+
+    /// Returns an iterator over mutable references to the values, in order.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, V> {
+        ValuesMut { iter: self.iter_mut() }
+    }
+
+    /// Drops the trailing holes, and compacts the entries once they are mostly holes.
+    fn trim(&mut self) {
+        while let Some(None) = self.entries.last() {
+            self.entries.pop();
+        }
+
+        if self.entries.len() > 8 && self.entries.len() > self.len * 2 {
+            self.entries.retain(Option::is_some);
+            self.reindex();
+        }
+    }
+
+    /// Renumbers the integer keys to start counting from zero.
+    fn renumber(&mut self) {
+        let mut next_index = 0;
+
+        self.entries.retain(Option::is_some);
+
+        for (key, _) in self.entries.iter_mut().flatten() {
+            if key.is_int() {
+                *key = Key::Int(next_index);
+                next_index += 1;
+            }
+        }
+
+        self.next_index = next_index;
+        self.reindex();
+    }
+
+    fn reindex(&mut self) {
+        self.indices.clear();
+
+        for (position, entry) in self.entries.iter().enumerate() {
+            if let Some((key, _)) = entry {
+                self.indices.insert(key.clone(), position);
+            }
+        }
+    }
+}
+
+impl<V> Default for PhpArray<V> {
+    fn default() -> Self {
+        PhpArray::new()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for PhpArray<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Arrays are equal when they contain the same key/value pairs in the same order.
+impl<V: PartialEq> PartialEq for PhpArray<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<K, V> Index<K> for PhpArray<V>
+    where
+        K: Into<Key> {
+
+    type Output = V;
+
+    fn index(&self, key: K) -> &V {
+        self.get(key).expect("undefined array key")
+    }
+}
+
+impl<K, V> IndexMut<K> for PhpArray<V>
+    where
+        K: Into<Key> {
+
+    fn index_mut(&mut self, key: K) -> &mut V {
+        self.get_mut(key).expect("undefined array key")
+    }
+}
+
+impl<V> From<Vec<V>> for PhpArray<V> {
+    fn from(vec: Vec<V>) -> Self {
+        let mut array = PhpArray::with_capacity(vec.len());
+
+        for value in vec {
+            array.push(value);
+        }
+
+        array
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for PhpArray<V>
+    where
+        K: Into<Key> {
+
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut array = PhpArray::new();
+        array.extend(iter);
+
+        array
+    }
+}
+
+impl<K, V> Extend<(K, V)> for PhpArray<V>
+    where
+        K: Into<Key> {
+
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// The union operator: the entries of the right-hand array whose key is not used in the left-hand
+/// array are appended to it. For keys that exist in both arrays, the left-hand entry is kept.
+///
+/// # Examples
+///
+/// ```
+/// use phpify::array::{Key, PhpArray};
+///
+/// let a: PhpArray<&str> = vec![("a", "apple"), ("b", "banana")].into_iter().collect();
+/// let b: PhpArray<&str> = vec![("a", "pear"), ("b", "strawberry"), ("c", "cherry")].into_iter().collect();
+///
+/// let c = a + b;
+///
+/// assert_eq!(c.into_iter().collect::<Vec<(Key, &str)>>(), [
+///     (Key::from("a"), "apple"),
+///     (Key::from("b"), "banana"),
+///     (Key::from("c"), "cherry"),
+/// ]);
+/// ```
+impl<V> Add for PhpArray<V> {
+    type Output = PhpArray<V>;
+
+    fn add(mut self, other: PhpArray<V>) -> PhpArray<V> {
+        self += other;
+        self
+    }
+}
+
+impl<V: Clone> Add for &PhpArray<V> {
+    type Output = PhpArray<V>;
+
+    fn add(self, other: &PhpArray<V>) -> PhpArray<V> {
+        let mut array = self.clone();
+
+        for (key, value) in other {
+            if !array.contains_key(key) {
+                array.insert(key.clone(), value.clone());
+            }
+        }
+
+        array
+    }
+}
+
+impl<V> AddAssign for PhpArray<V> {
+    fn add_assign(&mut self, other: PhpArray<V>) {
+        for (key, value) in other {
+            if !self.contains_key(&key) {
+                self.insert(key, value);
+            }
+        }
+    }
+}
+
+impl<V> IntoIterator for PhpArray<V> {
+    type Item = (Key, V);
+    type IntoIter = IntoIter<V>;
+
+    fn into_iter(self) -> IntoIter<V> {
+        IntoIter { entries: self.entries.into_iter(), len: self.len }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a PhpArray<V> {
+    type Item = (&'a Key, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Iter<'a, V> {
+        self.iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a mut PhpArray<V> {
+    type Item = (&'a Key, &'a mut V);
+    type IntoIter = IterMut<'a, V>;
+
+    fn into_iter(self) -> IterMut<'a, V> {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over the entries of a PhpArray.
+#[derive(Clone)]
+pub struct Iter<'a, V> {
+    entries: std::slice::Iter<'a, Option<(Key, V)>>,
+    len: usize,
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (&'a Key, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.entries.by_ref().flatten().next()?;
+        self.len -= 1;
+
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, V> DoubleEndedIterator for Iter<'a, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.entries.by_ref().rev().flatten().next()?;
+        self.len -= 1;
+
+        Some((key, value))
+    }
+}
+
+impl<'a, V> ExactSizeIterator for Iter<'a, V> {}
+
+/// A mutable iterator over the entries of a PhpArray.
+pub struct IterMut<'a, V> {
+    entries: std::slice::IterMut<'a, Option<(Key, V)>>,
+    len: usize,
+}
+
+impl<'a, V> Iterator for IterMut<'a, V> {
+    type Item = (&'a Key, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.entries.by_ref().flatten().next()?;
+        self.len -= 1;
+
+        Some((&*key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, V> DoubleEndedIterator for IterMut<'a, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.entries.by_ref().rev().flatten().next()?;
+        self.len -= 1;
+
+        Some((&*key, value))
+    }
+}
+
+impl<'a, V> ExactSizeIterator for IterMut<'a, V> {}
+
+/// An owning iterator over the entries of a PhpArray.
+pub struct IntoIter<V> {
+    entries: std::vec::IntoIter<Option<(Key, V)>>,
+    len: usize,
+}
+
+impl<V> Iterator for IntoIter<V> {
+    type Item = (Key, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.by_ref().flatten().next()?;
+        self.len -= 1;
+
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<V> DoubleEndedIterator for IntoIter<V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.by_ref().rev().flatten().next()?;
+        self.len -= 1;
+
+        Some(entry)
+    }
+}
+
+impl<V> ExactSizeIterator for IntoIter<V> {}
+
+/// An iterator over the keys of a PhpArray.
+#[derive(Clone)]
+pub struct Keys<'a, V> {
+    iter: Iter<'a, V>,
+}
+
+impl<'a, V> Iterator for Keys<'a, V> {
+    type Item = &'a Key;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, V> DoubleEndedIterator for Keys<'a, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<'a, V> ExactSizeIterator for Keys<'a, V> {}
+
+/// An iterator over the values of a PhpArray.
+#[derive(Clone)]
+pub struct Values<'a, V> {
+    iter: Iter<'a, V>,
+}
+
+impl<'a, V> Iterator for Values<'a, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, V> DoubleEndedIterator for Values<'a, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<'a, V> ExactSizeIterator for Values<'a, V> {}
+
+/// A mutable iterator over the values of a PhpArray.
+pub struct ValuesMut<'a, V> {
+    iter: IterMut<'a, V>,
+}
+
+impl<'a, V> Iterator for ValuesMut<'a, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, V> DoubleEndedIterator for ValuesMut<'a, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<'a, V> ExactSizeIterator for ValuesMut<'a, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let mut array = PhpArray::new();
+        array.insert("a", 1);
+        array.insert("5", 2);
+        array.push(3);
+        array.insert(-10, 4);
+
+        assert_eq!(array.len(), 4);
+        assert_eq!(array.get("a"), Some(&1));
+        assert_eq!(array.get(5), Some(&2));
+        assert_eq!(array.get(6), Some(&3));
+        assert_eq!(array.get("-10"), Some(&4));
+        assert_eq!(array.get("b"), None);
+        assert_eq!(array.next_index(), 7);
+
+        assert_eq!(array.insert("a", 5), Some(1));
+        assert_eq!(array.first(), Some((&Key::from("a"), &5)));
+        assert_eq!(array.last(), Some((&Key::Int(-10), &4)));
+
+        assert_eq!(array.remove(5), Some(2));
+        assert_eq!(array.remove(5), None);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.values().collect::<Vec<_>>(), [&5, &3, &4]);
+        assert_eq!(array.iter().rev().map(|(_, value)| *value).collect::<Vec<_>>(), [4, 3, 5]);
+    }
+
+    #[test]
+    fn test_next_index() {
+        let mut array = PhpArray::new();
+        array.insert(-5, "a");
+        array.push("b");
+        assert_eq!(array.get(-4), Some(&"b"));
+
+        let mut array = PhpArray::new();
+        array.push("a");
+        array.push("b");
+        array.remove(1);
+        array.push("c");
+        assert_eq!(array.keys().collect::<Vec<_>>(), [&Key::Int(0), &Key::Int(2)]);
+
+        assert_eq!(array.pop(), Some("c"));
+        array.push("d");
+        assert_eq!(array.get(2), Some(&"d"));
+    }
+
+    #[test]
+    fn test_shift_unshift() {
+        let mut array: PhpArray<&str> = vec![(5, "a"), (6, "b")].into_iter().collect();
+        array.insert("x", "c");
+        array.insert(9, "d");
+
+        assert_eq!(array.shift(), Some("a"));
+        assert_eq!(array.keys().collect::<Vec<_>>(), [&Key::Int(0), &Key::from("x"), &Key::Int(1)]);
+        assert_eq!(array.next_index(), 2);
+
+        array.unshift("z");
+        assert_eq!(array.keys().collect::<Vec<_>>(), [&Key::Int(0), &Key::Int(1), &Key::from("x"), &Key::Int(2)]);
+        assert_eq!(array.values().collect::<Vec<_>>(), [&"z", &"b", &"c", &"d"]);
+    }
+
+    #[test]
+    fn test_holes() {
+        let mut array = PhpArray::from((0..100).collect::<Vec<_>>());
+
+        for index in 0..90 {
+            array.remove(index);
+        }
+
+        assert_eq!(array.len(), 10);
+        assert_eq!(array.get(95), Some(&95));
+        assert_eq!(array.values().copied().collect::<Vec<_>>(), (90..100).collect::<Vec<_>>());
+        assert!(!array.is_list());
+        assert!(PhpArray::from(vec![1, 2]).is_list());
+    }
+
+    #[test]
+    fn test_union() {
+        let a = PhpArray::from(vec![1, 2]);
+        let b = PhpArray::from(vec![3, 4, 5]);
+
+        assert_eq!(&a + &b, PhpArray::from(vec![1, 2, 5]));
+        assert_eq!(&b + &a, b);
+
+        let mut c: PhpArray<i32> = vec![("x", 1)].into_iter().collect();
+        c += vec![("7", 2), ("x", 3)].into_iter().collect();
+        assert_eq!(c.keys().collect::<Vec<_>>(), [&Key::from("x"), &Key::Int(7)]);
+        assert_eq!(c.next_index(), 8);
+    }
+
+    #[test]
+    fn test_eq() {
+        let a: PhpArray<i32> = vec![("a", 1), ("b", 2)].into_iter().collect();
+        let b: PhpArray<i32> = vec![("b", 2), ("a", 1)].into_iter().collect();
+
+        assert_eq!(a, a.clone());
+        assert_ne!(a, b);
+        assert_eq!(format!("{:?}", a), r#"{Str("a"): 1, Str("b"): 2}"#);
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::php_array::MAX_SIZE;
+use crate::error::PhpError;
+use crate::string::PhpString;
+use crate::value::{parse_numeric_whole, Number, ToValue, Value};
+
+/// A bound of the range, as given by the start or end argument.
+#[derive(Clone, Copy)]
+enum Bound {
+    Int(i64),
+    Float(f64),
+    Char(u8),
+}
+
+/// Create an array containing a range of elements
+///
+/// # Description
+///
+/// Create an array containing a range of elements.
+///
+/// If both start and end are strings, and step is an integer, the produced array will be a
+/// sequence of bytes. Otherwise, the produced array will be a sequence of numbers.
+///
+/// The sequence is increasing if start is less than or equal to end. Otherwise, the sequence is
+/// decreasing.
+///
+/// # Parameters
+///
+/// **start**
+///
+/// First value of the sequence.
+///
+/// **end**
+///
+/// Last possible value of the sequence.
+///
+/// **step**
+///
+/// step indicates by how much is the produced sequence progressed between values of the sequence.
+///
+/// step may be negative for decreasing sequences.
+///
+/// If step is a float without a fractional part, it is interpreted as an integer.
+///
+/// # Return Values
+///
+/// Returns a sequence of elements as a list with the first element being start going up to end,
+/// with each value of the sequence being step values apart.
+///
+/// The last element of the returned array is either end or the previous element of the sequence,
+/// depending on the value of step.
+///
+/// If both start and end are strings, and step is an integer the produced array will be a sequence
+/// of bytes, converted to strings lossily.
+///
+/// If start, end, or step is a float, the produced array will be a sequence of floats. The number
+/// of elements is rounded half up, so that floating point errors do not lose the last element.
+///
+/// Otherwise, the produced array will be a sequence of integers.
+///
+/// Like PHP, an empty string is converted to 0, only the first byte of a non-numeric string is
+/// used, and a non-numeric string is converted to 0 if the other bound is a number.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if step is 0, if step exceeds the range, if step is negative for an
+/// increasing range, if a float is not finite, or if the range has too many elements. Returns
+/// PhpError::TypeError if start or end is not a string, integer or float, or step is not numeric.
+///
+/// # Examples
+///
+/// Example #1 range() examples
+///
+/// ```
+/// use phpify::array::range;
+/// use phpify::value::Value;
+///
+/// assert_eq!(range(0, 12, 1).unwrap(), (0..=12).map(Value::from).collect::<Vec<Value>>());
+/// assert_eq!(range(0, 100, 10).unwrap(), (0..=10).map(|i| Value::from(i * 10)).collect::<Vec<Value>>());
+/// assert_eq!(range("a", "i", 1).unwrap(), ["a", "b", "c", "d", "e", "f", "g", "h", "i"].map(Value::from));
+/// assert_eq!(range("c", "a", 1).unwrap(), ["c", "b", "a"].map(Value::from));
+/// assert_eq!(range("A", "z", 1).unwrap().len(), 58);
+/// assert_eq!(range(0, 1, 0.25).unwrap(), [0.0, 0.25, 0.5, 0.75, 1.0].map(Value::from));
+/// assert_eq!(range(5, 1, -2).unwrap(), [5, 3, 1].map(Value::from));
+/// ```
+pub fn range<S, E, T>(start: S, end: E, step: T) -> Result<Vec<Value>, PhpError>
+    where
+        S: ToValue,
+        E: ToValue,
+        T: ToValue {
+
+    let (step, is_step_float, is_step_negative) = match step.to_value() {
+        Value::Float(float) => {
+            if !float.is_finite() {
+                return Err(PhpError::ValueError(format!("range(): Argument #3 ($step) must be a finite number, {} provided", Value::Float(float))));
+            }
+
+            (float.abs(), float.fract() != 0.0, float < 0.0)
+        }
+        Value::String(string) => match parse_numeric_whole(string.as_bytes()) {
+            Some(Number::Int(int)) => (int.unsigned_abs() as f64, false, int < 0),
+            Some(Number::Float(float)) => (float.abs(), float.fract() != 0.0, float < 0.0),
+            None => return Err(PhpError::TypeError("range(): Argument #3 ($step) must be of type int|float, string given".to_string())),
+        },
+        Value::Array(_) => return Err(PhpError::TypeError("range(): Argument #3 ($step) must be of type int|float, array given".to_string())),
+        Value::Object(object) => return Err(PhpError::TypeError(format!("range(): Argument #3 ($step) must be of type int|float, {} given", object.class_name()))),
+        value => {
+            let int = value.to_int();
+            (int.unsigned_abs() as f64, false, int < 0)
+        }
+    };
+
+    if step == 0.0 {
+        return Err(PhpError::ValueError("range(): Argument #3 ($step) cannot be 0".to_string()));
+    }
+
+    let mut start = bound(start.to_value(), 1, "start")?;
+    let mut end = bound(end.to_value(), 2, "end")?;
+
+    match (start, end) {
+        (Bound::Char(_), Bound::Char(_)) if is_step_float => {
+            start = Bound::Int(0);
+            end = Bound::Int(0);
+        }
+        (Bound::Char(low), Bound::Char(high)) => return char_range(low, high, step as u64, is_step_negative),
+        (Bound::Char(_), _) => start = Bound::Int(0),
+        (_, Bound::Char(_)) => end = Bound::Int(0),
+        _ => {}
+    }
+
+    match (start, end) {
+        (Bound::Int(start), Bound::Int(end)) if !is_step_float => int_range(start, end, step as u64, is_step_negative),
+        _ => float_range(to_float(start), to_float(end), step, is_step_negative),
+    }
+}
+
+fn bound(value: Value, position: usize, name: &str) -> Result<Bound, PhpError> {
+    match value {
+        Value::Float(float) if !float.is_finite() => {
+            Err(PhpError::ValueError(format!("range(): Argument #{} (${}) must be a finite number, {} provided", position, name, Value::Float(float))))
+        }
+        Value::Float(float) => Ok(Bound::Float(float)),
+        Value::String(string) if string.is_empty() => Ok(Bound::Int(0)),
+        Value::String(string) => match parse_numeric_whole(string.as_bytes()) {
+            Some(Number::Int(int)) => Ok(Bound::Int(int)),
+            Some(Number::Float(float)) => Ok(Bound::Float(float)),
+            None => Ok(Bound::Char(string.as_bytes()[0])),
+        },
+        Value::Array(_) => {
+            Err(PhpError::TypeError(format!("range(): Argument #{} (${}) must be of type string|int|float, array given", position, name)))
+        }
+        Value::Object(object) => {
+            Err(PhpError::TypeError(format!("range(): Argument #{} (${}) must be of type string|int|float, {} given", position, name, object.class_name())))
+        }
+        value => Ok(Bound::Int(value.to_int())),
+    }
+}
+
+fn to_float(bound: Bound) -> f64 {
+    match bound {
+        Bound::Int(int) => int as f64,
+        Bound::Float(float) => float,
+        Bound::Char(_) => 0.0,
+    }
+}
+
+fn char_range(low: u8, high: u8, step: u64, is_step_negative: bool) -> Result<Vec<Value>, PhpError> {
+    let char_value = |byte: u8| Value::String(PhpString::from(vec![byte]));
+
+    if low > high {
+        if u64::from(low - high) < step {
+            return Err(step_exceeds_range());
+        }
+
+        return Ok((u64::from(high)..=u64::from(low)).rev().step_by(step as usize).map(|byte| char_value(byte as u8)).collect());
+    }
+
+    if high > low {
+        if is_step_negative {
+            return Err(negative_step());
+        }
+
+        if u64::from(high - low) < step {
+            return Err(step_exceeds_range());
+        }
+
+        return Ok((u64::from(low)..=u64::from(high)).step_by(step as usize).map(|byte| char_value(byte as u8)).collect());
+    }
+
+    Ok(vec![char_value(low)])
+}
+
+fn int_range(start: i64, end: i64, step: u64, is_step_negative: bool) -> Result<Vec<Value>, PhpError> {
+    if start == end {
+        return Ok(vec![Value::Int(start)]);
+    }
+
+    if start < end && is_step_negative {
+        return Err(negative_step());
+    }
+
+    let distance = (i128::from(end) - i128::from(start)).unsigned_abs();
+
+    if distance < u128::from(step) {
+        return Err(step_exceeds_range());
+    }
+
+    let size = distance / u128::from(step) + 1;
+
+    if size >= MAX_SIZE as u128 {
+        return Err(PhpError::ValueError(format!(
+            "The supplied range exceeds the maximum array size by {} elements: start={}, end={}, step={}",
+            size - MAX_SIZE as u128, start, end, step,
+        )));
+    }
+
+    let direction: i128 = if start < end { 1 } else { -1 };
+
+    Ok((0..size as i128).map(|index| Value::Int((i128::from(start) + direction * index * i128::from(step)) as i64)).collect())
+}
+
+fn float_range(start: f64, end: f64, step: f64, is_step_negative: bool) -> Result<Vec<Value>, PhpError> {
+    if start == end {
+        return Ok(vec![Value::Float(start)]);
+    }
+
+    if start < end && is_step_negative {
+        return Err(negative_step());
+    }
+
+    let distance = (end - start).abs();
+
+    if distance < step {
+        return Err(step_exceeds_range());
+    }
+
+    let size = (distance / step + 1.0).round();
+
+    if size >= MAX_SIZE as f64 {
+        return Err(PhpError::ValueError(format!(
+            "The supplied range exceeds the maximum array size by {:.0} elements: start={:.1}, end={:.1}, step={:.1}",
+            size - MAX_SIZE as f64, start, end, step,
+        )));
+    }
+
+    let direction = if start < end { 1.0 } else { -1.0 };
+
+    Ok((0..size as u64)
+        .map(|index| start + direction * index as f64 * step)
+        .take_while(|&element| if start < end { element <= end } else { element >= end })
+        .map(Value::Float)
+        .collect())
+}
+
+fn step_exceeds_range() -> PhpError {
+    PhpError::ValueError("range(): Argument #3 ($step) must not exceed the specified range".to_string())
+}
+
+fn negative_step() -> PhpError {
+    PhpError::ValueError("range(): Argument #3 ($step) must be greater than 0 for increasing ranges".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::range;
+    use crate::error::PhpError;
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        assert_eq!(range(1, 3, 1), Ok(vec![Value::Int(1), Value::Int(2), Value::Int(3)]));
+        assert_eq!(range(3, 1, 1), Ok(vec![Value::Int(3), Value::Int(2), Value::Int(1)]));
+        assert_eq!(range(3, 1, -1), Ok(vec![Value::Int(3), Value::Int(2), Value::Int(1)]));
+        assert_eq!(range(1, 6, 2.0), Ok(vec![Value::Int(1), Value::Int(3), Value::Int(5)]));
+        assert_eq!(range(2, 2, 5), Ok(vec![Value::Int(2)]));
+        assert_eq!(range("1", "3", 1), Ok(vec![Value::Int(1), Value::Int(2), Value::Int(3)]));
+        assert_eq!(range(1, 2, 0.5), Ok(vec![Value::Float(1.0), Value::Float(1.5), Value::Float(2.0)]));
+        assert_eq!(range(1.0, 2, 1), Ok(vec![Value::Float(1.0), Value::Float(2.0)]));
+        assert_eq!(range(0, 1, 0.1).unwrap().len(), 11);
+        assert_eq!(range(0.3, 0.0, 0.1).unwrap().len(), 3);
+        assert_eq!(range(i64::MIN, i64::MIN + 2, 1).unwrap().len(), 3);
+
+        assert_eq!(range("a", "e", 2), Ok(vec![Value::from("a"), Value::from("c"), Value::from("e")]));
+        assert_eq!(range("e", "a", -2), Ok(vec![Value::from("e"), Value::from("c"), Value::from("a")]));
+        assert_eq!(range("abc", "b", 1), Ok(vec![Value::from("a"), Value::from("b")]));
+        assert_eq!(range("a", 2, 1), Ok(vec![Value::Int(0), Value::Int(1), Value::Int(2)]));
+        assert_eq!(range("", 1, 1), Ok(vec![Value::Int(0), Value::Int(1)]));
+        assert_eq!(range("a", "b", 0.5), Ok(vec![Value::Float(0.0)]));
+
+        assert_eq!(range(1, 2, 0), Err(PhpError::ValueError("range(): Argument #3 ($step) cannot be 0".to_string())));
+        assert_eq!(range(1, 2, 2), Err(PhpError::ValueError("range(): Argument #3 ($step) must not exceed the specified range".to_string())));
+        assert_eq!(range(1, 2, -1), Err(PhpError::ValueError("range(): Argument #3 ($step) must be greater than 0 for increasing ranges".to_string())));
+        assert_eq!(range(1, 2, "x"), Err(PhpError::TypeError("range(): Argument #3 ($step) must be of type int|float, string given".to_string())));
+        assert_eq!(range(f64::NAN, 2, 1), Err(PhpError::ValueError("range(): Argument #1 ($start) must be a finite number, NAN provided".to_string())));
+        assert_eq!(
+            range(0, i64::MAX, 1),
+            Err(PhpError::ValueError("The supplied range exceeds the maximum array size by 9223372035781033984 elements: start=0, end=9223372036854775807, step=1".to_string())),
+        );
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::sort::sort_values;
+use crate::array::ArrayLikeMut;
+use crate::value::ToValue;
+
+/// Sort an array in descending order
+///
+/// # Description
+///
+/// Sorts array in place by values in descending order. Elements that compare equal keep their
+/// original order.
+///
+/// This function assigns new keys to the elements in array. It will remove any existing keys that
+/// may have been assigned, rather than just reordering the keys.
+///
+/// The flags modify the comparison behavior, see sort().
+///
+/// # Examples
+///
+/// Example #1 rsort() example
+///
+/// ```
+/// use phpify::array::{rsort, SORT_REGULAR};
+///
+/// let mut fruits = vec!["lemon", "orange", "banana", "apple"];
+/// rsort(&mut fruits, SORT_REGULAR);
+///
+/// assert_eq!(fruits, ["orange", "lemon", "banana", "apple"]);
+/// ```
+pub fn rsort<A>(array: &mut A, flags: u32)
+    where
+        A: ArrayLikeMut,
+        A::Value: ToValue {
+
+    sort_values(array, flags, true, false);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{rsort, Key, PhpArray, SORT_FLAG_CASE, SORT_REGULAR, SORT_STRING};
+
+    #[test]
+    fn test() {
+        let mut array = vec!["10", "9", "2", "1"];
+        rsort(&mut array, SORT_REGULAR);
+        assert_eq!(array, ["10", "9", "2", "1"]);
+        rsort(&mut array, SORT_STRING);
+        assert_eq!(array, ["9", "2", "10", "1"]);
+
+        let mut array = vec!["a", "B", "A", "b"];
+        rsort(&mut array, SORT_STRING | SORT_FLAG_CASE);
+        assert_eq!(array, ["B", "b", "a", "A"]);
+
+        let mut array: PhpArray<i32> = vec![("a", 1), ("b", 2)].into_iter().collect();
+        rsort(&mut array, SORT_REGULAR);
+        assert_eq!(array.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::Int(0), 2), (Key::Int(1), 1)]);
+    }
+}
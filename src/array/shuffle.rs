@@ -0,0 +1,103 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::ArrayLikeMut;
+use crate::random::{with_generator, Engine};
+
+/// Shuffle an array
+///
+/// # Description
+///
+/// This function shuffles (randomizes the order of the elements in) an array. It uses the
+/// Mersenne Twister shared with mt_rand(), so after mt_srand() it shuffles like PHP does.
+///
+/// This function assigns new keys to the elements in array. It will remove any existing keys that
+/// may have been assigned, rather than just reordering the keys.
+///
+/// # Examples
+///
+/// Example #1 shuffle() example
+///
+/// ```
+/// use phpify::array::shuffle;
+///
+/// let mut numbers: Vec<i32> = (1..=20).collect();
+/// shuffle(&mut numbers);
+///
+/// numbers.sort();
+/// assert_eq!(numbers, (1..=20).collect::<Vec<i32>>());
+/// ```
+pub fn shuffle<A>(array: &mut A)
+    where
+        A: ArrayLikeMut {
+
+    let old = std::mem::replace(array, A::from_entries(std::iter::empty()));
+    let mut values: Vec<A::Value> = old.into_entries().into_iter().map(|(_, value)| value).collect();
+
+    shuffle_slice(&mut values);
+
+    for value in values {
+        array.push(value);
+    }
+}
+
+/// Shuffles the slice in place with the Mersenne Twister shared with mt_rand().
+pub(crate) fn shuffle_slice<T>(slice: &mut [T]) {
+    with_generator(|generator| shuffle_slice_with(slice, generator));
+}
+
+/// Shuffles the slice in place with the engine, the way PHP's php_array_data_shuffle() does.
+pub(crate) fn shuffle_slice_with<T, E>(slice: &mut [T], engine: &mut E)
+    where
+        E: Engine + ?Sized {
+
+    for left in (1..slice.len()).rev() {
+        let index = engine.range(0, left as i64) as usize;
+
+        if index != left {
+            slice.swap(left, index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{shuffle, Key, PhpArray};
+    use crate::random::{mt_srand, MT_RAND_MT19937};
+
+    #[test]
+    fn test() {
+        mt_srand(1, MT_RAND_MT19937);
+        let mut first: Vec<i32> = (0..10).collect();
+        shuffle(&mut first);
+
+        mt_srand(1, MT_RAND_MT19937);
+        let mut second: Vec<i32> = (0..10).collect();
+        shuffle(&mut second);
+
+        assert_eq!(first, second);
+        assert_ne!(first, (0..10).collect::<Vec<i32>>());
+
+        let mut array: PhpArray<&str> = vec![("a", "x"), ("b", "y")].into_iter().collect();
+        shuffle(&mut array);
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::Int(0), &Key::Int(1)]);
+
+        let mut empty: Vec<i32> = Vec::new();
+        shuffle(&mut empty);
+        assert!(empty.is_empty());
+    }
+}
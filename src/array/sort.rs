@@ -0,0 +1,290 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+
+use crate::array::{ArrayLikeMut, SORT_FLAG_CASE, SORT_LOCALE_STRING, SORT_NATURAL, SORT_NUMERIC, SORT_STRING};
+use crate::string::compare_natural;
+use crate::value::{ToValue, Value};
+
+/// Sort an array in ascending order
+///
+/// # Description
+///
+/// Sorts array in place by values in ascending order. Like PHP 8, the sort is stable: elements that
+/// compare equal keep their original order.
+///
+/// This function assigns new keys to the elements in array. It will remove any existing keys that
+/// may have been assigned, rather than just reordering the keys.
+///
+/// The flags may be used to modify the comparison behavior:
+///
+/// - SORT_REGULAR - compare items normally (don't change types)
+/// - SORT_NUMERIC - compare items numerically
+/// - SORT_STRING - compare items as strings
+/// - SORT_LOCALE_STRING - compare items as strings, treated the same as SORT_STRING
+/// - SORT_NATURAL - compare items as strings using "natural ordering" like natsort()
+/// - SORT_FLAG_CASE - can be combined (bitwise OR) with SORT_STRING or SORT_NATURAL to sort
+///   strings case-insensitively
+///
+/// # Examples
+///
+/// Example #1 sort() example
+///
+/// ```
+/// use phpify::array::{sort, SORT_REGULAR};
+///
+/// let mut fruits = vec!["lemon", "orange", "banana", "apple"];
+/// sort(&mut fruits, SORT_REGULAR);
+///
+/// assert_eq!(fruits, ["apple", "banana", "lemon", "orange"]);
+/// ```
+///
+/// Example #2 sort() using case-insensitive natural ordering
+///
+/// ```
+/// use phpify::array::{sort, SORT_FLAG_CASE, SORT_NATURAL, SORT_STRING};
+///
+/// let mut fruits = vec!["Orange1", "orange2", "Orange3", "orange20"];
+///
+/// sort(&mut fruits, SORT_NATURAL | SORT_FLAG_CASE);
+/// assert_eq!(fruits, ["Orange1", "orange2", "Orange3", "orange20"]);
+///
+/// sort(&mut fruits, SORT_STRING);
+/// assert_eq!(fruits, ["Orange1", "Orange3", "orange2", "orange20"]);
+/// ```
+pub fn sort<A>(array: &mut A, flags: u32)
+    where
+        A: ArrayLikeMut,
+        A::Value: ToValue {
+
+    sort_values(array, flags, false, false);
+}
+
+/// Sorts the array by its values according to the flags, in reverse order if reverse is true.
+pub(crate) fn sort_values<A>(array: &mut A, flags: u32, reverse: bool, keep_keys: bool)
+    where
+        A: ArrayLikeMut,
+        A::Value: ToValue {
+
+    let entries = take_entries(array);
+    let values: Vec<Value> = entries.iter().map(|(_, value)| value.to_value()).collect();
+    let order = sorted_order(values.len(), |a, b| ordered(compare_by_flags(&values[a], &values[b], flags), reverse));
+
+    rebuild(array, entries, order, keep_keys);
+}
+
+/// Sorts the array by its keys according to the flags, in reverse order if reverse is true.
+pub(crate) fn sort_keys<A>(array: &mut A, flags: u32, reverse: bool)
+    where
+        A: ArrayLikeMut,
+        A::Key: ToValue {
+
+    let entries = take_entries(array);
+    let keys: Vec<Value> = entries.iter().map(|(key, _)| key.to_value()).collect();
+    let order = sorted_order(keys.len(), |a, b| ordered(compare_by_flags(&keys[a], &keys[b], flags), reverse));
+
+    rebuild(array, entries, order, true);
+}
+
+/// Sorts the array by comparing its entries with compare.
+pub(crate) fn sort_entries<A, F>(array: &mut A, keep_keys: bool, mut compare: F)
+    where
+        A: ArrayLikeMut,
+        F: FnMut(&(A::Key, A::Value), &(A::Key, A::Value)) -> Ordering {
+
+    let entries = take_entries(array);
+    let order = sorted_order(entries.len(), |a, b| compare(&entries[a], &entries[b]));
+
+    rebuild(array, entries, order, keep_keys);
+}
+
+/// Sorts the slice in place by its values according to the flags, in reverse order if reverse is
+/// true. This is sort_values() for arrays that cannot be rebuilt, like slices.
+pub(crate) fn sort_slice<T>(slice: &mut [T], flags: u32, reverse: bool)
+    where
+        T: ToValue {
+
+    let values: Vec<Value> = slice.iter().map(ToValue::to_value).collect();
+    let order = sorted_order(values.len(), |a, b| ordered(compare_by_flags(&values[a], &values[b], flags), reverse));
+
+    permute(slice, order);
+}
+
+/// Sorts the slice in place by comparing its elements with compare.
+pub(crate) fn sort_slice_by<T, F>(slice: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering {
+
+    let order = sorted_order(slice.len(), |a, b| compare(&slice[a], &slice[b]));
+
+    permute(slice, order);
+}
+
+/// Compares values the way PHP's sort functions do for the flags.
+pub(crate) fn compare_by_flags(a: &Value, b: &Value, flags: u32) -> Ordering {
+    let fold_case = flags & SORT_FLAG_CASE != 0;
+
+    match flags & !SORT_FLAG_CASE {
+        SORT_NUMERIC => match (a, b) {
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            _ => a.to_float().partial_cmp(&b.to_float()).unwrap_or(Ordering::Greater),
+        },
+        SORT_STRING | SORT_LOCALE_STRING => {
+            let (a, b) = (a.to_php_string(), b.to_php_string());
+
+            if fold_case {
+                a.iter().map(u8::to_ascii_lowercase).cmp(b.iter().map(u8::to_ascii_lowercase))
+            } else {
+                a.cmp(&b)
+            }
+        },
+        SORT_NATURAL => compare_natural(&a.to_php_string(), &b.to_php_string(), fold_case),
+        _ => a.compare(b),
+    }
+}
+
+fn ordered(ordering: Ordering, reverse: bool) -> Ordering {
+    if reverse { ordering.reverse() } else { ordering }
+}
+
+fn take_entries<A>(array: &mut A) -> Vec<(A::Key, A::Value)>
+    where
+        A: ArrayLikeMut {
+
+    std::mem::replace(array, A::from_entries(std::iter::empty())).into_entries()
+}
+
+/// Puts the entries back into the array in the given order, renumbering the keys unless keep_keys
+/// is true.
+fn rebuild<A>(array: &mut A, entries: Vec<(A::Key, A::Value)>, order: Vec<usize>, keep_keys: bool)
+    where
+        A: ArrayLikeMut {
+
+    let mut entries: Vec<Option<(A::Key, A::Value)>> = entries.into_iter().map(Some).collect();
+    let sorted = order.into_iter().filter_map(|index| entries[index].take());
+
+    if keep_keys {
+        *array = A::from_entries(sorted);
+    } else {
+        for (_, value) in sorted {
+            array.push(value);
+        }
+    }
+}
+
+/// Moves the elements of the slice so that position i holds the element that was at order[i],
+/// following each cycle of the permutation with swaps.
+fn permute<T>(slice: &mut [T], mut order: Vec<usize>) {
+    for start in 0..order.len() {
+        let mut current = start;
+
+        loop {
+            let next = std::mem::replace(&mut order[current], current);
+
+            if next == start || next == current {
+                break;
+            }
+
+            slice.swap(current, next);
+            current = next;
+        }
+    }
+}
+
+/// Returns the indices 0..len in stable sorted order. A merge sort is used, so a comparison that is
+/// not a total order, as PHP's comparisons of mixed types often are, still terminates.
+pub(crate) fn sorted_order<F>(len: usize, mut compare: F) -> Vec<usize>
+    where
+        F: FnMut(usize, usize) -> Ordering {
+
+    let mut order: Vec<usize> = (0..len).collect();
+    let mut buffer = order.clone();
+    let mut width = 1;
+
+    while width < len {
+        for start in (0..len).step_by(width * 2) {
+            let middle = (start + width).min(len);
+            let end = (start + width * 2).min(len);
+            let (mut left, mut right) = (start, middle);
+
+            for slot in buffer[start..end].iter_mut() {
+                if left < middle && (right >= end || compare(order[right], order[left]) != Ordering::Less) {
+                    *slot = order[left];
+                    left += 1;
+                } else {
+                    *slot = order[right];
+                    right += 1;
+                }
+            }
+        }
+
+        std::mem::swap(&mut order, &mut buffer);
+        width *= 2;
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{sort, Key, PhpArray, SORT_FLAG_CASE, SORT_NATURAL, SORT_NUMERIC, SORT_REGULAR, SORT_STRING};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        let mut array = vec![3, 1, 2];
+        sort(&mut array, SORT_REGULAR);
+        assert_eq!(array, [1, 2, 3]);
+
+        let mut array: PhpArray<i32> = vec![("b", 2), ("a", 1)].into_iter().collect();
+        sort(&mut array, SORT_REGULAR);
+        assert_eq!(array.into_iter().collect::<Vec<(Key, i32)>>(), [(Key::Int(0), 1), (Key::Int(1), 2)]);
+
+        let mut array = vec!["10", "9", "2", "1"];
+        sort(&mut array, SORT_STRING);
+        assert_eq!(array, ["1", "10", "2", "9"]);
+        sort(&mut array, SORT_REGULAR);
+        assert_eq!(array, ["1", "2", "9", "10"]);
+
+        let mut array = vec![Value::from("1e1"), Value::from(9), Value::from("abc"), Value::from(2.5)];
+        sort(&mut array, SORT_NUMERIC);
+        assert_eq!(array, [Value::from("abc"), Value::from(2.5), Value::from(9), Value::from("1e1")]);
+
+        let mut array = vec!["b", "B", "a", "A"];
+        sort(&mut array, SORT_STRING | SORT_FLAG_CASE);
+        assert_eq!(array, ["a", "A", "b", "B"]);
+
+        let mut array = vec!["img12.png", "IMG10.png", "img2.png", "img1.png"];
+        sort(&mut array, SORT_NATURAL);
+        assert_eq!(array, ["IMG10.png", "img1.png", "img2.png", "img12.png"]);
+        sort(&mut array, SORT_NATURAL | SORT_FLAG_CASE);
+        assert_eq!(array, ["img1.png", "img2.png", "IMG10.png", "img12.png"]);
+
+        let mut array = vec![Value::from("a"), Value::from(1), Value::from("10"), Value::from(true), Value::from(()), Value::from(vec![1])];
+        sort(&mut array, SORT_REGULAR);
+        assert_eq!(array.len(), 6);
+
+        let mut array: Vec<i32> = (0..100).rev().collect();
+        sort(&mut array, SORT_REGULAR);
+        assert_eq!(array, (0..100).collect::<Vec<i32>>());
+
+        let mut empty: Vec<i32> = Vec::new();
+        sort(&mut empty, SORT_REGULAR);
+        assert!(empty.is_empty());
+    }
+}
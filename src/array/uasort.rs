@@ -0,0 +1,75 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+
+use crate::array::sort::sort_entries;
+use crate::array::ArrayLikeMut;
+
+/// Sort an array with a user-defined comparison function and maintain index association
+///
+/// # Description
+///
+/// Sorts array in place such that its keys maintain their correlation with the values they are
+/// associated with, using a user-defined comparison function. Elements that compare equal keep
+/// their original order.
+///
+/// Vectors are keyed by position, so for them this is the same as usort().
+///
+/// **callback**
+///
+/// The comparison function must return Less, Equal or Greater if the first argument is considered
+/// to be respectively less than, equal to, or greater than the second.
+///
+/// # Examples
+///
+/// Example #1 Basic uasort() example
+///
+/// ```
+/// use phpify::array::{uasort, Key, PhpArray};
+///
+/// let mut array: PhpArray<i32> = vec![("a", 4), ("b", 8), ("c", -1), ("d", -9), ("e", 2), ("f", 5), ("g", 3), ("h", -4)]
+///     .into_iter()
+///     .collect();
+///
+/// uasort(&mut array, |a, b| a.cmp(b));
+///
+/// assert_eq!(array.values().collect::<Vec<&i32>>(), [&-9, &-4, &-1, &2, &3, &4, &5, &8]);
+/// assert_eq!(array.keys().next(), Some(&Key::from("d")));
+/// ```
+pub fn uasort<A>(array: &mut A, mut callback: impl FnMut(&A::Value, &A::Value) -> Ordering)
+    where
+        A: ArrayLikeMut {
+
+    sort_entries(array, true, |(_, a), (_, b)| callback(a, b));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{uasort, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let mut array: PhpArray<&str> = vec![(3, "bb"), (1, "a"), (2, "cc")].into_iter().collect();
+        uasort(&mut array, |a, b| a.len().cmp(&b.len()));
+        assert_eq!(array.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::Int(1), "a"), (Key::Int(3), "bb"), (Key::Int(2), "cc")]);
+
+        let mut array = vec![2, 1];
+        uasort(&mut array, |a, b| a.cmp(b));
+        assert_eq!(array, [1, 2]);
+    }
+}
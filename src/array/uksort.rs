@@ -0,0 +1,75 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+
+use crate::array::sort::sort_entries;
+use crate::array::ArrayLikeMut;
+
+/// Sort an array by keys using a user-defined comparison function
+///
+/// # Description
+///
+/// Sorts array in place by keys using a user-supplied comparison function to determine the order.
+///
+/// **callback**
+///
+/// The comparison function must return Less, Equal or Greater if the first argument is considered
+/// to be respectively less than, equal to, or greater than the second.
+///
+/// # Examples
+///
+/// Example #1 uksort() example
+///
+/// ```
+/// use phpify::array::{uksort, Key, PhpArray};
+///
+/// let mut a: PhpArray<i32> = vec![("John", 1), ("the Earth", 2), ("an apple", 3), ("a banana", 4)]
+///     .into_iter()
+///     .collect();
+///
+/// fn strip_article(key: &Key) -> String {
+///     let key = key.to_string();
+///     key.strip_prefix("an ").or_else(|| key.strip_prefix("a ")).or_else(|| key.strip_prefix("the ")).unwrap_or(&key).to_string()
+/// }
+///
+/// uksort(&mut a, |a, b| strip_article(a).cmp(&strip_article(b)));
+///
+/// assert_eq!(a.values().collect::<Vec<&i32>>(), [&2, &1, &3, &4]);
+/// ```
+pub fn uksort<A>(array: &mut A, mut callback: impl FnMut(&A::Key, &A::Key) -> Ordering)
+    where
+        A: ArrayLikeMut {
+
+    sort_entries(array, true, |(a, _), (b, _)| callback(a, b));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{uksort, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let mut array: PhpArray<i32> = vec![("bb", 1), ("a", 2), ("ccc", 3)].into_iter().collect();
+        uksort(&mut array, |a, b| b.to_string().len().cmp(&a.to_string().len()));
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::from("ccc"), &Key::from("bb"), &Key::from("a")]);
+
+        let mut array = vec![1, 2, 3];
+        uksort(&mut array, |a, b| b.cmp(a));
+        assert_eq!(array, [3, 2, 1]);
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+
+use crate::array::sort::sort_entries;
+use crate::array::ArrayLikeMut;
+
+/// Sort an array by values using a user-defined comparison function
+///
+/// # Description
+///
+/// Sorts array in place by values using a user-supplied comparison function to determine the order.
+/// Elements that compare equal keep their original order.
+///
+/// This function assigns new keys to the elements in array. It will remove any existing keys that
+/// may have been assigned, rather than just reordering the keys.
+///
+/// **callback**
+///
+/// The comparison function must return Less, Equal or Greater if the first argument is considered
+/// to be respectively less than, equal to, or greater than the second.
+///
+/// # Examples
+///
+/// Example #1 usort() example
+///
+/// ```
+/// use phpify::array::usort;
+///
+/// let mut a = vec![3, 2, 5, 6, 1];
+/// usort(&mut a, |a, b| a.cmp(b));
+///
+/// assert_eq!(a, [1, 2, 3, 5, 6]);
+/// ```
+pub fn usort<A>(array: &mut A, mut callback: impl FnMut(&A::Value, &A::Value) -> Ordering)
+    where
+        A: ArrayLikeMut {
+
+    sort_entries(array, false, |(_, a), (_, b)| callback(a, b));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{usort, Key, PhpArray};
+
+    #[test]
+    fn test() {
+        let mut array = vec![(1, "a"), (0, "b"), (1, "c"), (0, "d")];
+        usort(&mut array, |a, b| a.0.cmp(&b.0));
+        assert_eq!(array, [(0, "b"), (0, "d"), (1, "a"), (1, "c")]);
+
+        let mut array: PhpArray<&str> = vec![("x", "banana"), ("y", "apple")].into_iter().collect();
+        usort(&mut array, |a, b| a.cmp(b));
+        assert_eq!(array.into_iter().collect::<Vec<(Key, &str)>>(), [(Key::Int(0), "apple"), (Key::Int(1), "banana")]);
+    }
+}
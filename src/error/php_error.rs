@@ -0,0 +1,98 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::error::Error;
+use std::fmt;
+
+/// An error condition raised by a PHP function.
+///
+/// # Description
+///
+/// Each variant corresponds to the way PHP 8 reports the condition, and holds PHP's message.
+///
+/// - ValueError - an argument has the right type, but its value is not accepted.
+/// - TypeError - an argument does not have the type the function expects.
+/// - ArgumentCountError - fewer arguments were given than the function requires.
+/// - Warning - the function raised an E_WARNING and failed.
+///
+/// Functions that can both miss and fail, like strpos(), return Result<Option<_>, PhpError>: None
+/// is the legitimate false return, an error is what PHP would throw or warn about.
+///
+/// # Examples
+///
+/// Example #1 PhpError example
+///
+/// ```
+/// use phpify::error::PhpError;
+/// use phpify::string::{explode, strpos};
+///
+/// let error = explode("", "a,b", isize::MAX).unwrap_err();
+///
+/// assert_eq!(error, PhpError::ValueError("explode(): Argument #1 ($separator) cannot be empty".to_string()));
+/// assert_eq!(error.to_string(), "explode(): Argument #1 ($separator) cannot be empty");
+///
+/// assert_eq!(strpos("abc", "d", 0), Ok(None));
+/// assert!(strpos("abc", "d", 4).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PhpError {
+    ValueError(String),
+    TypeError(String),
+    ArgumentCountError(String),
+    Warning(String),
+}
+
+impl PhpError {
+    /// Returns the message of the error.
+    pub fn message(&self) -> &str {
+        match self {
+            PhpError::ValueError(message)
+            | PhpError::TypeError(message)
+            | PhpError::ArgumentCountError(message)
+            | PhpError::Warning(message) => message,
+        }
+    }
+
+    /// Returns true if PHP throws the error, rather than raising a warning.
+    pub fn is_throwable(&self) -> bool {
+        !matches!(self, PhpError::Warning(_))
+    }
+}
+
+impl fmt::Display for PhpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl Error for PhpError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+
+    #[test]
+    fn test() {
+        let error = PhpError::TypeError("strlen(): Argument #1 ($string) must be of type string, array given".to_string());
+        assert_eq!(error.message(), "strlen(): Argument #1 ($string) must be of type string, array given");
+        assert!(error.is_throwable());
+
+        let warning = PhpError::Warning("Undefined array key 1".to_string());
+        assert_eq!(warning.to_string(), "Undefined array key 1");
+        assert!(!warning.is_throwable());
+    }
+}
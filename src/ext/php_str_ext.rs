@@ -0,0 +1,337 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+use std::io::Write;
+
+use crate::array::ArrayLike;
+use crate::error::PhpError;
+use crate::json::{json_decode, JsonError};
+use crate::mbstring::{
+    mb_convert_case,
+    mb_str_pad,
+    mb_str_split,
+    mb_strimwidth,
+    mb_stripos,
+    mb_strlen,
+    mb_strpos,
+    mb_strrpos,
+    mb_strwidth,
+    mb_substr,
+};
+use crate::string::{
+    explode,
+    fprintf,
+    lcfirst,
+    printf,
+    sprintf,
+    sscanf,
+    str_shuffle,
+    stripos,
+    strlen,
+    strnatcasecmp,
+    strnatcmp,
+    strpos,
+    substr,
+    ucfirst,
+    vsprintf,
+    PhpString,
+};
+use crate::value::{ToValue, Value};
+use crate::var::{unserialize, UnserializeError, UnserializeOptions};
+
+/// Returns the bytes of a string, for the functions that work on bytes.
+fn bytes<S>(string: &S) -> &[u8]
+    where
+        S: AsRef<[u8]> + ?Sized {
+
+    string.as_ref()
+}
+
+/// Returns a string as text, for the functions that take UTF-8.
+fn text<S>(string: &S) -> &str
+    where
+        S: AsRef<str> + ?Sized {
+
+    string.as_ref()
+}
+
+/// The string functions of the library as methods.
+///
+/// # Description
+///
+/// Every method is named after the function it calls, prefixed with php_, and takes the string the
+/// function works on as its receiver: the haystack of strpos(), the format of sprintf() and so on.
+/// The other arguments are passed in the order of the function.
+///
+/// The methods only call the functions, so they behave the same in every respect.
+///
+/// The trait is implemented for str, String and PhpString, so the strings returned by substr(),
+/// ucfirst() and the like can be chained. The methods of functions that take UTF-8 text, like the
+/// mb_ functions, json_decode() and sscanf(), require the string to be a str or String.
+///
+/// # Examples
+///
+/// Example #1 Chaining string functions
+///
+/// ```
+/// use phpify::ext::PhpStrExt;
+///
+/// let title = "hello world".php_ucfirst();
+///
+/// assert_eq!(title, "Hello world");
+/// assert_eq!("abcdef".php_substr(1, 3), "bcd");
+/// assert_eq!("abcdef".php_strpos("cd", 0), Ok(Some(2)));
+/// assert_eq!("a,b,c".php_explode(",", isize::MAX).unwrap(), ["a", "b", "c"]);
+/// assert_eq!(String::from("Straße").php_mb_strlen(), 6);
+/// assert_eq!("hello world".php_ucfirst().php_substr(0, 5).php_strpos("l", 0), Ok(Some(2)));
+/// ```
+pub trait PhpStrExt: AsRef<[u8]> {
+    /// Split a string by a string, see explode().
+    fn php_explode<D>(&self, delimiter: D, limit: isize) -> Result<Vec<PhpString>, PhpError>
+        where
+            D: AsRef<[u8]> {
+
+        explode(delimiter, bytes(self), limit)
+    }
+
+    /// Write a formatted string to a stream, using the string as the format, see fprintf().
+    fn php_fprintf<W>(&self, handle: &mut W, values: &[&dyn ToValue]) -> Result<usize, PhpError>
+        where
+            W: Write + ?Sized {
+
+        fprintf(handle, bytes(self), values)
+    }
+
+    /// Decodes the string as JSON, see json_decode().
+    fn php_json_decode(&self, assoc: bool, depth: usize, flags: u32) -> Result<Value, JsonError>
+        where
+            Self: AsRef<str> {
+
+        json_decode(text(self), assoc, depth, flags)
+    }
+
+    /// Make a string's first character lowercase, see lcfirst().
+    fn php_lcfirst(&self) -> PhpString {
+        lcfirst(bytes(self))
+    }
+
+    /// Perform case folding on a string, see mb_convert_case().
+    fn php_mb_convert_case(&self, mode: u32) -> Result<String, PhpError>
+        where
+            Self: AsRef<str> {
+
+        mb_convert_case(text(self), mode)
+    }
+
+    /// Pad a multibyte string to a certain length with another multibyte string, see mb_str_pad().
+    fn php_mb_str_pad<P>(&self, length: usize, pad_string: P, pad_type: u32) -> Result<String, PhpError>
+        where
+            Self: AsRef<str>,
+            P: AsRef<str> {
+
+        mb_str_pad(text(self), length, pad_string, pad_type)
+    }
+
+    /// Given a multibyte string, return an array of its characters, see mb_str_split().
+    fn php_mb_str_split(&self, length: usize) -> Result<Vec<String>, PhpError>
+        where
+            Self: AsRef<str> {
+
+        mb_str_split(text(self), length)
+    }
+
+    /// Get truncated string with specified width, see mb_strimwidth().
+    fn php_mb_strimwidth<T>(&self, start: isize, width: usize, trim_marker: T) -> Result<String, PhpError>
+        where
+            Self: AsRef<str>,
+            T: AsRef<str> {
+
+        mb_strimwidth(text(self), start, width, trim_marker)
+    }
+
+    /// Finds position of first occurrence of a string within another, case insensitive, see
+    /// mb_stripos().
+    fn php_mb_stripos<N>(&self, needle: N, offset: isize) -> Result<Option<usize>, PhpError>
+        where
+            Self: AsRef<str>,
+            N: AsRef<str> {
+
+        mb_stripos(text(self), needle, offset)
+    }
+
+    /// Get string length, see mb_strlen().
+    fn php_mb_strlen(&self) -> usize
+        where
+            Self: AsRef<str> {
+
+        mb_strlen(text(self))
+    }
+
+    /// Find position of first occurrence of string in a string, see mb_strpos().
+    fn php_mb_strpos<N>(&self, needle: N, offset: isize) -> Result<Option<usize>, PhpError>
+        where
+            Self: AsRef<str>,
+            N: AsRef<str> {
+
+        mb_strpos(text(self), needle, offset)
+    }
+
+    /// Find position of last occurrence of a string in a string, see mb_strrpos().
+    fn php_mb_strrpos<N>(&self, needle: N, offset: isize) -> Result<Option<usize>, PhpError>
+        where
+            Self: AsRef<str>,
+            N: AsRef<str> {
+
+        mb_strrpos(text(self), needle, offset)
+    }
+
+    /// Return width of string, see mb_strwidth().
+    fn php_mb_strwidth(&self) -> usize
+        where
+            Self: AsRef<str> {
+
+        mb_strwidth(text(self))
+    }
+
+    /// Get part of string, see mb_substr().
+    fn php_mb_substr(&self, start: isize, length: isize) -> String
+        where
+            Self: AsRef<str> {
+
+        mb_substr(text(self), start, length)
+    }
+
+    /// Output a formatted string, using the string as the format, see printf().
+    fn php_printf(&self, values: &[&dyn ToValue]) -> Result<usize, PhpError> {
+        printf(bytes(self), values)
+    }
+
+    /// Return a formatted string, using the string as the format, see sprintf().
+    fn php_sprintf(&self, values: &[&dyn ToValue]) -> Result<PhpString, PhpError> {
+        sprintf(bytes(self), values)
+    }
+
+    /// Parses input from a string according to a format, see sscanf().
+    fn php_sscanf(&self, format: &str) -> Result<Option<Vec<Value>>, PhpError>
+        where
+            Self: AsRef<str> {
+
+        sscanf(text(self), format)
+    }
+
+    /// Randomly shuffles a string, see str_shuffle().
+    fn php_str_shuffle(&self) -> PhpString {
+        str_shuffle(bytes(self))
+    }
+
+    /// Find the position of the first occurrence of a case-insensitive substring in a string, see
+    /// stripos().
+    fn php_stripos<N>(&self, needle: N, offset: isize) -> Result<Option<usize>, PhpError>
+        where
+            N: AsRef<[u8]> {
+
+        stripos(bytes(self), needle, offset)
+    }
+
+    /// Get string length, see strlen().
+    fn php_strlen(&self) -> usize {
+        strlen(bytes(self))
+    }
+
+    /// Case insensitive string comparisons using a "natural order" algorithm, see strnatcasecmp().
+    fn php_strnatcasecmp<S>(&self, other: S) -> Ordering
+        where
+            S: AsRef<[u8]> {
+
+        strnatcasecmp(bytes(self), other)
+    }
+
+    /// String comparisons using a "natural order" algorithm, see strnatcmp().
+    fn php_strnatcmp<S>(&self, other: S) -> Ordering
+        where
+            S: AsRef<[u8]> {
+
+        strnatcmp(bytes(self), other)
+    }
+
+    /// Find the position of the first occurrence of a substring in a string, see strpos().
+    fn php_strpos<N>(&self, needle: N, offset: isize) -> Result<Option<usize>, PhpError>
+        where
+            N: AsRef<[u8]> {
+
+        strpos(bytes(self), needle, offset)
+    }
+
+    /// Return part of a string, see substr().
+    fn php_substr(&self, start: isize, length: isize) -> PhpString {
+        substr(bytes(self), start, length)
+    }
+
+    /// Make a string's first character uppercase, see ucfirst().
+    fn php_ucfirst(&self) -> PhpString {
+        ucfirst(bytes(self))
+    }
+
+    /// Creates a value from its stored representation, see unserialize().
+    fn php_unserialize(&self, options: &UnserializeOptions) -> Result<Value, UnserializeError> {
+        unserialize(bytes(self), options)
+    }
+
+    /// Return a formatted string, using the string as the format, see vsprintf().
+    fn php_vsprintf<A>(&self, values: &A) -> Result<PhpString, PhpError>
+        where
+            A: ArrayLike + ?Sized,
+            A::Value: ToValue {
+
+        vsprintf(bytes(self), values)
+    }
+}
+
+impl PhpStrExt for str {}
+
+impl PhpStrExt for String {}
+
+impl PhpStrExt for PhpString {}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::ext::PhpStrExt;
+    use crate::string::{substr, ucfirst};
+
+    #[test]
+    fn test() {
+        let string = String::from("hello wörld");
+
+        assert_eq!(string.php_ucfirst(), ucfirst(&string));
+        assert_eq!(string.php_substr(-5, 3), substr(&string, -5, 3));
+        assert_eq!(string.php_strlen(), 12);
+        assert_eq!(string.php_mb_strlen(), 11);
+        assert_eq!(string.php_mb_substr(6, 5), "wörld");
+        assert_eq!(string.php_mb_str_split(4).unwrap(), ["hell", "o wö", "rld"]);
+        assert_eq!("img12".php_strnatcmp("img10"), Ordering::Greater);
+        assert_eq!("%s-%d".php_sprintf(&[&"a", &1]).unwrap(), "a-1");
+        assert_eq!("%s".php_vsprintf(&vec!["b"]).unwrap(), "b");
+        assert!("[1]".php_json_decode(false, 512, 0).is_ok());
+
+        let chained = "hello world".php_ucfirst().php_substr(1, 7).php_lcfirst().php_str_shuffle();
+        assert_eq!(chained.php_strlen(), 7);
+        assert_eq!("hello world".php_ucfirst().php_substr(0, 5).php_explode("l", isize::MAX).unwrap(), ["He", "", "o"]);
+    }
+}
@@ -0,0 +1,675 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+use std::ops::ControlFlow;
+
+use crate::array::{
+    array_chunk,
+    array_column,
+    array_column_indexed,
+    array_column_rows,
+    array_combine,
+    array_diff,
+    array_diff_assoc,
+    array_diff_key,
+    array_diff_uassoc,
+    array_diff_ukey,
+    array_fill_keys,
+    array_filter,
+    array_filter_both,
+    array_filter_key,
+    array_filter_truthy,
+    array_flip,
+    array_intersect,
+    array_intersect_assoc,
+    array_intersect_key,
+    array_intersect_uassoc,
+    array_intersect_ukey,
+    array_keys,
+    array_keys_search,
+    array_map,
+    array_map_multiple,
+    array_map_zip,
+    array_pad,
+    array_rand,
+    array_rand_with,
+    array_reduce,
+    array_search,
+    array_slice,
+    array_udiff,
+    array_udiff_assoc,
+    array_udiff_uassoc,
+    array_uintersect,
+    array_uintersect_assoc,
+    array_uintersect_uassoc,
+    array_unique,
+    array_values,
+    array_walk,
+    array_walk_recursive,
+    array_walk_recursive_with,
+    array_walk_with,
+    in_array,
+    shuffle_slice,
+    sort_slice,
+    sort_slice_by,
+    ArrayLike,
+    Key,
+    PhpArray,
+    Record,
+    SORT_FLAG_CASE,
+    SORT_NATURAL,
+};
+use crate::error::PhpError;
+use crate::json::{json_encode, JsonError};
+use crate::random::Engine;
+use crate::string::{implode, PhpString};
+use crate::value::{ToValue, Value};
+use crate::var::serialize;
+
+/// The array functions that work on a single list, as methods of slices and vectors.
+///
+/// # Description
+///
+/// Every method is named after the function it calls, prefixed with php_, and takes the array the
+/// function works on as its receiver. The other arguments are passed in the order of the function.
+/// Arguments that have no effect on lists, like preserve_keys, are left out. The keys of a list are
+/// the positions of its elements.
+///
+/// The methods call the functions, so they behave the same in every respect. The exceptions are the
+/// methods that sort or shuffle: sort() and the like take an array that can grow, which a slice is
+/// not, so these methods reorder the slice in place with the comparisons and the random numbers of
+/// the functions.
+///
+/// The trait is a deliberate subset of the array functions. These functions have no method:
+///
+/// - array_push(), array_pop(), array_shift(), array_unshift() and array_splice(), as vectors have
+///   methods of their own to change their length.
+/// - asort(), arsort(), uasort(), ksort(), krsort() and uksort(), as a list keeps no keys to
+///   preserve: php_sort(), php_rsort() and php_usort() give the same order.
+/// - array_merge(), array_merge_recursive(), array_replace() and array_replace_recursive(), which
+///   take PhpArray. Merging lists appends them, which [a, b].concat() does.
+/// - array_multisort(), which sorts several arrays at once, and array_fill() and range(), which
+///   create an array from nothing.
+///
+/// # Examples
+///
+/// Example #1 Chaining array functions
+///
+/// ```
+/// use phpify::ext::PhpVecExt;
+///
+/// let words = vec!["pear", "apple", "fig", "apple"];
+///
+/// let mut unique = words.php_array_unique(0);
+/// unique.php_sort(0);
+///
+/// assert_eq!(unique, ["apple", "fig", "pear"]);
+/// assert_eq!(unique.php_implode(", "), "apple, fig, pear");
+/// assert!(words[..2].php_in_array(&"apple", true));
+/// ```
+pub trait PhpVecExt<T>: AsRef<[T]> + AsMut<[T]> {
+    /// Split an array into chunks, see array_chunk().
+    fn php_array_chunk(&self, length: usize) -> Result<Vec<Vec<T>>, PhpError>
+        where
+            T: Clone {
+
+        array_chunk(self.as_ref(), length, false)
+    }
+
+    /// Return the values from a single column of the records, see array_column().
+    fn php_array_column<K>(&self, column_key: K) -> Vec<T::Field>
+        where
+            T: Record,
+            T::Field: Clone,
+            K: Into<Key> {
+
+        array_column(self.as_ref(), column_key)
+    }
+
+    /// Return the values from a single column of the records, indexed by another column, see
+    /// array_column_indexed().
+    fn php_array_column_indexed<K, I>(&self, column_key: K, index_key: I) -> Result<PhpArray<T::Field>, PhpError>
+        where
+            T: Record,
+            T::Field: Clone + ToValue,
+            K: Into<Key>,
+            I: Into<Key> {
+
+        array_column_indexed(self.as_ref(), column_key, index_key)
+    }
+
+    /// Return the records, indexed by one of their columns, see array_column_rows().
+    fn php_array_column_rows<I>(&self, index_key: I) -> Result<PhpArray<T>, PhpError>
+        where
+            T: Record + Clone,
+            T::Field: ToValue,
+            I: Into<Key> {
+
+        array_column_rows(self.as_ref(), index_key)
+    }
+
+    /// Creates an array by using the elements as keys and another array for its values, see
+    /// array_combine().
+    fn php_array_combine<V>(&self, values: &[V]) -> Result<PhpArray<V>, PhpError>
+        where
+            T: ToValue,
+            V: Clone {
+
+        array_combine(self.as_ref(), values)
+    }
+
+    /// Computes the difference of arrays, see array_diff().
+    fn php_array_diff(&self, arrays: &[&[T]]) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_diff(self.as_ref(), arrays)
+    }
+
+    /// Computes the difference of arrays with additional index check, see array_diff_assoc().
+    fn php_array_diff_assoc(&self, arrays: &[&[T]]) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_diff_assoc(self.as_ref(), arrays)
+    }
+
+    /// Computes the difference of arrays using keys for comparison, see array_diff_key().
+    fn php_array_diff_key(&self, arrays: &[&[T]]) -> Vec<T>
+        where
+            T: Clone {
+
+        array_diff_key(self.as_ref(), arrays)
+    }
+
+    /// Computes the difference of arrays with additional index check which is performed by a user
+    /// supplied callback function, see array_diff_uassoc().
+    fn php_array_diff_uassoc(&self, arrays: &[&[T]], key_compare_func: impl FnMut(&usize, &usize) -> Ordering) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_diff_uassoc(self.as_ref(), arrays, key_compare_func)
+    }
+
+    /// Computes the difference of arrays using a callback function on the keys for comparison, see
+    /// array_diff_ukey().
+    fn php_array_diff_ukey(&self, arrays: &[&[T]], key_compare_func: impl FnMut(&usize, &usize) -> Ordering) -> Vec<T>
+        where
+            T: Clone {
+
+        array_diff_ukey(self.as_ref(), arrays, key_compare_func)
+    }
+
+    /// Fill an array with values, using the elements as keys, see array_fill_keys().
+    fn php_array_fill_keys<V>(&self, value: V) -> PhpArray<V>
+        where
+            T: ToValue,
+            V: Clone {
+
+        array_fill_keys(self.as_ref(), value)
+    }
+
+    /// Filters elements of an array using a callback function, see array_filter().
+    fn php_array_filter(&self, callback: impl FnMut(&T) -> bool) -> Vec<T>
+        where
+            T: Clone {
+
+        array_filter(self.as_ref(), callback)
+    }
+
+    /// Filters elements of an array by value and key using a callback function, see
+    /// array_filter_both().
+    fn php_array_filter_both(&self, callback: impl FnMut(&T, &usize) -> bool) -> Vec<T>
+        where
+            T: Clone {
+
+        array_filter_both(self.as_ref(), callback)
+    }
+
+    /// Filters elements of an array by key using a callback function, see array_filter_key().
+    fn php_array_filter_key(&self, callback: impl FnMut(&usize) -> bool) -> Vec<T>
+        where
+            T: Clone {
+
+        array_filter_key(self.as_ref(), callback)
+    }
+
+    /// Filters the elements of an array that are false, see array_filter_truthy().
+    fn php_array_filter_truthy(&self) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_filter_truthy(self.as_ref())
+    }
+
+    /// Exchanges all keys with their associated values in an array, see array_flip().
+    fn php_array_flip(&self) -> PhpArray<usize>
+        where
+            T: ToValue {
+
+        array_flip(self.as_ref())
+    }
+
+    /// Computes the intersection of arrays, see array_intersect().
+    fn php_array_intersect(&self, arrays: &[&[T]]) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_intersect(self.as_ref(), arrays)
+    }
+
+    /// Computes the intersection of arrays with additional index check, see
+    /// array_intersect_assoc().
+    fn php_array_intersect_assoc(&self, arrays: &[&[T]]) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_intersect_assoc(self.as_ref(), arrays)
+    }
+
+    /// Computes the intersection of arrays using keys for comparison, see array_intersect_key().
+    fn php_array_intersect_key(&self, arrays: &[&[T]]) -> Vec<T>
+        where
+            T: Clone {
+
+        array_intersect_key(self.as_ref(), arrays)
+    }
+
+    /// Computes the intersection of arrays with additional index check, compares indexes by a
+    /// callback function, see array_intersect_uassoc().
+    fn php_array_intersect_uassoc(&self, arrays: &[&[T]], key_compare_func: impl FnMut(&usize, &usize) -> Ordering) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_intersect_uassoc(self.as_ref(), arrays, key_compare_func)
+    }
+
+    /// Computes the intersection of arrays using a callback function on the keys for comparison,
+    /// see array_intersect_ukey().
+    fn php_array_intersect_ukey(&self, arrays: &[&[T]], key_compare_func: impl FnMut(&usize, &usize) -> Ordering) -> Vec<T>
+        where
+            T: Clone {
+
+        array_intersect_ukey(self.as_ref(), arrays, key_compare_func)
+    }
+
+    /// Return all the keys of an array, see array_keys().
+    fn php_array_keys(&self) -> Vec<usize> {
+        array_keys(self.as_ref())
+    }
+
+    /// Return the keys of an array that hold a given value, see array_keys_search().
+    fn php_array_keys_search(&self, search_value: &T, strict: bool) -> Vec<usize>
+        where
+            T: ToValue {
+
+        array_keys_search(self.as_ref(), search_value, strict)
+    }
+
+    /// Applies the callback to the elements of the array, see array_map().
+    fn php_array_map<R>(&self, callback: impl FnMut(&T) -> R) -> Vec<R> {
+        array_map(callback, self.as_ref())
+    }
+
+    /// Applies the callback to the elements of the array and the other arrays, see
+    /// array_map_multiple().
+    fn php_array_map_multiple<R>(&self, callback: impl FnMut(&[Option<&T>]) -> R, arrays: &[&[T]]) -> Vec<R> {
+        array_map_multiple(callback, &with_others(self.as_ref(), arrays))
+    }
+
+    /// Zips the array and the other arrays into an array of arrays, see array_map_zip().
+    fn php_array_map_zip(&self, arrays: &[&[T]]) -> PhpArray<Value>
+        where
+            T: ToValue {
+
+        array_map_zip(&with_others(self.as_ref(), arrays))
+    }
+
+    /// Pad array to the specified length with a value, see array_pad().
+    fn php_array_pad(&self, length: isize, value: T) -> Result<Vec<T>, PhpError>
+        where
+            T: Clone {
+
+        array_pad(self.as_ref(), length, value)
+    }
+
+    /// Pick one or more random keys out of an array, see array_rand().
+    fn php_array_rand(&self, num: usize) -> Result<Vec<usize>, PhpError> {
+        array_rand(self.as_ref(), num)
+    }
+
+    /// Pick one or more random keys out of an array using the given engine, see
+    /// array_rand_with().
+    fn php_array_rand_with<E>(&self, num: usize, engine: &mut E) -> Result<Vec<usize>, PhpError>
+        where
+            E: Engine + ?Sized {
+
+        array_rand_with(self.as_ref(), num, engine)
+    }
+
+    /// Iteratively reduce the array to a single value using a callback function, see
+    /// array_reduce().
+    fn php_array_reduce<R>(&self, callback: impl FnMut(R, &T) -> R, initial: R) -> R {
+        array_reduce(self.as_ref(), callback, initial)
+    }
+
+    /// Searches the array for a given value and returns the first corresponding key, see
+    /// array_search().
+    fn php_array_search(&self, needle: &T, strict: bool) -> Option<usize>
+        where
+            T: ToValue {
+
+        array_search(needle, self.as_ref(), strict)
+    }
+
+    /// Extract a slice of the array, see array_slice().
+    fn php_array_slice(&self, offset: isize, length: isize) -> Vec<T>
+        where
+            T: Clone {
+
+        array_slice(self.as_ref(), offset, length, false)
+    }
+
+    /// Computes the difference of arrays by using a callback function for data comparison, see
+    /// array_udiff().
+    fn php_array_udiff(&self, arrays: &[&[T]], value_compare_func: impl FnMut(&T, &T) -> Ordering) -> Vec<T>
+        where
+            T: Clone {
+
+        array_udiff(self.as_ref(), arrays, value_compare_func)
+    }
+
+    /// Computes the difference of arrays with additional index check, compares data by a callback
+    /// function, see array_udiff_assoc().
+    fn php_array_udiff_assoc(&self, arrays: &[&[T]], value_compare_func: impl FnMut(&T, &T) -> Ordering) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_udiff_assoc(self.as_ref(), arrays, value_compare_func)
+    }
+
+    /// Computes the difference of arrays with additional index check, compares data and indexes by
+    /// a callback function, see array_udiff_uassoc().
+    fn php_array_udiff_uassoc(
+        &self,
+        arrays: &[&[T]],
+        value_compare_func: impl FnMut(&T, &T) -> Ordering,
+        key_compare_func: impl FnMut(&usize, &usize) -> Ordering,
+    ) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_udiff_uassoc(self.as_ref(), arrays, value_compare_func, key_compare_func)
+    }
+
+    /// Computes the intersection of arrays, compares data by a callback function, see
+    /// array_uintersect().
+    fn php_array_uintersect(&self, arrays: &[&[T]], value_compare_func: impl FnMut(&T, &T) -> Ordering) -> Vec<T>
+        where
+            T: Clone {
+
+        array_uintersect(self.as_ref(), arrays, value_compare_func)
+    }
+
+    /// Computes the intersection of arrays with additional index check, compares data by a
+    /// callback function, see array_uintersect_assoc().
+    fn php_array_uintersect_assoc(&self, arrays: &[&[T]], value_compare_func: impl FnMut(&T, &T) -> Ordering) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_uintersect_assoc(self.as_ref(), arrays, value_compare_func)
+    }
+
+    /// Computes the intersection of arrays with additional index check, compares data and indexes
+    /// by separate callback functions, see array_uintersect_uassoc().
+    fn php_array_uintersect_uassoc(
+        &self,
+        arrays: &[&[T]],
+        value_compare_func: impl FnMut(&T, &T) -> Ordering,
+        key_compare_func: impl FnMut(&usize, &usize) -> Ordering,
+    ) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_uintersect_uassoc(self.as_ref(), arrays, value_compare_func, key_compare_func)
+    }
+
+    /// Removes duplicate values from an array, see array_unique().
+    fn php_array_unique(&self, flags: u32) -> Vec<T>
+        where
+            T: ToValue + Clone {
+
+        array_unique(self.as_ref(), flags)
+    }
+
+    /// Return all the values of an array, see array_values().
+    fn php_array_values(&self) -> Vec<T>
+        where
+            T: Clone {
+
+        array_values(self.as_ref())
+    }
+
+    /// Apply a user supplied function to every member of an array, see array_walk().
+    fn php_array_walk(&mut self, callback: impl FnMut(&mut T, usize)) {
+        array_walk(self.as_mut(), callback);
+    }
+
+    /// Apply a user function recursively to every member of an array, see array_walk_recursive().
+    fn php_array_walk_recursive(&mut self, callback: impl FnMut(&mut Value, &[Key]))
+        where
+            [T]: ArrayLike<Key = usize, Value = Value> {
+
+        array_walk_recursive(self.as_mut(), callback);
+    }
+
+    /// Apply a user function recursively to every member of an array, passing an argument and
+    /// stopping when the callback breaks, see array_walk_recursive_with().
+    fn php_array_walk_recursive_with<A, B>(&mut self, callback: impl FnMut(&mut Value, &[Key], &A) -> ControlFlow<B>, arg: A) -> ControlFlow<B>
+        where
+            [T]: ArrayLike<Key = usize, Value = Value> {
+
+        array_walk_recursive_with(self.as_mut(), callback, arg)
+    }
+
+    /// Apply a user supplied function to every member of an array, passing an argument and
+    /// stopping when the callback breaks, see array_walk_with().
+    fn php_array_walk_with<A, B>(&mut self, callback: impl FnMut(&mut T, usize, &A) -> ControlFlow<B>, arg: A) -> ControlFlow<B> {
+        array_walk_with(self.as_mut(), callback, arg)
+    }
+
+    /// Join the elements with a string, see implode().
+    fn php_implode<G>(&self, glue: G) -> PhpString
+        where
+            T: AsRef<[u8]>,
+            G: AsRef<[u8]> {
+
+        implode(glue, self.as_ref())
+    }
+
+    /// Checks if a value exists in an array, see in_array().
+    fn php_in_array(&self, needle: &T, strict: bool) -> bool
+        where
+            T: ToValue {
+
+        in_array(needle, self.as_ref(), strict)
+    }
+
+    /// Returns the JSON representation of the array, see json_encode().
+    fn php_json_encode(&self, flags: u32, depth: usize) -> Result<String, JsonError>
+        where
+            T: ToValue {
+
+        json_encode(self.as_ref(), flags, depth)
+    }
+
+    /// Sort an array using a case insensitive "natural order" algorithm, see natcasesort().
+    fn php_natcasesort(&mut self)
+        where
+            T: ToValue {
+
+        sort_slice(self.as_mut(), SORT_NATURAL | SORT_FLAG_CASE, false);
+    }
+
+    /// Sort an array using a "natural order" algorithm, see natsort().
+    fn php_natsort(&mut self)
+        where
+            T: ToValue {
+
+        sort_slice(self.as_mut(), SORT_NATURAL, false);
+    }
+
+    /// Sort an array in descending order, see rsort().
+    fn php_rsort(&mut self, flags: u32)
+        where
+            T: ToValue {
+
+        sort_slice(self.as_mut(), flags, true);
+    }
+
+    /// Generates a storable representation of the array, see serialize().
+    fn php_serialize(&self) -> PhpString
+        where
+            T: ToValue {
+
+        serialize(self.as_ref())
+    }
+
+    /// Shuffle an array, see shuffle().
+    fn php_shuffle(&mut self) {
+        shuffle_slice(self.as_mut());
+    }
+
+    /// Sort an array in ascending order, see sort().
+    fn php_sort(&mut self, flags: u32)
+        where
+            T: ToValue {
+
+        sort_slice(self.as_mut(), flags, false);
+    }
+
+    /// Sort an array by values using a user-defined comparison function, see usort().
+    fn php_usort(&mut self, callback: impl FnMut(&T, &T) -> Ordering) {
+        sort_slice_by(self.as_mut(), callback);
+    }
+}
+
+impl<T> PhpVecExt<T> for [T] {}
+
+impl<T> PhpVecExt<T> for Vec<T> {}
+
+/// Puts an array in front of the other arrays, for the functions that take them all at once.
+fn with_others<'a, T>(array: &'a [T], arrays: &[&'a [T]]) -> Vec<&'a [T]> {
+    let mut all = Vec::with_capacity(arrays.len() + 1);
+    all.push(array);
+    all.extend_from_slice(arrays);
+
+    all
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use crate::array::{array_slice, natcasesort, rsort, shuffle, sort, usort, PhpArray, SORT_REGULAR, SORT_STRING};
+    use crate::ext::PhpVecExt;
+    use crate::random::{mt_srand, MT_RAND_MT19937};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        let vec = vec![5, 3, 8, 3, 1];
+
+        assert_eq!(vec.php_array_slice(1, -1), array_slice(&vec, 1, -1, false));
+        assert_eq!(vec[1..].php_array_unique(0), [3, 8, 1]);
+        assert_eq!(vec.php_array_diff(&[&[3, 1]]), [5, 8]);
+        assert_eq!(vec.php_array_keys_search(&3, true), [1, 3]);
+        assert_eq!(vec.php_array_map(|value| value * 2), [10, 6, 16, 6, 2]);
+        assert_eq!(vec.php_array_reduce(|carry, value| carry + value, 0), 20);
+        assert_eq!(vec.php_array_chunk(2).unwrap(), [vec![5, 3], vec![8, 3], vec![1]]);
+        assert_eq!(vec.php_json_encode(0, 512).unwrap(), "[5,3,8,3,1]");
+
+        let mut slice = [10, 9, 2, 1];
+        slice.php_array_walk(|value, key| *value += key as i32);
+        assert_eq!(slice, [10, 10, 4, 4]);
+    }
+
+    #[test]
+    fn test_keys_and_others() {
+        let vec = vec![5, 3, 8, 3, 1];
+        let other = [5, 4, 8];
+
+        assert_eq!(vec[1..].php_array_diff(&[&other[..], &vec[..1]]), [3, 3, 1]);
+        assert_eq!(vec.php_array_diff_assoc(&[&other]), [3, 3, 1]);
+        assert_eq!(vec.php_array_intersect_assoc(&[&other]), [5, 8]);
+        assert_eq!(vec.php_array_diff_key(&[&other]), [3, 1]);
+        assert_eq!(vec.php_array_intersect_ukey(&[&other], |a, b| (a / 2).cmp(&(b / 2))), [5, 3, 8, 3]);
+        assert_eq!(vec.php_array_uintersect_uassoc(&[&other], |a, b| a.cmp(b), |a, b| a.cmp(b)), [5, 8]);
+        assert_eq!(vec.php_array_filter_key(|key| key % 2 == 0), [5, 8, 1]);
+        assert_eq!(vec.php_array_filter_both(|value, key| *value == 3 && *key > 1), [3]);
+        assert_eq!(vec.php_array_map_multiple(|values| values.iter().flatten().copied().sum::<i32>(), &[&other]), [10, 7, 16, 3, 1]);
+        assert_eq!(other.php_array_map_zip(&[&vec[..2]]), PhpArray::from(vec![Value::from(vec![5, 5]), Value::from(vec![4, 3]), Value::from(vec![Some(8), None])]));
+        assert_eq!(other.php_array_map_zip(&[]), PhpArray::from(vec![Value::from(5), Value::from(4), Value::from(8)]));
+
+        let mut words = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let result = words.php_array_walk_with(|word, key, suffix| {
+            word.push_str(suffix);
+
+            if key == 1 { ControlFlow::Break(key) } else { ControlFlow::Continue(()) }
+        }, "!");
+        assert_eq!(result, ControlFlow::Break(1));
+        assert_eq!(words, ["a!", "b!", "c"]);
+
+        let mut values = vec![Value::from(1), Value::from(vec![2, 3])];
+        values.php_array_walk_recursive(|value, path| *value = Value::from(path.len()));
+        assert_eq!(values, [Value::from(1), Value::from(vec![2, 2])]);
+    }
+
+    #[test]
+    fn test_sorts() {
+        let words = ["b10", "B2", "a", "10", "9", "", "b1"];
+
+        for flags in [SORT_REGULAR, SORT_STRING] {
+            let (mut vec, mut slice) = (words.to_vec(), words);
+            sort(&mut vec, flags);
+            slice.php_sort(flags);
+            assert_eq!(slice, vec[..]);
+
+            rsort(&mut vec, flags);
+            slice.php_rsort(flags);
+            assert_eq!(slice, vec[..]);
+        }
+
+        let (mut vec, mut slice) = (words.to_vec(), words);
+        natcasesort(&mut vec);
+        slice.php_natcasesort();
+        assert_eq!(slice, vec[..]);
+
+        let (mut vec, mut slice) = (words.to_vec(), words);
+        usort(&mut vec, |a, b| a.len().cmp(&b.len()));
+        slice.php_usort(|a, b| a.len().cmp(&b.len()));
+        assert_eq!(slice, vec[..]);
+
+        let (mut vec, mut slice) = ((0..50).collect::<Vec<i32>>(), (0..50).collect::<Vec<i32>>());
+        mt_srand(3, MT_RAND_MT19937);
+        shuffle(&mut vec);
+        mt_srand(3, MT_RAND_MT19937);
+        slice.as_mut_slice().php_shuffle();
+        assert_eq!(slice, vec);
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// All < and > are converted to \u003C and \u003E.
+pub const JSON_HEX_TAG: u32 = 1;
+
+/// All & are converted to \u0026.
+pub const JSON_HEX_AMP: u32 = 2;
+
+/// All ' are converted to \u0027.
+pub const JSON_HEX_APOS: u32 = 4;
+
+/// All " are converted to \u0022.
+pub const JSON_HEX_QUOT: u32 = 8;
+
+/// Outputs an object rather than an array when a non-associative array is used.
+pub const JSON_FORCE_OBJECT: u32 = 16;
+
+/// Encodes numeric strings as numbers.
+pub const JSON_NUMERIC_CHECK: u32 = 32;
+
+/// Don't escape /.
+pub const JSON_UNESCAPED_SLASHES: u32 = 64;
+
+/// Use whitespace in returned data to format it.
+pub const JSON_PRETTY_PRINT: u32 = 128;
+
+/// Encode multibyte Unicode characters literally (default is to escape as \uXXXX).
+pub const JSON_UNESCAPED_UNICODE: u32 = 256;
+
+/// Substitute some unencodable values instead of failing.
+pub const JSON_PARTIAL_OUTPUT_ON_ERROR: u32 = 512;
+
+/// Ensures that float values are always encoded as a float value.
+pub const JSON_PRESERVE_ZERO_FRACTION: u32 = 1024;
+
+/// The line terminators are kept unescaped when JSON_UNESCAPED_UNICODE is supplied.
+pub const JSON_UNESCAPED_LINE_TERMINATORS: u32 = 2048;
+
+/// Decodes JSON objects as arrays.
+pub const JSON_OBJECT_AS_ARRAY: u32 = 1;
+
+/// Decodes large integers as their original string value.
+pub const JSON_BIGINT_AS_STRING: u32 = 2;
+
+/// Throws an exception if an error occurs. Errors are always returned as a Result, so this flag
+/// has no effect.
+pub const JSON_THROW_ON_ERROR: u32 = 4194304;
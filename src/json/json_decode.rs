@@ -0,0 +1,465 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{Key, PhpArray};
+use crate::json::{JsonError, JSON_BIGINT_AS_STRING, JSON_OBJECT_AS_ARRAY};
+use crate::value::{Object, Value};
+
+/// Decodes a JSON string
+///
+/// # Description
+///
+/// Takes a JSON encoded string and converts it into a PHP value.
+///
+/// When assoc is true, JSON objects will be returned as associative arrays, otherwise they will be
+/// returned as objects of the class stdClass.
+///
+/// The depth is the maximum nesting depth of the structure being decoded, which PHP defaults to
+/// 512. It must be greater than zero.
+///
+/// The flags are a bitmask consisting of JSON_BIGINT_AS_STRING and JSON_OBJECT_AS_ARRAY, the
+/// latter having the same effect as setting assoc to true.
+///
+/// # Errors
+///
+/// Returns a JsonError if the JSON cannot be decoded or if the encoded data is deeper than the
+/// nesting limit.
+///
+/// # Examples
+///
+/// Example #1 json_decode() examples
+///
+/// ```
+/// use phpify::json::json_decode;
+/// use phpify::value::Value;
+///
+/// let json = r#"{"a":1,"b":2,"c":3,"d":4,"e":5}"#;
+///
+/// match json_decode(json, false, 512, 0).unwrap() {
+///     Value::Object(object) => {
+///         assert_eq!(object.class_name(), "stdClass");
+///         assert_eq!(object.get("c"), Some(&Value::Int(3)));
+///     },
+///     _ => unreachable!(),
+/// }
+///
+/// match json_decode(json, true, 512, 0).unwrap() {
+///     Value::Array(array) => assert_eq!(array.get("e"), Some(&Value::Int(5))),
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// Example #2 json_decode() of large integers
+///
+/// ```
+/// use phpify::json::{json_decode, JSON_BIGINT_AS_STRING};
+/// use phpify::value::Value;
+///
+/// let json = "12345678901234567890";
+///
+/// assert_eq!(json_decode(json, false, 512, 0).unwrap(), Value::Float(1.2345678901234567e19));
+/// assert_eq!(json_decode(json, false, 512, JSON_BIGINT_AS_STRING).unwrap(), Value::from("12345678901234567890"));
+/// ```
+pub fn json_decode(json: &str, assoc: bool, depth: usize, flags: u32) -> Result<Value, JsonError> {
+    if depth == 0 {
+        return Err(JsonError::Depth);
+    }
+
+    let mut decoder = Decoder {
+        json,
+        bytes: json.as_bytes(),
+        position: 0,
+        depth: 0,
+        max_depth: depth,
+        assoc: assoc || flags & JSON_OBJECT_AS_ARRAY != 0,
+        bigint_as_string: flags & JSON_BIGINT_AS_STRING != 0,
+    };
+
+    let value = decoder.value()?;
+    decoder.whitespace();
+
+    if decoder.position != decoder.bytes.len() {
+        return Err(JsonError::Syntax);
+    }
+
+    Ok(value)
+}
+
+struct Decoder<'a> {
+    json: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+    depth: usize,
+    max_depth: usize,
+    assoc: bool,
+    bigint_as_string: bool,
+}
+
+impl Decoder<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, JsonError> {
+        self.whitespace();
+
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array().map(Value::Array),
+            Some(b'"') => self.string().map(Value::from),
+            Some(b'-') | Some(b'0'..=b'9') => self.number(),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'n') => self.literal("null", Value::Null),
+            _ => Err(JsonError::Syntax),
+        }
+    }
+
+    fn literal(&mut self, literal: &str, value: Value) -> Result<Value, JsonError> {
+        if !self.bytes[self.position..].starts_with(literal.as_bytes()) {
+            return Err(JsonError::Syntax);
+        }
+
+        self.position += literal.len();
+
+        Ok(value)
+    }
+
+    fn number(&mut self) -> Result<Value, JsonError> {
+        let start = self.position;
+        let mut is_float = false;
+
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+
+        match self.peek() {
+            Some(b'0') => self.position += 1,
+            Some(b'1'..=b'9') => self.digits(),
+            _ => return Err(JsonError::Syntax),
+        }
+
+        if self.peek() == Some(b'.') {
+            is_float = true;
+            self.position += 1;
+            self.required_digits()?;
+        }
+
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            is_float = true;
+            self.position += 1;
+
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.position += 1;
+            }
+
+            self.required_digits()?;
+        }
+
+        let number = &self.json[start..self.position];
+
+        if !is_float {
+            if let Ok(int) = number.parse() {
+                return Ok(Value::Int(int));
+            }
+
+            if self.bigint_as_string {
+                return Ok(Value::from(number));
+            }
+        }
+
+        number.parse().map(Value::Float).map_err(|_| JsonError::Syntax)
+    }
+
+    fn digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn required_digits(&mut self) -> Result<(), JsonError> {
+        let start = self.position;
+        self.digits();
+
+        if self.position == start {
+            return Err(JsonError::Syntax);
+        }
+
+        Ok(())
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.position += 1;
+
+        let mut string = String::new();
+
+        loop {
+            let start = self.position;
+
+            while let Some(byte) = self.peek() {
+                if byte == b'"' || byte == b'\\' || byte < 0x20 {
+                    break;
+                }
+                self.position += 1;
+            }
+
+            string.push_str(&self.json[start..self.position]);
+
+            match self.peek() {
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(string);
+                },
+                Some(b'\\') => {
+                    self.position += 1;
+                    string.push(self.escape()?);
+                },
+                _ => return Err(JsonError::CtrlChar),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, JsonError> {
+        let char = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.position += 1;
+                return self.unicode_escape();
+            },
+            _ => return Err(JsonError::Syntax),
+        };
+
+        self.position += 1;
+
+        Ok(char)
+    }
+
+    /// Decodes the digits of a \u escape, combining surrogate pairs.
+    fn unicode_escape(&mut self) -> Result<char, JsonError> {
+        let unit = self.hex()?;
+
+        let code_point = match unit {
+            0xD800..=0xDBFF => {
+                if !self.bytes[self.position..].starts_with(b"\\u") {
+                    return Err(JsonError::Utf16);
+                }
+
+                self.position += 2;
+                let low = self.hex()?;
+
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return Err(JsonError::Utf16);
+                }
+
+                0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00)
+            },
+            0xDC00..=0xDFFF => return Err(JsonError::Utf16),
+            _ => unit,
+        };
+
+        char::from_u32(code_point).ok_or(JsonError::Utf16)
+    }
+
+    fn hex(&mut self) -> Result<u32, JsonError> {
+        let digits = self.json.get(self.position..self.position + 4).ok_or(JsonError::Syntax)?;
+
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(JsonError::Syntax);
+        }
+
+        self.position += 4;
+
+        u32::from_str_radix(digits, 16).map_err(|_| JsonError::Syntax)
+    }
+
+    fn enter(&mut self) -> Result<(), JsonError> {
+        self.position += 1;
+        self.depth += 1;
+
+        if self.depth > self.max_depth {
+            return Err(JsonError::Depth);
+        }
+
+        Ok(())
+    }
+
+    fn array(&mut self) -> Result<PhpArray<Value>, JsonError> {
+        self.enter()?;
+
+        let mut array = PhpArray::new();
+
+        self.whitespace();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            self.depth -= 1;
+            return Ok(array);
+        }
+
+        loop {
+            array.push(self.value()?);
+
+            self.whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => break,
+                _ => return Err(JsonError::Syntax),
+            }
+        }
+
+        self.position += 1;
+        self.depth -= 1;
+
+        Ok(array)
+    }
+
+    fn object(&mut self) -> Result<Value, JsonError> {
+        self.enter()?;
+
+        let mut properties = PhpArray::new();
+
+        self.whitespace();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+        } else {
+            loop {
+                self.whitespace();
+                if self.peek() != Some(b'"') {
+                    return Err(JsonError::Syntax);
+                }
+
+                let key = self.string()?;
+
+                self.whitespace();
+                if self.peek() != Some(b':') {
+                    return Err(JsonError::Syntax);
+                }
+                self.position += 1;
+
+                let value = self.value()?;
+
+                if !self.assoc && key.starts_with('\0') {
+                    return Err(JsonError::InvalidPropertyName);
+                }
+
+                properties.insert(Key::from(key), value);
+
+                self.whitespace();
+                match self.peek() {
+                    Some(b',') => self.position += 1,
+                    Some(b'}') => break,
+                    _ => return Err(JsonError::Syntax),
+                }
+            }
+
+            self.position += 1;
+        }
+
+        self.depth -= 1;
+
+        if self.assoc {
+            return Ok(Value::Array(properties));
+        }
+
+        Ok(Value::Object(Object::with_properties("stdClass", properties)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::PhpArray;
+    use crate::json::{json_decode, json_encode, JsonError, JSON_BIGINT_AS_STRING, JSON_OBJECT_AS_ARRAY};
+    use crate::value::{Object, Value};
+
+    fn decode(json: &str) -> Result<Value, JsonError> {
+        json_decode(json, true, 512, 0)
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(decode(" null "), Ok(Value::Null));
+        assert_eq!(decode("true"), Ok(Value::Bool(true)));
+        assert_eq!(decode("-0"), Ok(Value::Int(0)));
+        assert_eq!(decode("-12"), Ok(Value::Int(-12)));
+        assert_eq!(decode("1.0"), Ok(Value::Float(1.0)));
+        assert_eq!(decode("1E2"), Ok(Value::Float(100.0)));
+        assert_eq!(decode("-1.5e-2"), Ok(Value::Float(-0.015)));
+        assert_eq!(decode("9223372036854775807"), Ok(Value::Int(i64::MAX)));
+        assert_eq!(decode("-9223372036854775809"), Ok(Value::Float(-9223372036854775809.0)));
+        assert_eq!(json_decode("-9223372036854775809", true, 512, JSON_BIGINT_AS_STRING), Ok(Value::from("-9223372036854775809")));
+        assert_eq!(json_decode("1e30", true, 512, JSON_BIGINT_AS_STRING), Ok(Value::Float(1e30)));
+
+        assert_eq!(decode(r#""a\"\\\/\b\f\n\r\té€𝄞""#), Ok(Value::from("a\"\\/\u{8}\u{c}\n\r\t\u{e9}\u{20ac}\u{1d11e}")));
+        assert_eq!(decode("\"h\u{e9}llo\""), Ok(Value::from("h\u{e9}llo")));
+        assert_eq!(decode(r#""\u0000""#), Ok(Value::from("\0")));
+
+        assert_eq!(decode("[]"), Ok(Value::Array(PhpArray::new())));
+        assert_eq!(decode("[1, [2], \"3\"]"), Ok(Value::from(vec![Value::from(1), Value::from(vec![2]), Value::from("3")])));
+
+        let expected: PhpArray<Value> = vec![("a", Value::from(3)), ("1", Value::Null), ("", Value::from(true))].into_iter().collect();
+        assert_eq!(decode(r#" { "a" : 1 , "1" : null, "" : true, "a": 3 } "#), Ok(Value::Array(expected.clone())));
+        assert_eq!(json_decode(r#"{"a":3,"1":null,"":true}"#, false, 512, JSON_OBJECT_AS_ARRAY), Ok(Value::Array(expected.clone())));
+        assert_eq!(json_decode(r#"{"a":3,"1":null,"":true}"#, false, 512, 0), Ok(Value::Object(Object::with_properties("stdClass", expected))));
+        assert_eq!(json_decode("{}", false, 512, 0), Ok(Value::Object(Object::new("stdClass"))));
+
+        let value = Value::from(vec![Value::from(1.5), Value::from("x/\u{e9}"), Value::from(vec![true])]);
+        assert_eq!(decode(&json_encode(&value, 0, 512).unwrap()), Ok(value));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(decode(""), Err(JsonError::Syntax));
+        assert_eq!(decode("[1,]"), Err(JsonError::Syntax));
+        assert_eq!(decode("[1}"), Err(JsonError::Syntax));
+        assert_eq!(decode("{'a':1}"), Err(JsonError::Syntax));
+        assert_eq!(decode("{\"a\" 1}"), Err(JsonError::Syntax));
+        assert_eq!(decode("01"), Err(JsonError::Syntax));
+        assert_eq!(decode("1."), Err(JsonError::Syntax));
+        assert_eq!(decode(".5"), Err(JsonError::Syntax));
+        assert_eq!(decode("+1"), Err(JsonError::Syntax));
+        assert_eq!(decode("1e"), Err(JsonError::Syntax));
+        assert_eq!(decode("True"), Err(JsonError::Syntax));
+        assert_eq!(decode("nul"), Err(JsonError::Syntax));
+        assert_eq!(decode("1 2"), Err(JsonError::Syntax));
+        assert_eq!(decode(r#""\x""#), Err(JsonError::Syntax));
+        assert_eq!(decode(r#""\u12""#), Err(JsonError::Syntax));
+        assert_eq!(decode("\"a\nb\""), Err(JsonError::CtrlChar));
+        assert_eq!(decode("\"abc"), Err(JsonError::CtrlChar));
+        assert_eq!(decode(r#""\ud834""#), Err(JsonError::Utf16));
+        assert_eq!(decode(r#""\udd1e""#), Err(JsonError::Utf16));
+        assert_eq!(decode(r#""\ud834A""#), Err(JsonError::Utf16));
+        assert_eq!(json_decode(r#"{"\u0000a":1}"#, false, 512, 0), Err(JsonError::InvalidPropertyName));
+        assert!(json_decode(r#"{"\u0000a":1}"#, true, 512, 0).is_ok());
+
+        assert_eq!(json_decode("[[1]]", true, 2, 0), Ok(Value::from(vec![vec![1]])));
+        assert_eq!(json_decode("[[1]]", true, 1, 0), Err(JsonError::Depth));
+        assert_eq!(json_decode("1", true, 1, 0), Ok(Value::Int(1)));
+        assert_eq!(json_decode("1", true, 0, 0), Err(JsonError::Depth));
+    }
+}
@@ -0,0 +1,355 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{Key, PhpArray};
+use crate::json::{
+    JsonError,
+    JSON_FORCE_OBJECT,
+    JSON_HEX_AMP,
+    JSON_HEX_APOS,
+    JSON_HEX_QUOT,
+    JSON_HEX_TAG,
+    JSON_NUMERIC_CHECK,
+    JSON_PARTIAL_OUTPUT_ON_ERROR,
+    JSON_PRESERVE_ZERO_FRACTION,
+    JSON_PRETTY_PRINT,
+    JSON_UNESCAPED_LINE_TERMINATORS,
+    JSON_UNESCAPED_SLASHES,
+    JSON_UNESCAPED_UNICODE,
+};
+use crate::value::{gcvt, parse_numeric_whole, Number, ToValue, Value};
+
+/// Returns the JSON representation of a value
+///
+/// # Description
+///
+/// Returns a string containing the JSON representation of the supplied value.
+///
+/// Arrays whose keys are 0 to n - 1 in order are encoded as JSON arrays, other arrays and objects
+/// as JSON objects. Floats are encoded with the shortest representation that round-trips, like the
+/// default serialize_precision of -1 does.
+///
+/// The flags are a bitmask consisting of JSON_FORCE_OBJECT, JSON_HEX_AMP, JSON_HEX_APOS,
+/// JSON_HEX_QUOT, JSON_HEX_TAG, JSON_NUMERIC_CHECK, JSON_PARTIAL_OUTPUT_ON_ERROR,
+/// JSON_PRESERVE_ZERO_FRACTION, JSON_PRETTY_PRINT, JSON_UNESCAPED_LINE_TERMINATORS,
+/// JSON_UNESCAPED_SLASHES and JSON_UNESCAPED_UNICODE.
+///
+/// The depth is the maximum nesting of arrays and objects, which PHP defaults to 512.
+///
+/// # Errors
+///
+/// Returns JsonError::InfOrNan for infinite and NAN floats, JsonError::Utf8 for strings that are not
+/// valid UTF-8 and JsonError::Depth if the depth is exceeded. With JSON_PARTIAL_OUTPUT_ON_ERROR, 0 is
+/// substituted for unencodable floats, null for invalid strings and the depth is not enforced
+/// instead.
+///
+/// # Examples
+///
+/// Example #1 A json_encode() example
+///
+/// ```
+/// use phpify::array::PhpArray;
+/// use phpify::json::json_encode;
+///
+/// let mut arr = PhpArray::new();
+/// arr.insert("a", 1);
+/// arr.insert("b", 2);
+/// arr.insert("c", 3);
+///
+/// assert_eq!(json_encode(&arr, 0, 512).unwrap(), r#"{"a":1,"b":2,"c":3}"#);
+/// ```
+///
+/// Example #2 Using the flags
+///
+/// ```
+/// use phpify::json::{json_encode, JSON_PRETTY_PRINT, JSON_UNESCAPED_SLASHES, JSON_UNESCAPED_UNICODE};
+///
+/// assert_eq!(json_encode("a/é", 0, 512).unwrap(), r#""a\/\u00e9""#);
+/// assert_eq!(json_encode("a/é", JSON_UNESCAPED_SLASHES | JSON_UNESCAPED_UNICODE, 512).unwrap(), r#""a/é""#);
+/// assert_eq!(json_encode(&vec![1, 2], JSON_PRETTY_PRINT, 512).unwrap(), "[\n    1,\n    2\n]");
+/// ```
+///
+/// Example #3 JSON_PRESERVE_ZERO_FRACTION
+///
+/// ```
+/// use phpify::json::{json_encode, JSON_PRESERVE_ZERO_FRACTION};
+///
+/// assert_eq!(json_encode(&12.0, JSON_PRESERVE_ZERO_FRACTION, 512).unwrap(), "12.0");
+/// assert_eq!(json_encode(&12.0, 0, 512).unwrap(), "12");
+/// ```
+pub fn json_encode<T>(value: &T, flags: u32, depth: usize) -> Result<String, JsonError>
+    where
+        T: ToValue + ?Sized {
+
+    let mut encoder = Encoder {
+        buffer: String::new(),
+        flags,
+        depth: 0,
+        max_depth: depth,
+    };
+
+    encoder.value(&value.to_value())?;
+
+    Ok(encoder.buffer)
+}
+
+struct Encoder {
+    buffer: String,
+    flags: u32,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl Encoder {
+    fn has(&self, flag: u32) -> bool {
+        self.flags & flag != 0
+    }
+
+    fn value(&mut self, value: &Value) -> Result<(), JsonError> {
+        match value {
+            Value::Null => self.buffer.push_str("null"),
+            Value::Bool(bool) => self.buffer.push_str(if *bool { "true" } else { "false" }),
+            Value::Int(int) => self.buffer.push_str(&int.to_string()),
+            Value::Float(float) => self.float(*float)?,
+            Value::String(string) => self.string(string)?,
+            Value::Array(array) => {
+                let is_list = !self.has(JSON_FORCE_OBJECT) && array.keys().zip(0..).all(|(key, index)| *key == Key::Int(index));
+                self.array(array, is_list, false)?;
+            },
+            Value::Object(object) => self.array(object.properties(), false, true)?,
+        }
+
+        Ok(())
+    }
+
+    fn float(&mut self, float: f64) -> Result<(), JsonError> {
+        if !float.is_finite() {
+            if !self.has(JSON_PARTIAL_OUTPUT_ON_ERROR) {
+                return Err(JsonError::InfOrNan);
+            }
+
+            self.buffer.push('0');
+            return Ok(());
+        }
+
+        let formatted = gcvt(float, -1, 'e');
+        self.buffer.push_str(&formatted);
+
+        if self.has(JSON_PRESERVE_ZERO_FRACTION) && !formatted.contains(['.', 'e']) {
+            self.buffer.push_str(".0");
+        }
+
+        Ok(())
+    }
+
+    fn string(&mut self, string: &[u8]) -> Result<(), JsonError> {
+        if self.has(JSON_NUMERIC_CHECK) {
+            match parse_numeric_whole(string) {
+                Some(Number::Int(int)) => return self.value(&Value::Int(int)),
+                Some(Number::Float(float)) => return self.value(&Value::Float(float)),
+                None => {},
+            }
+        }
+
+        self.utf8_string(string)
+    }
+
+    /// Escapes a string that must be valid UTF-8.
+    fn utf8_string(&mut self, string: &[u8]) -> Result<(), JsonError> {
+        match std::str::from_utf8(string) {
+            Ok(string) => self.escape_string(string),
+            Err(_) if self.has(JSON_PARTIAL_OUTPUT_ON_ERROR) => self.buffer.push_str("null"),
+            Err(_) => return Err(JsonError::Utf8),
+        }
+
+        Ok(())
+    }
+
+    fn escape_string(&mut self, string: &str) {
+        self.buffer.push('"');
+
+        for char in string.chars() {
+            match char {
+                '"' if self.has(JSON_HEX_QUOT) => self.buffer.push_str("\\u0022"),
+                '"' => self.buffer.push_str("\\\""),
+                '\\' => self.buffer.push_str("\\\\"),
+                '/' if !self.has(JSON_UNESCAPED_SLASHES) => self.buffer.push_str("\\/"),
+                '\u{8}' => self.buffer.push_str("\\b"),
+                '\u{c}' => self.buffer.push_str("\\f"),
+                '\n' => self.buffer.push_str("\\n"),
+                '\r' => self.buffer.push_str("\\r"),
+                '\t' => self.buffer.push_str("\\t"),
+                '<' if self.has(JSON_HEX_TAG) => self.buffer.push_str("\\u003C"),
+                '>' if self.has(JSON_HEX_TAG) => self.buffer.push_str("\\u003E"),
+                '&' if self.has(JSON_HEX_AMP) => self.buffer.push_str("\\u0026"),
+                '\'' if self.has(JSON_HEX_APOS) => self.buffer.push_str("\\u0027"),
+                '\u{2028}' | '\u{2029}' if !self.has(JSON_UNESCAPED_LINE_TERMINATORS) => self.escape(char),
+                _ if char < ' ' => self.escape(char),
+                _ if !char.is_ascii() && !self.has(JSON_UNESCAPED_UNICODE) => self.escape(char),
+                _ => self.buffer.push(char),
+            }
+        }
+
+        self.buffer.push('"');
+    }
+
+    /// Escapes a character as \uXXXX, using a surrogate pair outside the Basic Multilingual Plane.
+    fn escape(&mut self, char: char) {
+        let mut units = [0; 2];
+
+        for unit in char.encode_utf16(&mut units) {
+            self.buffer.push_str(&format!("\\u{:04x}", unit));
+        }
+    }
+
+    /// Encodes an array or the properties of an object, leaving out the mangled names of the
+    /// protected and private properties.
+    fn array(&mut self, array: &PhpArray<Value>, is_list: bool, is_object: bool) -> Result<(), JsonError> {
+        self.buffer.push(if is_list { '[' } else { '{' });
+        self.depth += 1;
+
+        let mut need_comma = false;
+
+        for (key, value) in array {
+            if let Key::Str(name) = key {
+                if is_object && name.starts_with(b"\0") {
+                    continue;
+                }
+            }
+
+            if need_comma {
+                self.buffer.push(',');
+            }
+            need_comma = true;
+
+            self.newline();
+
+            if !is_list {
+                match key {
+                    Key::Int(int) => self.escape_string(&int.to_string()),
+                    Key::Str(name) => self.utf8_string(name)?,
+                }
+                self.buffer.push(':');
+
+                if self.has(JSON_PRETTY_PRINT) {
+                    self.buffer.push(' ');
+                }
+            }
+
+            self.value(value)?;
+        }
+
+        if self.depth > self.max_depth && !self.has(JSON_PARTIAL_OUTPUT_ON_ERROR) {
+            return Err(JsonError::Depth);
+        }
+
+        self.depth -= 1;
+
+        if need_comma {
+            self.newline();
+        }
+
+        self.buffer.push(if is_list { ']' } else { '}' });
+
+        Ok(())
+    }
+
+    /// Starts a new, indented line when pretty printing.
+    fn newline(&mut self) {
+        if self.has(JSON_PRETTY_PRINT) {
+            self.buffer.push('\n');
+            self.buffer.push_str(&"    ".repeat(self.depth));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::PhpArray;
+    use crate::json::{
+        json_encode,
+        JsonError,
+        JSON_FORCE_OBJECT,
+        JSON_HEX_AMP,
+        JSON_HEX_APOS,
+        JSON_HEX_QUOT,
+        JSON_HEX_TAG,
+        JSON_NUMERIC_CHECK,
+        JSON_PARTIAL_OUTPUT_ON_ERROR,
+        JSON_PRESERVE_ZERO_FRACTION,
+        JSON_PRETTY_PRINT,
+        JSON_UNESCAPED_LINE_TERMINATORS,
+        JSON_UNESCAPED_UNICODE,
+    };
+    use crate::string::PhpString;
+    use crate::value::{Object, Value};
+
+    #[test]
+    fn test() {
+        assert_eq!(json_encode(&Value::Null, 0, 512), Ok("null".to_string()));
+        assert_eq!(json_encode(&true, 0, 512), Ok("true".to_string()));
+        assert_eq!(json_encode(&-5, 0, 512), Ok("-5".to_string()));
+        assert_eq!(json_encode(&0.1, 0, 512), Ok("0.1".to_string()));
+        assert_eq!(json_encode(&1e25, 0, 512), Ok("1.0e+25".to_string()));
+        assert_eq!(json_encode(&1e25, JSON_PRESERVE_ZERO_FRACTION, 512), Ok("1.0e+25".to_string()));
+        assert_eq!(json_encode(&-0.0, JSON_PRESERVE_ZERO_FRACTION, 512), Ok("-0.0".to_string()));
+        assert_eq!(json_encode(&f64::NAN, 0, 512), Err(JsonError::InfOrNan));
+        assert_eq!(json_encode(&vec![f64::INFINITY], JSON_PARTIAL_OUTPUT_ON_ERROR, 512), Ok("[0]".to_string()));
+
+        assert_eq!(json_encode("\"\\/\u{8}\u{c}\n\r\t\u{1}", 0, 512), Ok(r#""\"\\\/\b\f\n\r\t\u0001""#.to_string()));
+        assert_eq!(json_encode("<a href='x'>&</a>", JSON_HEX_TAG | JSON_HEX_APOS | JSON_HEX_AMP, 512), Ok(r#""\u003Ca href=\u0027x\u0027\u003E\u0026\u003C\/a\u003E""#.to_string()));
+        assert_eq!(json_encode("\"", JSON_HEX_QUOT, 512), Ok(r#""\u0022""#.to_string()));
+        assert_eq!(json_encode("\u{20ac}\u{1d11e}", 0, 512), Ok(r#""\u20ac\ud834\udd1e""#.to_string()));
+        assert_eq!(json_encode("\u{20ac}\u{2028}", JSON_UNESCAPED_UNICODE, 512), Ok("\"\u{20ac}\\u2028\"".to_string()));
+        assert_eq!(json_encode("\u{2028}", JSON_UNESCAPED_UNICODE | JSON_UNESCAPED_LINE_TERMINATORS, 512), Ok("\"\u{2028}\"".to_string()));
+        assert_eq!(json_encode(&vec!["1", "1.5", "1e2", "a"], JSON_NUMERIC_CHECK, 512), Ok(r#"[1,1.5,100,"a"]"#.to_string()));
+        let array: PhpArray<i32> = vec![("1.5", 1)].into_iter().collect();
+        assert_eq!(json_encode(&array, JSON_NUMERIC_CHECK, 512), Ok(r#"{"1.5":1}"#.to_string()));
+
+        assert_eq!(json_encode(&Vec::<i32>::new(), 0, 512), Ok("[]".to_string()));
+        assert_eq!(json_encode(&Vec::<i32>::new(), JSON_FORCE_OBJECT, 512), Ok("{}".to_string()));
+        assert_eq!(json_encode(&vec![1, 2], JSON_FORCE_OBJECT, 512), Ok(r#"{"0":1,"1":2}"#.to_string()));
+
+        let mut array = PhpArray::from(vec![1, 2, 3]);
+        array.remove(1);
+        assert_eq!(json_encode(&array, 0, 512), Ok(r#"{"0":1,"2":3}"#.to_string()));
+
+        let mut array = PhpArray::new();
+        array.insert("\0a", 1);
+        assert_eq!(json_encode(&array, 0, 512), Ok(r#"{"\u0000a":1}"#.to_string()));
+
+        let mut object = Object::new("Foo");
+        object.set("a", vec![1]);
+        object.set("\0*\0b", 2);
+        object.set("c", Object::new("stdClass"));
+        assert_eq!(json_encode(&object, 0, 512), Ok(r#"{"a":[1],"c":{}}"#.to_string()));
+        assert_eq!(json_encode(&object, JSON_PRETTY_PRINT, 512), Ok("{\n    \"a\": [\n        1\n    ],\n    \"c\": {}\n}".to_string()));
+
+        assert_eq!(json_encode(&vec![vec![1]], 0, 2), Ok("[[1]]".to_string()));
+        assert_eq!(json_encode(&vec![vec![1]], 0, 1), Err(JsonError::Depth));
+        assert_eq!(json_encode(&vec![vec![1]], JSON_PARTIAL_OUTPUT_ON_ERROR, 1), Ok("[[1]]".to_string()));
+        assert_eq!(json_encode(&1, 0, 0), Ok("1".to_string()));
+
+        let binary = PhpString::from(&b"a\xffb"[..]);
+        assert_eq!(json_encode(&binary, 0, 512), Err(JsonError::Utf8));
+        assert_eq!(json_encode(&vec![binary.clone()], JSON_PARTIAL_OUTPUT_ON_ERROR, 512), Ok("[null]".to_string()));
+
+        let mut array = PhpArray::new();
+        array.insert(binary, 1);
+        assert_eq!(json_encode(&array, 0, 512), Err(JsonError::Utf8));
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::error::Error;
+use std::fmt;
+
+/// The error returned by json_encode() and json_decode().
+///
+/// # Description
+///
+/// The variants correspond to the JSON_ERROR_* constants, their codes being returned by code()
+/// and their messages, as returned by json_last_error_msg(), by msg().
+///
+/// # Examples
+///
+/// Example #1 JsonError example
+///
+/// ```
+/// use phpify::json::{json_decode, JsonError};
+///
+/// let error = json_decode("{'a': 1}", false, 512, 0).unwrap_err();
+///
+/// assert_eq!(error, JsonError::Syntax);
+/// assert_eq!(error.code(), 4);
+/// assert_eq!(error.msg(), "Syntax error");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonError {
+    /// The maximum stack depth has been exceeded.
+    Depth,
+
+    /// Invalid or malformed JSON.
+    StateMismatch,
+
+    /// Control character error, possibly incorrectly encoded.
+    CtrlChar,
+
+    /// Syntax error.
+    Syntax,
+
+    /// Malformed UTF-8 characters, possibly incorrectly encoded.
+    Utf8,
+
+    /// One or more recursive references in the value to be encoded.
+    Recursion,
+
+    /// One or more NAN or INF values in the value to be encoded.
+    InfOrNan,
+
+    /// A value of a type that cannot be encoded was given.
+    UnsupportedType,
+
+    /// A property name that cannot be encoded was given.
+    InvalidPropertyName,
+
+    /// Malformed UTF-16 characters, possibly incorrectly encoded.
+    Utf16,
+}
+
+impl JsonError {
+    /// Returns the value of the corresponding JSON_ERROR_* constant.
+    pub fn code(&self) -> u32 {
+        match self {
+            JsonError::Depth => 1,
+            JsonError::StateMismatch => 2,
+            JsonError::CtrlChar => 3,
+            JsonError::Syntax => 4,
+            JsonError::Utf8 => 5,
+            JsonError::Recursion => 6,
+            JsonError::InfOrNan => 7,
+            JsonError::UnsupportedType => 8,
+            JsonError::InvalidPropertyName => 9,
+            JsonError::Utf16 => 10,
+        }
+    }
+
+    /// Returns the error message, like json_last_error_msg() does.
+    pub fn msg(&self) -> &'static str {
+        match self {
+            JsonError::Depth => "Maximum stack depth exceeded",
+            JsonError::StateMismatch => "State mismatch (invalid or malformed JSON)",
+            JsonError::CtrlChar => "Control character error, possibly incorrectly encoded",
+            JsonError::Syntax => "Syntax error",
+            JsonError::Utf8 => "Malformed UTF-8 characters, possibly incorrectly encoded",
+            JsonError::Recursion => "Recursion detected",
+            JsonError::InfOrNan => "Inf and NaN cannot be JSON encoded",
+            JsonError::UnsupportedType => "Type is not supported",
+            JsonError::InvalidPropertyName => "The decoded property name is invalid",
+            JsonError::Utf16 => "Single unpaired UTF-16 surrogate in unicode escape",
+        }
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.msg())
+    }
+}
+
+impl Error for JsonError {}
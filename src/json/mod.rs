@@ -0,0 +1,42 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+mod constants;
+mod json_decode;
+mod json_encode;
+mod json_error;
+
+pub use constants::{
+    JSON_BIGINT_AS_STRING,
+    JSON_FORCE_OBJECT,
+    JSON_HEX_AMP,
+    JSON_HEX_APOS,
+    JSON_HEX_QUOT,
+    JSON_HEX_TAG,
+    JSON_NUMERIC_CHECK,
+    JSON_OBJECT_AS_ARRAY,
+    JSON_PARTIAL_OUTPUT_ON_ERROR,
+    JSON_PRESERVE_ZERO_FRACTION,
+    JSON_PRETTY_PRINT,
+    JSON_THROW_ON_ERROR,
+    JSON_UNESCAPED_LINE_TERMINATORS,
+    JSON_UNESCAPED_SLASHES,
+    JSON_UNESCAPED_UNICODE,
+};
+pub use json_decode::json_decode;
+pub use json_encode::json_encode;
+pub use json_error::JsonError;
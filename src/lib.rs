@@ -19,4 +19,12 @@
 //! other than making the transition from PHP to Rust easier.
 
 pub mod array;
+pub mod error;
+pub mod ext;
+pub mod json;
+mod macros;
+pub mod mbstring;
+pub mod random;
 pub mod string;
+pub mod value;
+pub mod var;
@@ -0,0 +1,282 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Creates a PhpArray of Values, the way a PHP array literal does.
+///
+/// # Description
+///
+/// Entries are separated by commas, and a trailing comma is allowed. An entry is either a value,
+/// stored under the next free integer key, or a key and a value separated by =>. Keys may be
+/// integers, strings or bools and are cast like PHP casts them, so "8" is stored under 8 and later
+/// entries without a key continue counting from the largest integer key.
+///
+/// Values are converted with Value::from(). A value written as [...] is a nested array literal, and
+/// null is Value::Null.
+///
+/// Entries whose key and value are single tokens, like literals, variables, null, nested [...]
+/// arrays or expressions in parentheses, do not count towards the recursion limit, so literals of
+/// any length can be written with them. Once an entry has a longer key or value, like -1 or a + b,
+/// the entries up to the last such entry are expanded recursively, one level for each of those
+/// entries and one for every eight of the others.
+///
+/// # Examples
+///
+/// Example #1 A simple array
+///
+/// ```
+/// use phpify::array::{Key, PhpArray};
+/// use phpify::php_array;
+/// use phpify::value::Value;
+///
+/// let array = php_array!["foo" => "bar", "bar" => "foo"];
+///
+/// assert_eq!(array["foo"], Value::from("bar"));
+/// assert_eq!(array["bar"], Value::from("foo"));
+/// ```
+///
+/// Example #2 Keys not on all elements
+///
+/// ```
+/// use phpify::array::Key;
+/// use phpify::php_array;
+///
+/// let array = php_array!["a", "b", 6 => "c", "d"];
+///
+/// assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::Int(0), &Key::Int(1), &Key::Int(6), &Key::Int(7)]);
+/// ```
+///
+/// Example #3 Nested arrays
+///
+/// ```
+/// use phpify::php_array;
+/// use phpify::value::Value;
+///
+/// let record = php_array![
+///     "id" => 1,
+///     "tags" => ["x", "y"],
+///     "parent" => null,
+/// ];
+///
+/// assert_eq!(record["tags"], Value::from(php_array!["x", "y"]));
+/// assert_eq!(record["parent"], Value::Null);
+/// ```
+#[macro_export]
+macro_rules! php_array {
+    ($($entries:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut array = $crate::array::PhpArray::<$crate::value::Value>::new();
+        $crate::__php_array_entries!(array; $($entries)*);
+        array
+    }};
+}
+
+/// Inserts the entries of a php_array! literal.
+///
+/// When every key and value is a single token tree, all entries are inserted by one repetition.
+/// Otherwise the leading entries are inserted, eight at a time while they are single token trees
+/// and one at a time when they are not, and the rest is handled the same way.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __php_array_entries {
+    ($array:ident; $($first:tt $(=> $second:tt)?),* $(,)?) => {
+        $($crate::__php_array_entry!($array; $first $(=> $second)?);)*
+    };
+    (
+        $array:ident;
+        $a:tt $(=> $A:tt)?, $b:tt $(=> $B:tt)?, $c:tt $(=> $C:tt)?, $d:tt $(=> $D:tt)?,
+        $e:tt $(=> $E:tt)?, $f:tt $(=> $F:tt)?, $g:tt $(=> $G:tt)?, $h:tt $(=> $H:tt)?,
+        $($rest:tt)*
+    ) => {
+        $crate::__php_array_entry!($array; $a $(=> $A)?);
+        $crate::__php_array_entry!($array; $b $(=> $B)?);
+        $crate::__php_array_entry!($array; $c $(=> $C)?);
+        $crate::__php_array_entry!($array; $d $(=> $D)?);
+        $crate::__php_array_entry!($array; $e $(=> $E)?);
+        $crate::__php_array_entry!($array; $f $(=> $F)?);
+        $crate::__php_array_entry!($array; $g $(=> $G)?);
+        $crate::__php_array_entry!($array; $h $(=> $H)?);
+        $crate::__php_array_entries!($array; $($rest)*);
+    };
+    ($array:ident; [$($value:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::__php_array_entry!($array; [$($value)*]);
+        $crate::__php_array_entries!($array; $($($rest)*)?);
+    };
+    ($array:ident; null $(, $($rest:tt)*)?) => {
+        $crate::__php_array_entry!($array; null);
+        $crate::__php_array_entries!($array; $($($rest)*)?);
+    };
+    ($array:ident; $key:expr => [$($value:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::__php_array_entry!($array; $key => [$($value)*]);
+        $crate::__php_array_entries!($array; $($($rest)*)?);
+    };
+    ($array:ident; $key:expr => null $(, $($rest:tt)*)?) => {
+        $crate::__php_array_entry!($array; $key => null);
+        $crate::__php_array_entries!($array; $($($rest)*)?);
+    };
+    ($array:ident; $key:expr => $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__php_array_entry!($array; $key => $value);
+        $crate::__php_array_entries!($array; $($($rest)*)?);
+    };
+    ($array:ident; $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__php_array_entry!($array; $value);
+        $crate::__php_array_entries!($array; $($($rest)*)?);
+    };
+}
+
+/// Inserts a single entry of a php_array! literal.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __php_array_entry {
+    ($array:ident; $key:tt => [$($value:tt)*]) => {
+        $array.insert($key, $crate::value::Value::Array($crate::php_array![$($value)*]));
+    };
+    ($array:ident; $key:tt => null) => {
+        $array.insert($key, $crate::value::Value::Null);
+    };
+    ($array:ident; $key:tt => $value:expr) => {
+        $array.insert($key, $crate::value::Value::from($value));
+    };
+    ($array:ident; [$($value:tt)*]) => {
+        $array.push($crate::value::Value::Array($crate::php_array![$($value)*]));
+    };
+    ($array:ident; null) => {
+        $array.push($crate::value::Value::Null);
+    };
+    ($array:ident; $value:expr) => {
+        $array.push($crate::value::Value::from($value));
+    };
+}
+
+/// Creates a Value, the way a PHP literal does.
+///
+/// # Description
+///
+/// null is Value::Null, [...] is an array literal as taken by php_array!, and any other value is
+/// converted with Value::from().
+///
+/// # Examples
+///
+/// Example #1 php!() examples
+///
+/// ```
+/// use phpify::{php, php_array};
+/// use phpify::value::Value;
+///
+/// assert_eq!(php!(null), Value::Null);
+/// assert_eq!(php!(true), Value::Bool(true));
+/// assert_eq!(php!(-5), Value::Int(-5));
+/// assert_eq!(php!(1.5), Value::Float(1.5));
+/// assert_eq!(php!("abc"), Value::String("abc".into()));
+/// assert_eq!(php!(["a" => 1]), Value::Array(php_array!["a" => 1]));
+/// ```
+#[macro_export]
+macro_rules! php {
+    (null) => {
+        $crate::value::Value::Null
+    };
+    ([$($entries:tt)*]) => {
+        $crate::value::Value::Array($crate::php_array![$($entries)*])
+    };
+    ($value:expr) => {
+        $crate::value::Value::from($value)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{Key, PhpArray};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        assert_eq!(php_array![], PhpArray::new());
+        assert_eq!(php_array![1, 2,], PhpArray::from(vec![Value::from(1), Value::from(2)]));
+
+        let name = String::from("name");
+        let array = php_array![
+            "8" => "a",
+            "b",
+            true => [],
+            -3 => [[1], "x" => null],
+            &name => 2 + 3,
+            null,
+        ];
+
+        assert_eq!(array.keys().collect::<Vec<&Key>>(), [&Key::Int(8), &Key::Int(9), &Key::Int(1), &Key::Int(-3), &Key::from("name"), &Key::Int(10)]);
+        assert_eq!(array[1], php!([]));
+        assert_eq!(array[-3], php!([[1], "x" => null]));
+        assert_eq!(array["name"], php!(5));
+        assert_eq!(array[10], php!(null));
+
+        let mut nested = PhpArray::new();
+        nested.push(Value::from(PhpArray::from(vec![Value::from(1)])));
+        nested.insert("x", Value::Null);
+        assert_eq!(array[-3], Value::from(nested));
+    }
+
+    #[test]
+    fn test_long() {
+        let array = php_array![
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+            20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39,
+            40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59,
+            60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79,
+            80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99,
+            100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115, 116, 117, 118, 119,
+            120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139,
+            140, 141, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159,
+            160, 161, 162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178, 179,
+            180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190, 191, 192, 193, 194, 195, 196, 197, 198, 199,
+            200, 201, 202, 203, 204, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 215, 216, 217, 218, 219,
+            220, 221, 222, 223, 224, 225, 226, 227, 228, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 239,
+            240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259,
+            260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 270, 271, 272, 273, 274, 275, 276, 277, 278, 279,
+            280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290, 291, 292, 293, 294, 295, 296, 297, 298, 299,
+        ];
+        assert_eq!(array.len(), 300);
+        assert_eq!(array[299], php!(299));
+
+        let array = php_array![
+            "k0" => 0, "k1" => 1, "k2" => 2, "k3" => 3, "k4" => 4, "k5" => 5, "k6" => 6, "k7" => 7, "k8" => 8, "k9" => 9,
+            "k10" => 10, "k11" => 11, "k12" => 12, "k13" => 13, "k14" => 14, "k15" => 15, "k16" => 16, "k17" => 17, "k18" => 18, "k19" => 19,
+            "k20" => 20, "k21" => 21, "k22" => 22, "k23" => 23, "k24" => 24, "k25" => 25, "k26" => 26, "k27" => 27, "k28" => 28, "k29" => 29,
+            "k30" => 30, "k31" => 31, "k32" => 32, "k33" => 33, "k34" => 34, "k35" => 35, "k36" => 36, "k37" => 37, "k38" => 38, "k39" => 39,
+            "k40" => 40, "k41" => 41, "k42" => 42, "k43" => 43, "k44" => 44, "k45" => 45, "k46" => 46, "k47" => 47, "k48" => 48, "k49" => 49,
+            "k50" => 50, "k51" => 51, "k52" => 52, "k53" => 53, "k54" => 54, "k55" => 55, "k56" => 56, "k57" => 57, "k58" => 58, "k59" => 59,
+            "k60" => 60, "k61" => 61, "k62" => 62, "k63" => 63, "k64" => 64, "k65" => 65, "k66" => 66, "k67" => 67, "k68" => 68, "k69" => 69,
+            "k70" => 70, "k71" => 71, "k72" => 72, "k73" => 73, "k74" => 74, "k75" => 75, "k76" => 76, "k77" => 77, "k78" => 78, "k79" => 79,
+            "k80" => 80, "k81" => 81, "k82" => 82, "k83" => 83, "k84" => 84, "k85" => 85, "k86" => 86, "k87" => 87, "k88" => 88, "k89" => 89,
+            "k90" => 90, "k91" => 91, "k92" => 92, "k93" => 93, "k94" => 94, "k95" => 95, "k96" => 96, "k97" => 97, "k98" => 98, "k99" => 99,
+            "k100" => 100, "k101" => 101, "k102" => 102, "k103" => 103, "k104" => 104, "k105" => 105, "k106" => 106, "k107" => 107, "k108" => 108, "k109" => 109,
+            "k110" => 110, "k111" => 111, "k112" => 112, "k113" => 113, "k114" => 114, "k115" => 115, "k116" => 116, "k117" => 117, "k118" => 118, "k119" => 119,
+            "k120" => 120, "k121" => 121, "k122" => 122, "k123" => 123, "k124" => 124, "k125" => 125, "k126" => 126, "k127" => 127, "k128" => 128, "k129" => 129,
+            "k130" => 130, "k131" => 131, "k132" => 132, "k133" => 133, "k134" => 134, "k135" => 135, "k136" => 136, "k137" => 137, "k138" => 138, "k139" => 139,
+            "k140" => 140, "k141" => 141, "k142" => 142, "k143" => 143, "k144" => 144, "k145" => 145, "k146" => 146, "k147" => 147, "k148" => 148, "k149" => 149,
+            "k150" => 150, "k151" => 151, "k152" => 152, "k153" => 153, "k154" => 154, "k155" => 155, "k156" => 156, "k157" => 157, "k158" => 158, "k159" => 159,
+            "k160" => 160, "k161" => 161, "k162" => 162, "k163" => 163, "k164" => 164, "k165" => 165, "k166" => 166, "k167" => 167, "k168" => 168, "k169" => 169,
+            "k170" => 170, "k171" => 171, "k172" => 172, "k173" => 173, "k174" => 174, "k175" => 175, "k176" => 176, "k177" => 177, "k178" => 178, "k179" => 179,
+            "k180" => 180, "k181" => 181, "k182" => 182, "k183" => 183, "k184" => 184, "k185" => 185, "k186" => 186, "k187" => 187, "k188" => 188, "k189" => 189,
+            "k190" => 190, "k191" => 191, "k192" => 192, "k193" => 193, "k194" => 194, "k195" => 195, "k196" => 196, "k197" => 197, "k198" => 198, "k199" => 199,
+            -1 => -1,
+            ["a" => 1],
+        ];
+        assert_eq!(array.len(), 202);
+        assert_eq!(array["k199"], php!(199));
+        assert_eq!(array[-1], php!(-1));
+        assert_eq!(array[0], php!(["a" => 1]));
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Converts a character to upper case, keeping it when its upper case is not a single character.
+pub(crate) fn simple_upper(char: char) -> char {
+    single(char.to_uppercase()).unwrap_or(char)
+}
+
+/// Converts a character to lower case, using the first character when its lower case is not a
+/// single character, like U+0130 which simply lower-cases to i.
+pub(crate) fn simple_lower(char: char) -> char {
+    char.to_lowercase().next().unwrap_or(char)
+}
+
+/// Folds a character to a caseless form of the same length.
+pub(crate) fn simple_fold(char: char) -> char {
+    single(char.to_uppercase().flat_map(char::to_lowercase)).unwrap_or_else(|| simple_lower(char))
+}
+
+/// Folds a character to its caseless form, which may consist of multiple characters.
+pub(crate) fn full_fold(char: char) -> String {
+    char.to_uppercase().flat_map(char::to_lowercase).collect()
+}
+
+/// Converts a character to title case, which differs from upper case for digraphs like "dž" and for
+/// characters that upper-case to multiple characters like "ß".
+pub(crate) fn title(char: char, simple: bool) -> String {
+    let title = match char {
+        '\u{1c4}' ..= '\u{1c6}' => '\u{1c5}',
+        '\u{1c7}' ..= '\u{1c9}' => '\u{1c8}',
+        '\u{1ca}' ..= '\u{1cc}' => '\u{1cb}',
+        '\u{1f1}' ..= '\u{1f3}' => '\u{1f2}',
+        _ if simple => simple_upper(char),
+        _ => {
+            let mut upper = char.to_uppercase();
+            let first = upper.next().unwrap_or(char);
+
+            return std::iter::once(first).chain(upper.flat_map(char::to_lowercase)).collect();
+        },
+    };
+
+    title.to_string()
+}
+
+/// Returns true if the character has a case, approximating the Cased derived Unicode property.
+pub(crate) fn is_cased(char: char) -> bool {
+    char.is_lowercase() || char.is_uppercase() || char.to_lowercase().ne(std::iter::once(char))
+}
+
+/// Returns true if the character is ignored when determining the case of its surroundings,
+/// approximating the Case_Ignorable derived Unicode property with apostrophes, word-internal
+/// punctuation, modifiers and combining marks.
+pub(crate) fn is_case_ignorable(char: char) -> bool {
+    matches!(char,
+        '\'' | '.' | ':' | '^' | '`' | '\u{a8}' | '\u{ad}' | '\u{af}' | '\u{b4}' | '\u{b7}' | '\u{b8}'
+        | '\u{2b0}' ..= '\u{36f}'
+        | '\u{483}' ..= '\u{489}'
+        | '\u{591}' ..= '\u{5bd}'
+        | '\u{1ab0}' ..= '\u{1aff}'
+        | '\u{1dc0}' ..= '\u{1dff}'
+        | '\u{200b}' ..= '\u{200f}'
+        | '\u{2018}' | '\u{2019}' | '\u{2024}' | '\u{2027}'
+        | '\u{20d0}' ..= '\u{20ff}'
+        | '\u{fe00}' ..= '\u{fe0f}'
+        | '\u{fe20}' ..= '\u{fe2f}'
+    )
+}
+
+fn single<I>(mut chars: I) -> Option<char>
+    where
+        I: Iterator<Item = char> {
+
+    let char = chars.next()?;
+
+    match chars.next() {
+        None => Some(char),
+        Some(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mbstring::case::{
+        full_fold,
+        is_case_ignorable,
+        is_cased,
+        simple_fold,
+        simple_lower,
+        simple_upper,
+        title,
+    };
+
+    #[test]
+    fn test() {
+        assert_eq!(simple_upper('a'), 'A');
+        assert_eq!(simple_upper('ß'), 'ß');
+        assert_eq!(simple_lower('\u{130}'), 'i');
+        assert_eq!(simple_fold('ς'), 'σ');
+        assert_eq!(simple_fold('ß'), 'ß');
+        assert_eq!(full_fold('ß'), "ss");
+        assert_eq!(title('ß', false), "Ss");
+        assert_eq!(title('ß', true), "ß");
+        assert_eq!(title('\u{1c6}', false), "\u{1c5}");
+        assert_eq!(title('é', false), "É");
+        assert!(is_cased('a'));
+        assert!(is_cased('\u{1c5}'));
+        assert!(!is_cased('1'));
+        assert!(is_case_ignorable('\''));
+        assert!(is_case_ignorable('\u{301}'));
+        assert!(!is_case_ignorable(' '));
+    }
+}
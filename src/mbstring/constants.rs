@@ -0,0 +1,41 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Performs a full upper-case folding.
+pub const MB_CASE_UPPER: u32 = 0;
+
+/// Performs a full lower-case folding.
+pub const MB_CASE_LOWER: u32 = 1;
+
+/// Performs a full title-case conversion based on the Cased and CaseIgnorable derived Unicode
+/// properties.
+pub const MB_CASE_TITLE: u32 = 2;
+
+/// Performs a full case fold conversion which removes case distinctions present in the string.
+pub const MB_CASE_FOLD: u32 = 3;
+
+/// Performs a simple upper-case folding.
+pub const MB_CASE_UPPER_SIMPLE: u32 = 4;
+
+/// Performs a simple lower-case folding.
+pub const MB_CASE_LOWER_SIMPLE: u32 = 5;
+
+/// Performs a simple title-case conversion.
+pub const MB_CASE_TITLE_SIMPLE: u32 = 6;
+
+/// Performs a simple case fold conversion.
+pub const MB_CASE_FOLD_SIMPLE: u32 = 7;
@@ -0,0 +1,175 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.mbstring.php
+
+use crate::error::PhpError;
+use crate::mbstring::case::{
+    full_fold,
+    is_case_ignorable,
+    is_cased,
+    simple_fold,
+    simple_lower,
+    simple_upper,
+    title,
+};
+use crate::mbstring::{
+    MB_CASE_FOLD,
+    MB_CASE_FOLD_SIMPLE,
+    MB_CASE_LOWER,
+    MB_CASE_LOWER_SIMPLE,
+    MB_CASE_TITLE,
+    MB_CASE_TITLE_SIMPLE,
+    MB_CASE_UPPER,
+    MB_CASE_UPPER_SIMPLE,
+};
+
+/// Perform case folding on a string.
+///
+/// # Description
+///
+/// Performs case folding on a string, converted in the way specified by mode.
+///
+/// # Parameters
+///
+/// **mode**
+///
+/// The mode of the conversion. It can be one of MB_CASE_UPPER, MB_CASE_LOWER, MB_CASE_TITLE,
+/// MB_CASE_FOLD, MB_CASE_UPPER_SIMPLE, MB_CASE_LOWER_SIMPLE, MB_CASE_TITLE_SIMPLE or
+/// MB_CASE_FOLD_SIMPLE.
+///
+/// The full modes may change the length of the string, like "ß" which upper-cases to "SS". The
+/// simple modes map every character to a single character. The full lower and title modes
+/// lower-case a final Greek capital sigma to "ς".
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if mode is not a valid mode.
+///
+/// # Examples
+///
+/// Example #1 mb_convert_case() example
+///
+/// ```
+/// use phpify::mbstring::{mb_convert_case, MB_CASE_LOWER, MB_CASE_TITLE, MB_CASE_UPPER};
+///
+/// let str = "mary had a Little lamb and she loved it so";
+///
+/// assert_eq!(mb_convert_case(str, MB_CASE_UPPER).unwrap(), "MARY HAD A LITTLE LAMB AND SHE LOVED IT SO");
+/// assert_eq!(mb_convert_case(str, MB_CASE_TITLE).unwrap(), "Mary Had A Little Lamb And She Loved It So");
+/// ```
+///
+/// Example #2 mb_convert_case() example with non-Latin UTF-8 text
+///
+/// ```
+/// use phpify::mbstring::{mb_convert_case, MB_CASE_LOWER, MB_CASE_TITLE, MB_CASE_UPPER};
+///
+/// let str = "Τάχιστη αλώπηξ βαφής ψημένη γη, δρασκελίζει υπέρ νωθρού κυνός";
+///
+/// assert_eq!(mb_convert_case(str, MB_CASE_UPPER).unwrap(), "ΤΆΧΙΣΤΗ ΑΛΏΠΗΞ ΒΑΦΉΣ ΨΗΜΈΝΗ ΓΗ, ΔΡΑΣΚΕΛΊΖΕΙ ΥΠΈΡ ΝΩΘΡΟΎ ΚΥΝΌΣ");
+/// assert_eq!(mb_convert_case(str, MB_CASE_TITLE).unwrap(), "Τάχιστη Αλώπηξ Βαφής Ψημένη Γη, Δρασκελίζει Υπέρ Νωθρού Κυνός");
+/// assert_eq!(mb_convert_case("ΚΥΝΌΣ", MB_CASE_LOWER).unwrap(), "κυνός");
+/// ```
+pub fn mb_convert_case<S>(string: S, mode: u32) -> Result<String, PhpError>
+    where
+        S: AsRef<str> {
+
+    let string = string.as_ref();
+
+    let converted = match mode {
+        MB_CASE_UPPER => string.to_uppercase(),
+        MB_CASE_LOWER => string.to_lowercase(),
+        MB_CASE_TITLE => convert_title(string, false),
+        MB_CASE_FOLD => string.chars().map(full_fold).collect(),
+        MB_CASE_UPPER_SIMPLE => string.chars().map(simple_upper).collect(),
+        MB_CASE_LOWER_SIMPLE => string.chars().map(simple_lower).collect(),
+        MB_CASE_TITLE_SIMPLE => convert_title(string, true),
+        MB_CASE_FOLD_SIMPLE => string.chars().map(simple_fold).collect(),
+        _ => return Err(PhpError::ValueError("mb_convert_case(): Argument #2 ($mode) must be one of the MB_CASE_* constants".to_string())),
+    };
+
+    Ok(converted)
+}
+
+/// Title-cases every cased character that does not follow a cased character, ignoring case-ignorable
+/// characters in between, and lower-cases the others.
+fn convert_title(string: &str, simple: bool) -> String {
+    let chars: Vec<char> = string.chars().collect();
+    let mut converted = String::with_capacity(string.len());
+    let mut title_mode = true;
+
+    for (index, &char) in chars.iter().enumerate() {
+        if title_mode {
+            converted.push_str(&title(char, simple));
+        } else if simple {
+            converted.push(simple_lower(char));
+        } else if char == 'Σ' && is_final_sigma(&chars, index) {
+            converted.push('ς');
+        } else {
+            converted.extend(char.to_lowercase());
+        }
+
+        if !is_case_ignorable(char) {
+            title_mode = !is_cased(char);
+        }
+    }
+
+    converted
+}
+
+/// Returns true if the sigma at index ends a word, i.e. it follows a cased character and is not
+/// followed by one.
+fn is_final_sigma(chars: &[char], index: usize) -> bool {
+    let mut before = chars[..index].iter().rev().filter(|&&char| !is_case_ignorable(char));
+    let mut after = chars[index + 1..].iter().filter(|&&char| !is_case_ignorable(char));
+
+    before.next().is_some_and(|&char| is_cased(char)) && !after.next().is_some_and(|&char| is_cased(char))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::mbstring::{
+        mb_convert_case,
+        MB_CASE_FOLD,
+        MB_CASE_FOLD_SIMPLE,
+        MB_CASE_LOWER,
+        MB_CASE_LOWER_SIMPLE,
+        MB_CASE_TITLE,
+        MB_CASE_TITLE_SIMPLE,
+        MB_CASE_UPPER,
+        MB_CASE_UPPER_SIMPLE,
+    };
+
+    #[test]
+    fn test() {
+        assert_eq!(mb_convert_case("straße", MB_CASE_UPPER), Ok("STRASSE".to_string()));
+        assert_eq!(mb_convert_case("straße", MB_CASE_UPPER_SIMPLE), Ok("STRAßE".to_string()));
+        assert_eq!(mb_convert_case("STRASSE", MB_CASE_LOWER), Ok("strasse".to_string()));
+        assert_eq!(mb_convert_case("\u{130}", MB_CASE_LOWER), Ok("i\u{307}".to_string()));
+        assert_eq!(mb_convert_case("\u{130}", MB_CASE_LOWER_SIMPLE), Ok("i".to_string()));
+        assert_eq!(mb_convert_case("Straße", MB_CASE_FOLD), Ok("strasse".to_string()));
+        assert_eq!(mb_convert_case("Straße", MB_CASE_FOLD_SIMPLE), Ok("straße".to_string()));
+        assert_eq!(mb_convert_case("ßa ǆb", MB_CASE_TITLE), Ok("Ssa ǅb".to_string()));
+        assert_eq!(mb_convert_case("ßa ǆb", MB_CASE_TITLE_SIMPLE), Ok("ßa ǅb".to_string()));
+        assert_eq!(mb_convert_case("it's o'NEIL", MB_CASE_TITLE), Ok("It's O'neil".to_string()));
+        assert_eq!(mb_convert_case("hello_world 1st", MB_CASE_TITLE), Ok("Hello_World 1St".to_string()));
+        assert_eq!(mb_convert_case("ΟΔΟΣ ΣΑ", MB_CASE_TITLE), Ok("Οδος Σα".to_string()));
+        assert_eq!(mb_convert_case("ΟΔΟΣ", MB_CASE_TITLE_SIMPLE), Ok("Οδοσ".to_string()));
+        assert_eq!(mb_convert_case("abc", 8), Err(PhpError::ValueError("mb_convert_case(): Argument #2 ($mode) must be one of the MB_CASE_* constants".to_string())));
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.mbstring.php
+
+use crate::error::PhpError;
+use crate::string::{STR_PAD_BOTH, STR_PAD_LEFT, STR_PAD_RIGHT};
+
+/// Pad a multibyte string to a certain length with another multibyte string.
+///
+/// # Description
+///
+/// This function returns the string padded on the left, the right, or both sides to the specified
+/// padding length. Lengths are counted in characters. If length is less than or equal to the
+/// length of the input string, no padding takes place.
+///
+/// # Parameters
+///
+/// **pad_string**
+///
+/// The pad_string may be truncated if the required number of padding characters can't be evenly
+/// divided by the pad_string's length.
+///
+/// **pad_type**
+///
+/// Can be STR_PAD_RIGHT, STR_PAD_LEFT, or STR_PAD_BOTH. If pad_type is STR_PAD_BOTH, the
+/// padding is divided between both sides, with the extra character going to the right.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if pad_string is empty or pad_type is not a valid pad type.
+///
+/// # Examples
+///
+/// Example #1 mb_str_pad() example
+///
+/// ```
+/// use phpify::mbstring::mb_str_pad;
+/// use phpify::string::{STR_PAD_BOTH, STR_PAD_LEFT, STR_PAD_RIGHT};
+///
+/// assert_eq!(mb_str_pad("▶▶", 6, "❤❓❇", STR_PAD_RIGHT).unwrap(), "▶▶❤❓❇❤");
+/// assert_eq!(mb_str_pad("▶▶", 6, "❤❓❇", STR_PAD_LEFT).unwrap(), "❤❓❇❤▶▶");
+/// assert_eq!(mb_str_pad("▶▶", 6, "❤❓❇", STR_PAD_BOTH).unwrap(), "❤❓▶▶❤❓");
+/// ```
+pub fn mb_str_pad<S, P>(string: S, length: usize, pad_string: P, pad_type: u32) -> Result<String, PhpError>
+    where
+        S: AsRef<str>,
+        P: AsRef<str> {
+
+    let string = string.as_ref();
+    let pad: Vec<char> = pad_string.as_ref().chars().collect();
+
+    if pad.is_empty() {
+        return Err(PhpError::ValueError("mb_str_pad(): Argument #3 ($pad_string) must be a non-empty string".to_string()));
+    }
+
+    let pad_length = length.saturating_sub(string.chars().count());
+
+    let left_length = match pad_type {
+        STR_PAD_LEFT => pad_length,
+        STR_PAD_RIGHT => 0,
+        STR_PAD_BOTH => pad_length / 2,
+        _ => {
+            return Err(PhpError::ValueError("mb_str_pad(): Argument #4 ($pad_type) must be STR_PAD_LEFT, STR_PAD_RIGHT, or STR_PAD_BOTH".to_string()));
+        },
+    };
+
+    let mut padded = String::with_capacity(string.len() + pad_length * 4);
+    padded.extend(pad.iter().cycle().take(left_length));
+    padded.push_str(string);
+    padded.extend(pad.iter().cycle().take(pad_length - left_length));
+
+    Ok(padded)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::mbstring::mb_str_pad;
+    use crate::string::{STR_PAD_BOTH, STR_PAD_LEFT, STR_PAD_RIGHT};
+
+    #[test]
+    fn test() {
+        assert_eq!(mb_str_pad("Alien", 10, " ", STR_PAD_RIGHT), Ok("Alien     ".to_string()));
+        assert_eq!(mb_str_pad("Alien", 10, "-=", STR_PAD_LEFT), Ok("-=-=-Alien".to_string()));
+        assert_eq!(mb_str_pad("Alien", 10, "_", STR_PAD_BOTH), Ok("__Alien___".to_string()));
+        assert_eq!(mb_str_pad("Alien", 6, "___", STR_PAD_RIGHT), Ok("Alien_".to_string()));
+        assert_eq!(mb_str_pad("Alien", 3, "*", STR_PAD_RIGHT), Ok("Alien".to_string()));
+        assert_eq!(mb_str_pad("日本", 4, "語", STR_PAD_LEFT), Ok("語語日本".to_string()));
+        assert_eq!(mb_str_pad("Alien", 10, "", STR_PAD_RIGHT), Err(PhpError::ValueError("mb_str_pad(): Argument #3 ($pad_string) must be a non-empty string".to_string())));
+        assert!(mb_str_pad("Alien", 10, " ", 3).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.mbstring.php
+
+use crate::error::PhpError;
+
+/// Given a multibyte string, return a vec of its characters.
+///
+/// # Description
+///
+/// This function will return a vec of strings, it is a version of str_split() with support for
+/// encodings of variable character size. The string is split into chunks of length characters,
+/// the last chunk may be shorter.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if length is less than 1.
+///
+/// # Examples
+///
+/// Example #1 mb_str_split() example
+///
+/// ```
+/// use phpify::mbstring::mb_str_split;
+///
+/// assert_eq!(mb_str_split("日本語テキスト", 1).unwrap(), ["日", "本", "語", "テ", "キ", "ス", "ト"]);
+/// assert_eq!(mb_str_split("日本語テキスト", 3).unwrap(), ["日本語", "テキス", "ト"]);
+/// ```
+pub fn mb_str_split<S>(string: S, length: usize) -> Result<Vec<String>, PhpError>
+    where
+        S: AsRef<str> {
+
+    if length < 1 {
+        return Err(PhpError::ValueError("mb_str_split(): Argument #2 ($length) must be greater than 0".to_string()));
+    }
+
+    let chars: Vec<char> = string.as_ref().chars().collect();
+
+    Ok(chars.chunks(length).map(|chunk| chunk.iter().collect()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::mbstring::mb_str_split;
+
+    #[test]
+    fn test() {
+        assert_eq!(mb_str_split("abc", 2), Ok(vec!["ab".to_string(), "c".to_string()]));
+        assert_eq!(mb_str_split("abc", 5), Ok(vec!["abc".to_string()]));
+        assert_eq!(mb_str_split("", 1), Ok(vec![]));
+        assert_eq!(mb_str_split("abc", 0), Err(PhpError::ValueError("mb_str_split(): Argument #2 ($length) must be greater than 0".to_string())));
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.mbstring.php
+
+use crate::error::PhpError;
+use crate::mbstring::mb_strwidth;
+use crate::mbstring::width::char_width;
+
+/// Get truncated string with specified width.
+///
+/// # Description
+///
+/// Truncates string to specified width, where halfwidth characters count as 1, and fullwidth
+/// characters count as 2. See mb_strwidth() for details regarding East Asian character widths.
+///
+/// # Parameters
+///
+/// **start**
+///
+/// Start position offset. Number of characters from the beginning of string (first character is
+/// 0), or if start is negative, number of characters from the end of the string.
+///
+/// **width**
+///
+/// The width of the desired trim, including the width of trim_marker.
+///
+/// **trim_marker**
+///
+/// A string that is added to the end of string when string is truncated.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if start is out of range.
+///
+/// # Examples
+///
+/// Example #1 mb_strimwidth() example
+///
+/// ```
+/// use phpify::mbstring::mb_strimwidth;
+///
+/// assert_eq!(mb_strimwidth("Hello World", 0, 10, "...").unwrap(), "Hello W...");
+/// assert_eq!(mb_strimwidth("日本語のテキスト", 0, 9, "…").unwrap(), "日本語の…");
+/// ```
+pub fn mb_strimwidth<S, T>(string: S, start: isize, width: usize, trim_marker: T) -> Result<String, PhpError>
+    where
+        S: AsRef<str>,
+        T: AsRef<str> {
+
+    let string = string.as_ref();
+    let trim_marker = trim_marker.as_ref();
+    let string_length = string.chars().count() as isize;
+
+    let start = if start < 0 { start + string_length } else { start };
+
+    if start < 0 || start > string_length {
+        return Err(PhpError::ValueError("mb_strimwidth(): Argument #2 ($start) is out of range".to_string()));
+    }
+
+    let rest: String = string.chars().skip(start as usize).collect();
+
+    if mb_strwidth(&rest) <= width {
+        return Ok(rest);
+    }
+
+    let available = width.saturating_sub(mb_strwidth(trim_marker));
+    let mut used = 0;
+
+    let mut trimmed: String = rest
+        .chars()
+        .take_while(|&char| {
+            used += char_width(char);
+            used <= available
+        })
+        .collect();
+
+    trimmed.push_str(trim_marker);
+
+    Ok(trimmed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::mbstring::mb_strimwidth;
+
+    #[test]
+    fn test() {
+        assert_eq!(mb_strimwidth("Hello World", 0, 11, "..."), Ok("Hello World".to_string()));
+        assert_eq!(mb_strimwidth("Hello World", 6, 5, "..."), Ok("World".to_string()));
+        assert_eq!(mb_strimwidth("Hello World", -5, 4, "..."), Ok("W...".to_string()));
+        assert_eq!(mb_strimwidth("Hello World", 0, 2, "..."), Ok("...".to_string()));
+        assert_eq!(mb_strimwidth("日本語", 0, 5, ""), Ok("日本".to_string()));
+        assert_eq!(mb_strimwidth("Hello", 6, 5, ""), Err(PhpError::ValueError("mb_strimwidth(): Argument #2 ($start) is out of range".to_string())));
+        assert!(mb_strimwidth("Hello", -6, 5, "").is_err());
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.mbstring.php
+
+use crate::error::PhpError;
+use crate::mbstring::case::simple_fold;
+use crate::mbstring::mb_strpos::find;
+use crate::string::offset_in;
+
+/// Finds position of first occurrence of a string within another, case insensitive.
+///
+/// # Description
+///
+/// Returns the position of the first occurrence of needle in the haystack string. Unlike
+/// mb_strpos(), mb_stripos() is case-insensitive. Both strings are case folded character by
+/// character, so positions are not affected by the folding.
+///
+/// # Parameters
+///
+/// **offset**
+///
+/// The position in haystack to start searching. A negative offset counts from the end of the
+/// string.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if offset is not contained in haystack.
+///
+/// # Examples
+///
+/// Example #1 mb_stripos() example
+///
+/// ```
+/// use phpify::mbstring::mb_stripos;
+///
+/// assert_eq!(mb_stripos("ÉCOLE élève", "ÉLÈVE", 0), Ok(Some(6)));
+/// ```
+pub fn mb_stripos<H, N>(haystack: H, needle: N, offset: isize) -> Result<Option<usize>, PhpError>
+    where
+        H: AsRef<str>,
+        N: AsRef<str> {
+
+    let haystack: Vec<char> = haystack.as_ref().chars().map(simple_fold).collect();
+    let needle: Vec<char> = needle.as_ref().chars().map(simple_fold).collect();
+    let offset = offset_in("mb_stripos", haystack.len(), offset)?;
+
+    Ok(find(&haystack[offset..], &needle).map(|position| position + offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mbstring::mb_stripos;
+
+    #[test]
+    fn test() {
+        assert_eq!(mb_stripos("HELLO WORLD", "world", 0), Ok(Some(6)));
+        assert!(mb_stripos("HELLO WORLD", "world", 12).is_err());
+        assert_eq!(mb_stripos("ΣΊΣΥΦΟΣ", "σος", 0), Ok(None));
+        assert_eq!(mb_stripos("ΣΊΣΥΦΟΣ", "φος", 0), Ok(Some(4)));
+        assert_eq!(mb_stripos("straße", "SS", 0), Ok(None));
+        assert_eq!(mb_stripos("\u{130}stanbul", "is", 0), Ok(Some(0)));
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.mbstring.php
+
+/// Get string length.
+///
+/// # Description
+///
+/// Gets the length of a string in characters. A multi-byte character is counted as 1.
+///
+/// Strings are UTF-8, so the characters are Unicode code points.
+///
+/// # Examples
+///
+/// Example #1 mb_strlen() example
+///
+/// ```
+/// use phpify::mbstring::mb_strlen;
+///
+/// assert_eq!(mb_strlen("héllo"), 5);
+/// assert_eq!(mb_strlen("日本語"), 3);
+/// ```
+pub fn mb_strlen<S>(string: S) -> usize
+    where
+        S: AsRef<str> {
+
+    string.as_ref().chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mbstring::mb_strlen;
+
+    #[test]
+    fn test() {
+        assert_eq!(mb_strlen(""), 0);
+        assert_eq!(mb_strlen("abc"), 3);
+        assert_eq!(mb_strlen("\u{1f600}e\u{301}"), 3);
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.mbstring.php
+
+use crate::error::PhpError;
+use crate::string::offset_in;
+
+/// Find position of first occurrence of string in a string.
+///
+/// # Description
+///
+/// Finds the position of the first occurrence of needle in the haystack string. Performs a
+/// multi-byte safe strpos() operation based on number of characters.
+///
+/// # Parameters
+///
+/// **offset**
+///
+/// The search offset. If it is not specified, 0 is used. A negative offset counts from the end of
+/// the string.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if offset is not contained in haystack.
+///
+/// # Examples
+///
+/// Example #1 mb_strpos() example
+///
+/// ```
+/// use phpify::mbstring::mb_strpos;
+///
+/// assert_eq!(mb_strpos("héllo wörld", "w", 0), Ok(Some(6)));
+/// assert_eq!(mb_strpos("日本語テキスト", "テ", -4), Ok(Some(3)));
+/// ```
+pub fn mb_strpos<H, N>(haystack: H, needle: N, offset: isize) -> Result<Option<usize>, PhpError>
+    where
+        H: AsRef<str>,
+        N: AsRef<str> {
+
+    let haystack: Vec<char> = haystack.as_ref().chars().collect();
+    let needle: Vec<char> = needle.as_ref().chars().collect();
+    let offset = offset_in("mb_strpos", haystack.len(), offset)?;
+
+    Ok(find(&haystack[offset..], &needle).map(|position| position + offset))
+}
+
+/// Returns the position of the first occurrence of needle in haystack.
+pub(crate) fn find(haystack: &[char], needle: &[char]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::mbstring::mb_strpos;
+
+    #[test]
+    fn test() {
+        assert_eq!(mb_strpos("Hello World", "World", 0), Ok(Some(6)));
+        assert_eq!(mb_strpos("Hello World", "World", 7), Ok(None));
+        assert!(mb_strpos("Hello World", "World", 12).is_err());
+        assert_eq!(mb_strpos("Hello World", "World", -12), Err(PhpError::ValueError("mb_strpos(): Argument #3 ($offset) must be contained in argument #1 ($haystack)".to_string())));
+        assert_eq!(mb_strpos("Hello World", "", 11), Ok(Some(11)));
+        assert_eq!(mb_strpos("日本語", "語", 0), Ok(Some(2)));
+        assert_eq!(mb_strpos("日本語", "本", -1), Ok(None));
+    }
+}
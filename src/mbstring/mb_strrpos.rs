@@ -0,0 +1,99 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.mbstring.php
+
+use crate::error::PhpError;
+use crate::string::offset_in;
+
+/// Find position of last occurrence of a string in a string.
+///
+/// # Description
+///
+/// Performs a multibyte safe strrpos() operation based on the number of characters. needle position
+/// is counted from the beginning of haystack. First character's position is 0. Second character
+/// position is 1.
+///
+/// # Parameters
+///
+/// **offset**
+///
+/// If zero or positive, the search is performed left to right skipping the first offset
+/// characters of haystack.
+///
+/// If negative, the search is performed right to left skipping the last offset characters of
+/// haystack, so the needle has to start at or before that position.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if offset is not contained in haystack.
+///
+/// # Examples
+///
+/// Example #1 mb_strrpos() example
+///
+/// ```
+/// use phpify::mbstring::mb_strrpos;
+///
+/// assert_eq!(mb_strrpos("日本語 日本語", "日本", 0), Ok(Some(4)));
+/// assert_eq!(mb_strrpos("日本語 日本語", "日本", -4), Ok(Some(0)));
+/// ```
+pub fn mb_strrpos<H, N>(haystack: H, needle: N, offset: isize) -> Result<Option<usize>, PhpError>
+    where
+        H: AsRef<str>,
+        N: AsRef<str> {
+
+    let haystack: Vec<char> = haystack.as_ref().chars().collect();
+    let needle: Vec<char> = needle.as_ref().chars().collect();
+    let position = offset_in("mb_strrpos", haystack.len(), offset)?;
+
+    let (start, end) = if offset >= 0 {
+        (position, haystack.len())
+    } else if ((-offset) as usize) < needle.len() {
+        (0, haystack.len())
+    } else {
+        (0, position + needle.len())
+    };
+
+    let haystack = &haystack[start..end];
+
+    if needle.is_empty() {
+        return Ok(Some(end));
+    }
+
+    Ok(haystack
+        .windows(needle.len())
+        .rposition(|window| window == &needle[..])
+        .map(|position| position + start))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::mbstring::mb_strrpos;
+
+    #[test]
+    fn test() {
+        assert_eq!(mb_strrpos("0123456789a123456789b", "7", 0), Ok(Some(17)));
+        assert_eq!(mb_strrpos("0123456789a123456789b", "7", 18), Ok(None));
+        assert_eq!(mb_strrpos("0123456789a123456789b", "7", -4), Ok(Some(17)));
+        assert_eq!(mb_strrpos("0123456789a123456789b", "7", -5), Ok(Some(7)));
+        assert_eq!(mb_strrpos("0123456789a123456789b", "7", 22), Err(PhpError::ValueError("mb_strrpos(): Argument #3 ($offset) must be contained in argument #1 ($haystack)".to_string())));
+        assert_eq!(mb_strrpos("abc", "", 0), Ok(Some(3)));
+        assert_eq!(mb_strrpos("éaé", "é", -1), Ok(Some(2)));
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.mbstring.php
+
+use crate::mbstring::width::char_width;
+
+/// Return width of string.
+///
+/// # Description
+///
+/// Returns the width of string, where halfwidth characters count as 1, and fullwidth characters
+/// count as 2.
+///
+/// The fullwidth characters are those with the East Asian Width property Wide or Fullwidth, such as
+/// CJK ideographs, kana, hangul, fullwidth forms and most emoji.
+///
+/// # Examples
+///
+/// Example #1 mb_strwidth() example
+///
+/// ```
+/// use phpify::mbstring::mb_strwidth;
+///
+/// assert_eq!(mb_strwidth("dog"), 3);
+/// assert_eq!(mb_strwidth("日本語"), 6);
+/// assert_eq!(mb_strwidth("ｱｲｳ"), 3);
+/// ```
+pub fn mb_strwidth<S>(string: S) -> usize
+    where
+        S: AsRef<str> {
+
+    string.as_ref().chars().map(char_width).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mbstring::mb_strwidth;
+
+    #[test]
+    fn test() {
+        assert_eq!(mb_strwidth(""), 0);
+        assert_eq!(mb_strwidth("héllo"), 5);
+        assert_eq!(mb_strwidth("Ｈｅｌｌｏ"), 10);
+        assert_eq!(mb_strwidth("\u{1f600}!"), 3);
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.mbstring.php
+
+/// Get part of string.
+///
+/// # Description
+///
+/// Performs a multi-byte safe substr() operation based on number of characters. Position is counted
+/// from the beginning of string. First character's position is 0. Second character position is 1,
+/// and so on.
+///
+/// # Parameters
+///
+/// **start**
+///
+/// If start is non-negative, the returned string will start at the start'th position in string,
+/// counting from zero.
+///
+/// If start is negative, the returned string will start at the start'th character from the end of
+/// string.
+///
+/// **length**
+///
+/// Maximum number of characters to use from string. If omitted (isize::MAX is passed), all
+/// characters to the end of the string are extracted. If length is negative, that many characters
+/// will be omitted from the end of string.
+///
+/// # Examples
+///
+/// Example #1 mb_substr() example
+///
+/// ```
+/// use phpify::mbstring::mb_substr;
+///
+/// assert_eq!(mb_substr("héllo", 1, 3), "éll");
+/// assert_eq!(mb_substr("日本語テキスト", -4, isize::MAX), "テキスト");
+/// assert_eq!(mb_substr("日本語テキスト", 1, -3), "本語テ");
+/// ```
+pub fn mb_substr<S>(string: S, start: isize, length: isize) -> String
+    where
+        S: AsRef<str> {
+
+    let string = string.as_ref();
+    let string_length = string.chars().count() as isize;
+
+    let start = if start < 0 { (string_length + start).max(0) } else { start };
+
+    if start > string_length {
+        return String::new();
+    }
+
+    let length = if length < 0 { (string_length - start + length).max(0) } else { length };
+
+    string.chars().skip(start as usize).take(length as usize).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mbstring::mb_substr;
+
+    #[test]
+    fn test() {
+        assert_eq!(mb_substr("Hello World", 0, 5), "Hello");
+        assert_eq!(mb_substr("Hello World", 11, 1), "");
+        assert_eq!(mb_substr("Hello World", 20, 1), "");
+        assert_eq!(mb_substr("Hello World", -20, 5), "Hello");
+        assert_eq!(mb_substr("Hello World", 7, -5), "");
+        assert_eq!(mb_substr("Hello World", 0, 0), "");
+        assert_eq!(mb_substr("日本語", 2, 5), "語");
+        assert_eq!(mb_substr("日本語", -2, -1), "本");
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+mod case;
+mod constants;
+mod mb_convert_case;
+mod mb_str_pad;
+mod mb_str_split;
+mod mb_strimwidth;
+mod mb_stripos;
+mod mb_strlen;
+mod mb_strpos;
+mod mb_strrpos;
+mod mb_strwidth;
+mod mb_substr;
+mod width;
+
+pub use constants::{
+    MB_CASE_FOLD,
+    MB_CASE_FOLD_SIMPLE,
+    MB_CASE_LOWER,
+    MB_CASE_LOWER_SIMPLE,
+    MB_CASE_TITLE,
+    MB_CASE_TITLE_SIMPLE,
+    MB_CASE_UPPER,
+    MB_CASE_UPPER_SIMPLE,
+};
+pub use mb_convert_case::mb_convert_case;
+pub use mb_str_pad::mb_str_pad;
+pub use mb_str_split::mb_str_split;
+pub use mb_strimwidth::mb_strimwidth;
+pub use mb_stripos::mb_stripos;
+pub use mb_strlen::mb_strlen;
+pub use mb_strpos::mb_strpos;
+pub use mb_strrpos::mb_strrpos;
+pub use mb_strwidth::mb_strwidth;
+pub use mb_substr::mb_substr;
@@ -0,0 +1,174 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// A source of random numbers the random functions can draw from.
+///
+/// # Description
+///
+/// Implemented by Mt19937, PcgOneseq128XslRr64, Xoshiro256StarStar and Secure. Functions taking an engine, like array_rand_with(), use it instead of the
+/// Mersenne Twister shared with mt_rand(), so a seeded engine gives reproducible results.
+///
+/// Implementors only need generate() and size(); range() maps the numbers into a range the way
+/// PHP's php_random_range() does.
+pub trait Engine {
+    /// Returns the next random number. Only the lowest size() bytes are random.
+    fn generate(&mut self) -> u64;
+
+    /// Returns the number of random bytes generate() returns, between 1 and 8.
+    fn size(&self) -> usize;
+
+    /// Returns a uniformly distributed number between min and max, inclusive. Numbers that would
+    /// make the distribution uneven are rejected.
+    fn range(&mut self, min: i64, max: i64) -> i64 {
+        let umax = (max as u64).wrapping_sub(min as u64);
+
+        let result = if umax > u64::from(u32::MAX) {
+            range64(self, umax)
+        } else {
+            u64::from(range32(self, umax as u32))
+        };
+
+        result.wrapping_add(min as u64) as i64
+    }
+
+    /// Returns a number between min and max, inclusive, scaled into the range the way PHP did
+    /// before 7.1.0, if the engine is in such a legacy mode. Returns None otherwise.
+    ///
+    /// Only Mt19937 in mode MT_RAND_PHP has a legacy mode. Randomizer::get_int() uses it like
+    /// getInt() does.
+    fn legacy_range(&mut self, _min: i64, _max: i64) -> Option<i64> {
+        None
+    }
+}
+
+impl<E> Engine for Box<E>
+    where
+        E: Engine + ?Sized {
+
+    fn generate(&mut self) -> u64 {
+        (**self).generate()
+    }
+
+    fn size(&self) -> usize {
+        (**self).size()
+    }
+
+    fn range(&mut self, min: i64, max: i64) -> i64 {
+        (**self).range(min, max)
+    }
+
+    fn legacy_range(&mut self, min: i64, max: i64) -> Option<i64> {
+        (**self).legacy_range(min, max)
+    }
+}
+
+/// Combines numbers from the engine until it has at least bytes random bytes.
+fn combine<E>(engine: &mut E, bytes: usize) -> u64
+    where
+        E: Engine + ?Sized {
+
+    let mut result = 0u64;
+    let mut total = 0;
+
+    while total < bytes {
+        let number = engine.generate() & (u64::MAX >> (64 - engine.size() * 8));
+
+        result |= number.checked_shl(total as u32 * 8).unwrap_or(0);
+        total += engine.size();
+    }
+
+    result
+}
+
+fn range32<E>(engine: &mut E, umax: u32) -> u32
+    where
+        E: Engine + ?Sized {
+
+    let result = combine(engine, 4) as u32;
+
+    if umax == u32::MAX {
+        return result;
+    }
+
+    let umax = umax + 1;
+
+    if umax & (umax - 1) == 0 {
+        return result & (umax - 1);
+    }
+
+    let limit = u32::MAX - (u32::MAX % umax) - 1;
+    let mut result = result;
+
+    while result > limit {
+        result = combine(engine, 4) as u32;
+    }
+
+    result % umax
+}
+
+fn range64<E>(engine: &mut E, umax: u64) -> u64
+    where
+        E: Engine + ?Sized {
+
+    let result = combine(engine, 8);
+
+    if umax == u64::MAX {
+        return result;
+    }
+
+    let umax = umax + 1;
+
+    if umax & (umax - 1) == 0 {
+        return result & (umax - 1);
+    }
+
+    let limit = u64::MAX - (u64::MAX % umax) - 1;
+    let mut result = result;
+
+    while result > limit {
+        result = combine(engine, 8);
+    }
+
+    result % umax
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::Engine;
+
+    struct Counter(u64);
+
+    impl Engine for Counter {
+        fn generate(&mut self) -> u64 {
+            self.0 += 1;
+            self.0
+        }
+
+        fn size(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn test() {
+        let mut engine = Counter(0);
+        assert_eq!(engine.range(0, 255), 1);
+        assert_eq!(engine.range(0, i64::from(u32::MAX)), 0x08070605);
+        assert_eq!(engine.range(10, (1 << 40) + 10), 56035572485);
+        assert_eq!(engine.range(-5, -5), -5);
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cell::RefCell;
+
+use rand::Rng;
+
+use crate::random::{Mt19937, MT_RAND_MT19937};
+
+thread_local! {
+    static GENERATOR: RefCell<Option<Mt19937>> = const { RefCell::new(None) };
+}
+
+/// Calls f with the Mersenne Twister shared by mt_rand(), rand(), shuffle(), str_shuffle() and
+/// array_rand(). Like PHP, the generator is seeded with a random seed when it is used before
+/// mt_srand() is called. Every thread has its own generator.
+pub(crate) fn with_generator<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut Mt19937) -> R {
+
+    GENERATOR.with(|generator| {
+        let mut generator = generator.borrow_mut();
+        let generator = generator.get_or_insert_with(|| Mt19937::new(rand::thread_rng().gen(), MT_RAND_MT19937));
+
+        f(generator)
+    })
+}
+
+/// Replaces the shared Mersenne Twister.
+pub(crate) fn seed_generator(seed: u32, mode: u32) {
+    GENERATOR.with(|generator| *generator.borrow_mut() = Some(Mt19937::new(seed, mode)));
+}
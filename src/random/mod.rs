@@ -0,0 +1,51 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+mod constants;
+mod engine;
+mod generator;
+mod getrandmax;
+mod mt19937;
+mod mt_getrandmax;
+mod mt_rand;
+mod mt_srand;
+mod pcg_oneseq128_xsl_rr64;
+mod rand;
+mod random_bytes;
+mod random_int;
+mod randomizer;
+mod secure;
+mod srand;
+mod xoshiro256_star_star;
+
+pub(crate) use generator::with_generator;
+
+pub use constants::{MT_RAND_MT19937, MT_RAND_PHP};
+pub use engine::Engine;
+pub use getrandmax::getrandmax;
+pub use mt19937::Mt19937;
+pub use mt_getrandmax::mt_getrandmax;
+pub use mt_rand::{mt_rand, mt_rand_range};
+pub use mt_srand::mt_srand;
+pub use pcg_oneseq128_xsl_rr64::PcgOneseq128XslRr64;
+pub use rand::{rand, rand_range};
+pub use random_bytes::random_bytes;
+pub use random_int::random_int;
+pub use randomizer::Randomizer;
+pub use secure::Secure;
+pub use srand::srand;
+pub use xoshiro256_star_star::Xoshiro256StarStar;
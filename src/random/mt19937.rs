@@ -0,0 +1,245 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::random::{Engine, MT_RAND_PHP};
+
+/// The length of the state vector.
+const N: usize = 624;
+
+/// The period parameter.
+const M: usize = 397;
+
+/// The Mersenne Twister pseudo random number generator, the way PHP implements it.
+///
+/// # Description
+///
+/// The generator produces the same sequence of 32 bit numbers as PHP for the same seed and mode.
+/// In mode MT_RAND_PHP it reproduces the incorrect implementation PHP used before 7.1.0.
+///
+/// # Examples
+///
+/// Example #1 Mt19937 example
+///
+/// ```
+/// use phpify::random::{Mt19937, MT_RAND_MT19937};
+///
+/// let mut engine = Mt19937::new(1, MT_RAND_MT19937);
+///
+/// assert_eq!(engine.generate(), 1791095845);
+/// assert_eq!(engine.range(1, 6), 6);
+/// ```
+#[derive(Clone)]
+pub struct Mt19937 {
+    state: [u32; N],
+    count: usize,
+    mode: u32,
+}
+
+impl Mt19937 {
+    /// Creates a generator seeded with the seed, in mode MT_RAND_MT19937 or MT_RAND_PHP.
+    pub fn new(seed: u32, mode: u32) -> Self {
+        let mut state = [0; N];
+        state[0] = seed;
+
+        for i in 1..N {
+            let previous = state[i - 1];
+            state[i] = 1812433253u32.wrapping_mul(previous ^ (previous >> 30)).wrapping_add(i as u32);
+        }
+
+        let mut engine = Mt19937 { state, count: N, mode };
+        engine.reload();
+
+        engine
+    }
+
+    /// Returns the mode of the generator.
+    pub fn mode(&self) -> u32 {
+        self.mode
+    }
+
+    /// Returns the next 32 bit number.
+    pub fn generate(&mut self) -> u32 {
+        if self.count >= N {
+            self.reload();
+        }
+
+        let mut s1 = self.state[self.count];
+        self.count += 1;
+
+        s1 ^= s1 >> 11;
+        s1 ^= (s1 << 7) & 0x9d2c5680;
+        s1 ^= (s1 << 15) & 0xefc60000;
+
+        s1 ^ (s1 >> 18)
+    }
+
+    /// Returns a uniformly distributed number between min and max, inclusive, like PHP's
+    /// php_mt_rand_range(). Numbers that would make the distribution uneven are rejected.
+    pub fn range(&mut self, min: i64, max: i64) -> i64 {
+        let umax = (max as u64).wrapping_sub(min as u64);
+
+        let result = if umax > u64::from(u32::MAX) {
+            self.range64(umax)
+        } else {
+            u64::from(self.range32(umax as u32))
+        };
+
+        result.wrapping_add(min as u64) as i64
+    }
+
+    fn range32(&mut self, umax: u32) -> u32 {
+        let result = self.generate();
+
+        if umax == u32::MAX {
+            return result;
+        }
+
+        let umax = umax + 1;
+
+        if umax & (umax - 1) == 0 {
+            return result & (umax - 1);
+        }
+
+        let limit = u32::MAX - (u32::MAX % umax) - 1;
+        let mut result = result;
+
+        while result > limit {
+            result = self.generate();
+        }
+
+        result % umax
+    }
+
+    fn range64(&mut self, umax: u64) -> u64 {
+        let mut result = self.generate64();
+
+        if umax == u64::MAX {
+            return result;
+        }
+
+        let umax = umax + 1;
+
+        if umax & (umax - 1) == 0 {
+            return result & (umax - 1);
+        }
+
+        let limit = u64::MAX - (u64::MAX % umax) - 1;
+
+        while result > limit {
+            result = self.generate64();
+        }
+
+        result % umax
+    }
+
+    fn generate64(&mut self) -> u64 {
+        let high = u64::from(self.generate());
+
+        (high << 32) | u64::from(self.generate())
+    }
+
+    /// Generates the next N numbers of the state vector.
+    fn reload(&mut self) {
+        let twist: fn(u32, u32, u32) -> u32 = if self.mode == MT_RAND_PHP { twist_php } else { twist };
+        let state = &mut self.state;
+
+        for i in 0..N - M {
+            state[i] = twist(state[i + M], state[i], state[i + 1]);
+        }
+
+        for i in N - M..N - 1 {
+            state[i] = twist(state[i + M - N], state[i], state[i + 1]);
+        }
+
+        state[N - 1] = twist(state[M - 1], state[N - 1], state[0]);
+
+        self.count = 0;
+    }
+}
+
+impl Engine for Mt19937 {
+    fn generate(&mut self) -> u64 {
+        u64::from(Mt19937::generate(self))
+    }
+
+    fn size(&self) -> usize {
+        4
+    }
+
+    fn range(&mut self, min: i64, max: i64) -> i64 {
+        Mt19937::range(self, min, max)
+    }
+
+    fn legacy_range(&mut self, min: i64, max: i64) -> Option<i64> {
+        if self.mode != MT_RAND_PHP {
+            return None;
+        }
+
+        let number = f64::from(Mt19937::generate(self) >> 1);
+        let umax = (max as u64).wrapping_sub(min as u64);
+        let scale = (umax as f64 + 1.0) * (number / (i32::MAX as f64 + 1.0));
+
+        Some((min as u64).wrapping_add(scale as u64) as i64)
+    }
+}
+
+fn mix_bits(u: u32, v: u32) -> u32 {
+    (u & 0x80000000) | (v & 0x7fffffff)
+}
+
+fn twist(m: u32, u: u32, v: u32) -> u32 {
+    m ^ (mix_bits(u, v) >> 1) ^ ((v & 1).wrapping_neg() & 0x9908b0df)
+}
+
+/// The twist of PHP before 7.1.0, which uses the low bit of u instead of v.
+fn twist_php(m: u32, u: u32, v: u32) -> u32 {
+    m ^ (mix_bits(u, v) >> 1) ^ ((u & 1).wrapping_neg() & 0x9908b0df)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::{Mt19937, MT_RAND_MT19937, MT_RAND_PHP};
+
+    #[test]
+    fn test() {
+        let mut engine = Mt19937::new(5489, MT_RAND_MT19937);
+        assert_eq!(engine.generate(), 3499211612);
+        assert_eq!(engine.generate(), 581869302);
+
+        let mut engine = Mt19937::new(1, MT_RAND_MT19937);
+        let numbers: Vec<u32> = (0..1000).map(|_| engine.generate()).collect();
+        assert_eq!(numbers[0] >> 1, 895547922);
+        assert_eq!(numbers[1] >> 1, 2141438069);
+
+        let mut engine = Mt19937::new(1, MT_RAND_PHP);
+        let legacy: Vec<u32> = (0..1000).map(|_| engine.generate()).collect();
+        assert_ne!(legacy, numbers);
+        assert_eq!(engine.mode(), MT_RAND_PHP);
+
+        let mut engine = Mt19937::new(3, MT_RAND_MT19937);
+        for _ in 0..1000 {
+            let number = engine.range(-3, 3);
+            assert!((-3..=3).contains(&number));
+        }
+        assert_eq!(engine.range(5, 5), 5);
+
+        for _ in 0..1000 {
+            let number = engine.range(0, 1 << 40);
+            assert!((0..=1 << 40).contains(&number));
+        }
+    }
+}
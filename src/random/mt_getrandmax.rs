@@ -0,0 +1,45 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Show largest possible random value
+///
+/// # Description
+///
+/// Returns the maximum value that can be returned by a call to mt_rand().
+///
+/// # Examples
+///
+/// Example #1 mt_getrandmax() example
+///
+/// ```
+/// use phpify::random::mt_getrandmax;
+///
+/// assert_eq!(mt_getrandmax(), 2147483647);
+/// ```
+pub fn mt_getrandmax() -> i64 {
+    2147483647
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::mt_getrandmax;
+
+    #[test]
+    fn test() {
+        assert_eq!(mt_getrandmax(), i64::from(i32::MAX));
+    }
+}
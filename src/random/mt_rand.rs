@@ -0,0 +1,114 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::error::PhpError;
+use crate::random::generator::with_generator;
+use crate::random::{mt_getrandmax, MT_RAND_PHP};
+
+/// Generate a random value via the Mersenne Twister Random Number Generator
+///
+/// # Description
+///
+/// Returns a random integer between 0 and mt_getrandmax(), inclusive, like mt_rand() without
+/// arguments. Use mt_rand_range() for a random number between min and max.
+///
+/// # Examples
+///
+/// Example #1 mt_rand() example
+///
+/// ```
+/// use phpify::random::{mt_getrandmax, mt_rand};
+///
+/// let number = mt_rand();
+///
+/// assert!(number >= 0 && number <= mt_getrandmax());
+/// ```
+pub fn mt_rand() -> i64 {
+    i64::from(with_generator(|generator| generator.generate() >> 1))
+}
+
+/// Generate a random value between min and max via the Mersenne Twister Random Number Generator
+///
+/// # Description
+///
+/// Returns a random integer between min and max, inclusive, like mt_rand(min, max). The numbers are
+/// uniformly distributed like PHP 7.1 and later do. When the generator was seeded in mode
+/// MT_RAND_PHP, the number is scaled into the range the way PHP did before 7.1.0.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if max is less than min.
+///
+/// # Examples
+///
+/// Example #1 mt_rand_range() example
+///
+/// ```
+/// use phpify::random::{mt_rand_range, mt_srand, MT_RAND_MT19937};
+///
+/// mt_srand(3, MT_RAND_MT19937);
+///
+/// let dice = mt_rand_range(1, 6).unwrap();
+///
+/// assert!(dice >= 1 && dice <= 6);
+/// ```
+pub fn mt_rand_range(min: i64, max: i64) -> Result<i64, PhpError> {
+    if max < min {
+        return Err(PhpError::ValueError("mt_rand(): Argument #2 ($max) must be greater than or equal to argument #1 ($min)".to_string()));
+    }
+
+    Ok(rand_common(min, max))
+}
+
+/// Returns a random number between min and max, like PHP's php_mt_rand_common().
+pub(crate) fn rand_common(min: i64, max: i64) -> i64 {
+    with_generator(|generator| {
+        if generator.mode() != MT_RAND_PHP {
+            return generator.range(min, max);
+        }
+
+        let number = f64::from(generator.generate() >> 1);
+        let scale = (max as f64 - min as f64 + 1.0) * (number / (mt_getrandmax() as f64 + 1.0));
+
+        min.wrapping_add(scale as i64)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::random::{mt_rand, mt_rand_range, mt_srand, MT_RAND_MT19937, MT_RAND_PHP};
+
+    #[test]
+    fn test() {
+        mt_srand(1, MT_RAND_MT19937);
+        assert_eq!(mt_rand(), 895547922);
+
+        mt_srand(42, MT_RAND_MT19937);
+        let numbers: Vec<i64> = (0..100).map(|_| mt_rand_range(1, 10).unwrap()).collect();
+        assert!(numbers.iter().all(|number| (1..=10).contains(number)));
+
+        mt_srand(42, MT_RAND_MT19937);
+        assert_eq!((0..100).map(|_| mt_rand_range(1, 10).unwrap()).collect::<Vec<i64>>(), numbers);
+
+        mt_srand(42, MT_RAND_PHP);
+        assert!((0..100).all(|_| (1..=10).contains(&mt_rand_range(1, 10).unwrap())));
+
+        assert_eq!(mt_rand_range(5, 5), Ok(5));
+        assert_eq!(mt_rand_range(2, 1), Err(PhpError::ValueError("mt_rand(): Argument #2 ($max) must be greater than or equal to argument #1 ($min)".to_string())));
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::random::generator::seed_generator;
+
+/// Seeds the Mersenne Twister Random Number Generator
+///
+/// # Description
+///
+/// Seeds the random number generator with seed. Like PHP, the seed is truncated to 32 bits.
+///
+/// The generator is shared by mt_rand(), rand(), shuffle(), str_shuffle() and array_rand(), so
+/// seeding it makes all of them produce the same sequences as PHP. Every thread has its own
+/// generator.
+///
+/// # Parameters
+///
+/// **mode**
+///
+/// MT_RAND_MT19937 uses the correct implementation, MT_RAND_PHP the incorrect implementation PHP
+/// used up till 7.1.0. Any other mode is treated as MT_RAND_MT19937.
+///
+/// # Examples
+///
+/// Example #1 mt_srand() example
+///
+/// ```
+/// use phpify::random::{mt_rand, mt_srand, MT_RAND_MT19937};
+///
+/// mt_srand(1, MT_RAND_MT19937);
+///
+/// assert_eq!(mt_rand(), 895547922);
+/// assert_eq!(mt_rand(), 2141438069);
+/// ```
+pub fn mt_srand(seed: i64, mode: u32) {
+    seed_generator(seed as u32, mode);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::{mt_rand, mt_srand, MT_RAND_MT19937, MT_RAND_PHP};
+
+    #[test]
+    fn test() {
+        mt_srand(1, MT_RAND_MT19937);
+        let first = mt_rand();
+
+        mt_srand(1 + (1 << 32), MT_RAND_MT19937);
+        assert_eq!(mt_rand(), first);
+
+        mt_srand(1, MT_RAND_PHP);
+        assert_ne!(mt_rand(), first);
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::random::Engine;
+
+/// The multiplier of the linear congruential generator.
+const MULTIPLIER: u128 = (2549297995355413924 << 64) + 4865540595714422341;
+
+/// The increment of the linear congruential generator.
+const INCREMENT: u128 = (6364136223846793005 << 64) + 1442695040888963407;
+
+/// The PCG oneseq 128 XSL RR 64 pseudo random number generator, the way PHP implements it.
+///
+/// # Description
+///
+/// A permuted congruential generator with a 128 bit state that returns 64 bit numbers. It produces
+/// the same sequence as PHP's Random\Engine\PcgOneseq128XslRr64 for the same seed.
+///
+/// # Examples
+///
+/// Example #1 PcgOneseq128XslRr64 example
+///
+/// ```
+/// use phpify::random::{Engine, PcgOneseq128XslRr64};
+///
+/// let mut engine = PcgOneseq128XslRr64::new(42);
+/// let number = engine.generate();
+///
+/// let mut engine = PcgOneseq128XslRr64::new(42);
+/// assert_eq!(engine.generate(), number);
+/// ```
+#[derive(Clone)]
+pub struct PcgOneseq128XslRr64 {
+    state: u128,
+}
+
+impl PcgOneseq128XslRr64 {
+    /// Creates a generator seeded with the seed.
+    pub fn new(seed: u128) -> Self {
+        let mut engine = PcgOneseq128XslRr64 { state: 0 };
+
+        engine.step();
+        engine.state = engine.state.wrapping_add(seed);
+        engine.step();
+
+        engine
+    }
+
+    /// Moves the generator ahead by advance steps, as if generate() was called advance times.
+    pub fn jump(&mut self, mut advance: u64) {
+        let mut multiplier = MULTIPLIER;
+        let mut increment = INCREMENT;
+        let mut accumulated_multiplier = 1u128;
+        let mut accumulated_increment = 0u128;
+
+        while advance > 0 {
+            if advance & 1 == 1 {
+                accumulated_multiplier = accumulated_multiplier.wrapping_mul(multiplier);
+                accumulated_increment = accumulated_increment.wrapping_mul(multiplier).wrapping_add(increment);
+            }
+
+            increment = multiplier.wrapping_add(1).wrapping_mul(increment);
+            multiplier = multiplier.wrapping_mul(multiplier);
+            advance /= 2;
+        }
+
+        self.state = accumulated_multiplier.wrapping_mul(self.state).wrapping_add(accumulated_increment);
+    }
+
+    fn step(&mut self) {
+        self.state = self.state.wrapping_mul(MULTIPLIER).wrapping_add(INCREMENT);
+    }
+}
+
+impl Engine for PcgOneseq128XslRr64 {
+    fn generate(&mut self) -> u64 {
+        self.step();
+
+        let value = ((self.state >> 64) as u64) ^ (self.state as u64);
+
+        value.rotate_right((self.state >> 122) as u32)
+    }
+
+    fn size(&self) -> usize {
+        8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::{Engine, PcgOneseq128XslRr64};
+
+    #[test]
+    fn test() {
+        let mut engine = PcgOneseq128XslRr64::new(1234);
+        let numbers: Vec<u64> = (0..100).map(|_| engine.generate()).collect();
+
+        let mut engine = PcgOneseq128XslRr64::new(1234);
+        engine.jump(40);
+        assert_eq!(engine.generate(), numbers[40]);
+        engine.jump(0);
+        assert_eq!(engine.generate(), numbers[41]);
+
+        assert_ne!(PcgOneseq128XslRr64::new(1235).generate(), numbers[0]);
+        assert_eq!(engine.size(), 8);
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::random::generator::with_generator;
+use crate::random::mt_rand::rand_common;
+
+/// Generate a random integer
+///
+/// # Description
+///
+/// Returns a random integer between 0 and getrandmax(), inclusive. Like PHP 7.1 and later, rand()
+/// is an alias of mt_rand() and uses the same generator.
+///
+/// # Examples
+///
+/// Example #1 rand() example
+///
+/// ```
+/// use phpify::random::{rand, srand, MT_RAND_MT19937};
+///
+/// srand(1, MT_RAND_MT19937);
+///
+/// assert_eq!(rand(), 895547922);
+/// ```
+pub fn rand() -> i64 {
+    i64::from(with_generator(|generator| generator.generate() >> 1))
+}
+
+/// Generate a random integer between min and max
+///
+/// # Description
+///
+/// Returns a random integer between min and max, inclusive, like rand(min, max). Unlike
+/// mt_rand_range(), a max lower than min is allowed, the range is then swapped.
+///
+/// # Examples
+///
+/// Example #1 rand_range() example
+///
+/// ```
+/// use phpify::random::rand_range;
+///
+/// let number = rand_range(5, 15);
+///
+/// assert!(number >= 5 && number <= 15);
+/// ```
+pub fn rand_range(min: i64, max: i64) -> i64 {
+    if max < min {
+        return rand_common(max, min);
+    }
+
+    rand_common(min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::{mt_rand_range, rand, rand_range, srand, MT_RAND_MT19937};
+
+    #[test]
+    fn test() {
+        srand(7, MT_RAND_MT19937);
+        let numbers: Vec<i64> = (0..10).map(|_| mt_rand_range(1, 100).unwrap()).collect();
+
+        srand(7, MT_RAND_MT19937);
+        assert_eq!((0..10).map(|_| rand_range(100, 1)).collect::<Vec<i64>>(), numbers);
+
+        srand(1, MT_RAND_MT19937);
+        assert_eq!(rand(), 895547922);
+    }
+}
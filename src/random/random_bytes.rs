@@ -0,0 +1,72 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use crate::error::PhpError;
+use crate::string::PhpString;
+
+/// Get cryptographically secure random bytes
+///
+/// # Description
+///
+/// Generates a string containing uniformly selected random bytes with the requested length.
+///
+/// As the returned bytes are selected completely randomly, the resulting string is likely to
+/// contain unprintable characters or invalid UTF-8 sequences.
+///
+/// The bytes come from the CSPRNG of the operating system.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if length is 0.
+///
+/// # Examples
+///
+/// Example #1 random_bytes() example
+///
+/// ```
+/// use phpify::random::random_bytes;
+///
+/// let bytes = random_bytes(5).unwrap();
+///
+/// assert_eq!(bytes.len(), 5);
+/// ```
+pub fn random_bytes(length: usize) -> Result<PhpString, PhpError> {
+    if length == 0 {
+        return Err(PhpError::ValueError("random_bytes(): Argument #1 ($length) must be greater than 0".to_string()));
+    }
+
+    let mut bytes = vec![0; length];
+    OsRng.fill_bytes(&mut bytes);
+
+    Ok(PhpString::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::random::random_bytes;
+
+    #[test]
+    fn test() {
+        assert_eq!(random_bytes(16).unwrap().len(), 16);
+        assert_ne!(random_bytes(16).unwrap(), random_bytes(16).unwrap());
+        assert_eq!(random_bytes(0), Err(PhpError::ValueError("random_bytes(): Argument #1 ($length) must be greater than 0".to_string())));
+    }
+}
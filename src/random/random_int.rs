@@ -0,0 +1,68 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::error::PhpError;
+use crate::random::{Engine, Secure};
+
+/// Get a cryptographically secure, uniformly selected integer
+///
+/// # Description
+///
+/// Generates a cryptographically random integer between min and max, inclusive, suitable for use
+/// where unbiased results are critical, such as when shuffling a deck of cards for a poker game.
+///
+/// The numbers come from the CSPRNG of the operating system.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if min is greater than max.
+///
+/// # Examples
+///
+/// Example #1 random_int() example
+///
+/// ```
+/// use phpify::random::random_int;
+///
+/// let number = random_int(100, 999).unwrap();
+///
+/// assert!(number >= 100 && number <= 999);
+/// ```
+pub fn random_int(min: i64, max: i64) -> Result<i64, PhpError> {
+    if min > max {
+        return Err(PhpError::ValueError("random_int(): Argument #1 ($min) must be less than or equal to argument #2 ($max)".to_string()));
+    }
+
+    Ok(Secure.range(min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::random::random_int;
+
+    #[test]
+    fn test() {
+        for _ in 0..100 {
+            assert!((-5..=5).contains(&random_int(-5, 5).unwrap()));
+        }
+
+        assert_eq!(random_int(3, 3), Ok(3));
+        assert!(random_int(i64::MIN, i64::MAX).is_ok());
+        assert_eq!(random_int(2, 1), Err(PhpError::ValueError("random_int(): Argument #1 ($min) must be less than or equal to argument #2 ($max)".to_string())));
+    }
+}
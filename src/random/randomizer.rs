@@ -0,0 +1,215 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{pick_keys, shuffle_slice_with, ArrayLike};
+use crate::error::PhpError;
+use crate::random::Engine;
+use crate::string::PhpString;
+
+/// Randomizer on top of a swappable engine, the way PHP 8.2's Random\Randomizer works.
+///
+/// # Description
+///
+/// Provides the random functions on top of an engine: Mt19937, PcgOneseq128XslRr64 and
+/// Xoshiro256StarStar for seeded, reproducible sequences, or Secure for cryptographically secure
+/// results. Any type implementing Engine can be used, including Box<dyn Engine>.
+///
+/// # Examples
+///
+/// Example #1 Randomizer example
+///
+/// ```
+/// use phpify::random::{Randomizer, Secure, Xoshiro256StarStar};
+///
+/// let mut randomizer = Randomizer::new(Xoshiro256StarStar::new(42));
+/// let dice = randomizer.get_int(1, 6).unwrap();
+///
+/// assert!(dice >= 1 && dice <= 6);
+///
+/// let mut randomizer = Randomizer::new(Secure);
+/// let token = randomizer.get_bytes(16).unwrap();
+///
+/// assert_eq!(token.len(), 16);
+/// ```
+#[derive(Clone)]
+pub struct Randomizer<E>
+    where
+        E: Engine {
+
+    engine: E,
+}
+
+impl<E> Randomizer<E>
+    where
+        E: Engine {
+
+    /// Creates a randomizer that draws its random numbers from the engine.
+    pub fn new(engine: E) -> Self {
+        Randomizer { engine }
+    }
+
+    /// Returns the engine.
+    pub fn engine(&self) -> &E {
+        &self.engine
+    }
+
+    /// Returns a mutable reference to the engine.
+    pub fn engine_mut(&mut self) -> &mut E {
+        &mut self.engine
+    }
+
+    /// Consumes the randomizer, returning the engine.
+    pub fn into_engine(self) -> E {
+        self.engine
+    }
+
+    /// Returns a uniformly selected integer between min and max, inclusive, like getInt().
+    ///
+    /// With Mt19937 in mode MT_RAND_PHP, the number is scaled into the range the way PHP did before
+    /// 7.1.0, which is not uniform.
+    ///
+    /// # Errors
+    ///
+    /// Returns PhpError::ValueError if max is less than min.
+    pub fn get_int(&mut self, min: i64, max: i64) -> Result<i64, PhpError> {
+        if max < min {
+            return Err(PhpError::ValueError("Random\\Randomizer::getInt(): Argument #2 ($max) must be greater than or equal to argument #1 ($min)".to_string()));
+        }
+
+        Ok(self.engine.legacy_range(min, max).unwrap_or_else(|| self.engine.range(min, max)))
+    }
+
+    /// Returns a positive integer built from the next number of the engine, like nextInt().
+    pub fn next_int(&mut self) -> i64 {
+        (self.engine.generate() >> 1) as i64
+    }
+
+    /// Returns a string of length random bytes, like getBytes().
+    ///
+    /// # Errors
+    ///
+    /// Returns PhpError::ValueError if length is 0.
+    pub fn get_bytes(&mut self, length: usize) -> Result<PhpString, PhpError> {
+        if length == 0 {
+            return Err(PhpError::ValueError("Random\\Randomizer::getBytes(): Argument #1 ($length) must be greater than 0".to_string()));
+        }
+
+        let mut bytes = Vec::with_capacity(length);
+
+        while bytes.len() < length {
+            let number = self.engine.generate();
+            let size = self.engine.size().min(length - bytes.len());
+
+            bytes.extend_from_slice(&number.to_le_bytes()[..size]);
+        }
+
+        Ok(PhpString::from(bytes))
+    }
+
+    /// Returns the values of the array in random order, like shuffleArray(). The keys are not
+    /// preserved.
+    pub fn shuffle_array<A>(&mut self, array: &A) -> Vec<A::Value>
+        where
+            A: ArrayLike + ?Sized,
+            A::Value: Clone {
+
+        let mut values: Vec<A::Value> = array.entries().map(|(_, value)| value.clone()).collect();
+        shuffle_slice_with(&mut values, &mut self.engine);
+
+        values
+    }
+
+    /// Returns the bytes of the string in random order, like shuffleBytes().
+    pub fn shuffle_bytes<S>(&mut self, bytes: S) -> PhpString
+        where
+            S: AsRef<[u8]> {
+
+        let mut bytes = bytes.as_ref().to_vec();
+        shuffle_slice_with(&mut bytes, &mut self.engine);
+
+        PhpString::from(bytes)
+    }
+
+    /// Returns num distinct random keys of the array, in the order they appear in the array, like
+    /// pickArrayKeys().
+    ///
+    /// # Errors
+    ///
+    /// Returns PhpError::ValueError if array is empty, or if num is 0 or greater than the number of
+    /// entries in array.
+    pub fn pick_array_keys<A>(&mut self, array: &A, num: usize) -> Result<Vec<A::Key>, PhpError>
+        where
+            A: ArrayLike + ?Sized {
+
+        pick_keys("Random\\Randomizer::pickArrayKeys", array, num, &mut self.engine)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{array_rand_with, shuffle, PhpArray};
+    use crate::error::PhpError;
+    use crate::random::{mt_rand_range, mt_srand, Engine, Mt19937, PcgOneseq128XslRr64, Randomizer, Secure, Xoshiro256StarStar, MT_RAND_MT19937, MT_RAND_PHP};
+    use crate::string::str_shuffle;
+
+    #[test]
+    fn test() {
+        let mut randomizer = Randomizer::new(Mt19937::new(1, MT_RAND_MT19937));
+        let mut engine = Mt19937::new(1, MT_RAND_MT19937);
+        assert_eq!(randomizer.get_int(1, 100), Ok(engine.range(1, 100)));
+        assert_eq!(randomizer.next_int(), i64::from(engine.generate() >> 1));
+        assert_eq!(randomizer.get_int(2, 1), Err(PhpError::ValueError("Random\\Randomizer::getInt(): Argument #2 ($max) must be greater than or equal to argument #1 ($min)".to_string())));
+
+        let mut randomizer = Randomizer::new(Mt19937::new(1, MT_RAND_PHP));
+        let mut engine = Mt19937::new(1, MT_RAND_PHP);
+        assert_eq!(randomizer.get_int(1, 100), Ok(1 + (100.0 * f64::from(engine.generate() >> 1) / 2147483648.0) as i64));
+        let mut randomizer = Randomizer::new(Mt19937::new(1, MT_RAND_PHP));
+        mt_srand(1, MT_RAND_PHP);
+        assert_eq!(randomizer.get_int(1, 100), mt_rand_range(1, 100));
+        let mut randomizer: Randomizer<Box<dyn Engine>> = Randomizer::new(Box::new(Mt19937::new(1, MT_RAND_PHP)));
+        assert_eq!(randomizer.get_int(1, 100), Randomizer::new(Mt19937::new(1, MT_RAND_PHP)).get_int(1, 100));
+
+        let mut randomizer = Randomizer::new(Xoshiro256StarStar::from_state([1, 2, 3, 4]));
+        let bytes = randomizer.get_bytes(10).unwrap();
+        assert_eq!(bytes.as_bytes(), [0, 45, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(randomizer.get_bytes(0).is_err());
+
+        let mut randomizer = Randomizer::new(Mt19937::new(5, MT_RAND_MT19937));
+        let values = randomizer.shuffle_array(&vec![1, 2, 3, 4, 5, 6]);
+        mt_srand(5, MT_RAND_MT19937);
+        let mut expected = vec![1, 2, 3, 4, 5, 6];
+        shuffle(&mut expected);
+        assert_eq!(values, expected);
+
+        let mut randomizer = Randomizer::new(Mt19937::new(5, MT_RAND_MT19937));
+        let shuffled = randomizer.shuffle_bytes("abcdef");
+        mt_srand(5, MT_RAND_MT19937);
+        assert_eq!(shuffled, str_shuffle("abcdef"));
+
+        let array: PhpArray<i32> = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)].into_iter().collect();
+        let mut randomizer = Randomizer::new(PcgOneseq128XslRr64::new(7));
+        let keys = randomizer.pick_array_keys(&array, 2).unwrap();
+        assert_eq!(keys, array_rand_with(&array, 2, &mut PcgOneseq128XslRr64::new(7)).unwrap());
+        assert_eq!(keys.len(), 2);
+        assert_eq!(randomizer.pick_array_keys(&PhpArray::<i32>::new(), 1), Err(PhpError::ValueError("Random\\Randomizer::pickArrayKeys(): Argument #1 ($array) cannot be empty".to_string())));
+        assert!(keys.iter().all(|key| array.keys().any(|k| k == key)));
+
+        let mut randomizer: Randomizer<Box<dyn Engine>> = Randomizer::new(Box::new(Secure));
+        assert!((-3..=3).contains(&randomizer.get_int(-3, 3).unwrap()));
+        assert_eq!(randomizer.engine().size(), 8);
+    }
+}
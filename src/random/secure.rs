@@ -0,0 +1,70 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use crate::random::Engine;
+
+/// The cryptographically secure random number generator of the operating system.
+///
+/// # Description
+///
+/// Like PHP's Random\Engine\Secure, the numbers come from the CSPRNG of the operating system, the
+/// same source random_int() and random_bytes() use. The engine cannot be seeded.
+///
+/// # Examples
+///
+/// Example #1 Secure example
+///
+/// ```
+/// use phpify::array::array_rand_with;
+/// use phpify::random::Secure;
+///
+/// let input = vec!["Neo", "Morpheus", "Trinity", "Cypher", "Tank"];
+/// let rand_keys = array_rand_with(&input, 2, &mut Secure).unwrap();
+///
+/// assert_eq!(rand_keys.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Secure;
+
+impl Engine for Secure {
+    fn generate(&mut self) -> u64 {
+        OsRng.next_u64()
+    }
+
+    fn size(&self) -> usize {
+        8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::{Engine, Secure};
+
+    #[test]
+    fn test() {
+        let mut engine = Secure;
+        let numbers: Vec<u64> = (0..10).map(|_| engine.generate()).collect();
+        assert!(numbers.iter().any(|number| *number != numbers[0]));
+
+        for _ in 0..100 {
+            assert!((-2..=2).contains(&engine.range(-2, 2)));
+        }
+    }
+}
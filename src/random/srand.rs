@@ -0,0 +1,54 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::random::mt_srand;
+
+/// Seed the random number generator
+///
+/// # Description
+///
+/// Seeds the random number generator with seed. Like PHP 7.1 and later, srand() is an alias of
+/// mt_srand().
+///
+/// # Examples
+///
+/// Example #1 srand() example
+///
+/// ```
+/// use phpify::random::{mt_rand, srand, MT_RAND_MT19937};
+///
+/// srand(1, MT_RAND_MT19937);
+///
+/// assert_eq!(mt_rand(), 895547922);
+/// ```
+pub fn srand(seed: i64, mode: u32) {
+    mt_srand(seed, mode);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::{mt_rand, mt_srand, srand, MT_RAND_MT19937};
+
+    #[test]
+    fn test() {
+        mt_srand(5, MT_RAND_MT19937);
+        let number = mt_rand();
+
+        srand(5, MT_RAND_MT19937);
+        assert_eq!(mt_rand(), number);
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::random::Engine;
+
+/// The xoshiro256** pseudo random number generator, the way PHP implements it.
+///
+/// # Description
+///
+/// A generator with a 256 bit state that returns 64 bit numbers. It produces the same sequence as
+/// PHP's Random\Engine\Xoshiro256StarStar for the same seed.
+///
+/// # Examples
+///
+/// Example #1 Xoshiro256StarStar example
+///
+/// ```
+/// use phpify::random::{Engine, Xoshiro256StarStar};
+///
+/// let mut engine = Xoshiro256StarStar::from_state([1, 2, 3, 4]);
+///
+/// assert_eq!(engine.generate(), 11520);
+/// assert_eq!(engine.generate(), 0);
+/// ```
+#[derive(Clone)]
+pub struct Xoshiro256StarStar {
+    state: [u64; 4],
+}
+
+impl Xoshiro256StarStar {
+    /// Creates a generator seeded with the seed, which is expanded into the state with SplitMix64.
+    pub fn new(mut seed: u64) -> Self {
+        let mut state = [0; 4];
+
+        for word in state.iter_mut() {
+            *word = split_mix64(&mut seed);
+        }
+
+        Xoshiro256StarStar { state }
+    }
+
+    /// Creates a generator with the state, which must not be all zeros.
+    pub fn from_state(state: [u64; 4]) -> Self {
+        Xoshiro256StarStar { state }
+    }
+}
+
+impl Engine for Xoshiro256StarStar {
+    fn generate(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+
+    fn size(&self) -> usize {
+        8
+    }
+}
+
+fn split_mix64(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_add(0x9e3779b97f4a7c15);
+
+    let mut result = *seed;
+    result = (result ^ (result >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    result = (result ^ (result >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+    result ^ (result >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::{Engine, Xoshiro256StarStar};
+
+    #[test]
+    fn test() {
+        let mut engine = Xoshiro256StarStar::from_state([1, 2, 3, 4]);
+        let numbers: Vec<u64> = (0..4).map(|_| engine.generate()).collect();
+        assert_eq!(numbers, [11520, 0, 1509978240, 1215971899390074240]);
+
+        let engine = Xoshiro256StarStar::new(0);
+        assert_eq!(engine.state, [0xe220a8397b1dcdaf, 0x6e789e6aa1b965f4, 0x06c45d188009454f, 0xf88bb8a8724c81ec]);
+        assert_eq!(engine.size(), 8);
+    }
+}
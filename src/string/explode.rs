@@ -17,6 +17,10 @@
 
 // https://www.php.net/manual/en/ref.strings.php
 
+use crate::error::PhpError;
+use crate::string::strpos::find;
+use crate::string::PhpString;
+
 /// Split a string by a string.
 ///
 /// # Description
@@ -35,6 +39,10 @@
 ///
 /// If the limit parameter is zero, then this is treated as 1.
 ///
+/// # Errors
+///
+/// Returns PhpError::ValueError if delimiter is an empty string.
+///
 /// # Examples
 ///
 /// Example #1 explode() examples
@@ -82,62 +90,61 @@
 /// assert_eq!(explode("|", str, 2).unwrap(), ["one", "two|three|four"]);
 /// assert_eq!(explode("|", str, -1).unwrap(), ["one", "two", "three"]);
 /// ```
-pub fn explode<D, S>(delimiter: D, string: S, limit: isize) -> Option<Vec<String>>
+pub fn explode<D, S>(delimiter: D, string: S, limit: isize) -> Result<Vec<PhpString>, PhpError>
     where
-        D: AsRef<str>,
-        S: AsRef<str> {
+        D: AsRef<[u8]>,
+        S: AsRef<[u8]> {
 
     let delimiter = delimiter.as_ref();
     let string = string.as_ref();
 
     if delimiter.is_empty() {
-        return None;
+        return Err(PhpError::ValueError("explode(): Argument #1 ($separator) cannot be empty".to_string()));
     }
 
     if limit == 0 || limit == 1 {
-        return Some(vec![string.to_string()]);
+        return Ok(vec![PhpString::from(string)]);
     }
 
-    let vec: Vec<String> = string.split(delimiter).map(String::from).collect();
-    let vec_length = vec.len() as isize;
-
-    if limit > vec_length {
-        return Some(vec);
-    }
+    let mut vec = Vec::new();
+    let mut rest = string;
 
-    if limit > 0 {
-        let (left, right) = vec.split_at(limit as usize - 1);
-        let mut vec = left.to_vec();
-        vec.push(right.join(delimiter));
+    while let Some(position) = find(rest, delimiter) {
+        if limit > 0 && vec.len() as isize == limit - 1 {
+            break;
+        }
 
-        return Some(vec);
+        vec.push(PhpString::from(&rest[..position]));
+        rest = &rest[position + delimiter.len()..];
     }
 
-    if limit < 0 {
-        if limit <= (0 - vec_length) {
-            return Some(vec![]);
-        }
-        let (left, _right) = vec.split_at((vec_length + limit) as usize);
-        let vec = left.to_vec();
+    vec.push(PhpString::from(rest));
 
-        return Some(vec);
+    if limit < 0 {
+        let vec_length = vec.len() as isize;
+        vec.truncate((vec_length + limit).max(0) as usize);
     }
 
-    Some(vec)
+    Ok(vec)
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::error::PhpError;
     use crate::string::explode;
 
     #[test]
     fn test() {
-        assert_eq!(explode("|", "one|two|three", 3), Some(vec!["one".to_string(), "two".to_string(), "three".to_string()]));
-        assert_eq!(explode("|", "one|two|three", 1), Some(vec!["one|two|three".to_string()]));
-        assert_eq!(explode("|", "one|two|three", 2), Some(vec!["one".to_string(), "two|three".to_string()]));
-        assert_eq!(explode("|", "one|two|three", -1), Some(vec!["one".to_string(), "two".to_string()]));
-        assert_eq!(explode("|", "one|two|three", -3), Some(vec![]));
-        assert_eq!(explode(",", "one|two|three", 1), Some(vec!["one|two|three".to_string()]));
-        assert_eq!(explode("", "one|two|three", 3), None);
+        assert_eq!(explode("|", "one|two|three", 3).unwrap(), ["one", "two", "three"]);
+        assert_eq!(explode("|", "one|two|three", 1).unwrap(), ["one|two|three"]);
+        assert_eq!(explode("|", "one|two|three", 2).unwrap(), ["one", "two|three"]);
+        assert_eq!(explode("|", "one|two|three", -1).unwrap(), ["one", "two"]);
+        assert!(explode("|", "one|two|three", -3).unwrap().is_empty());
+        assert_eq!(explode(",", "one|two|three", 1).unwrap(), ["one|two|three"]);
+        assert_eq!(explode("", "one|two|three", 3), Err(PhpError::ValueError("explode(): Argument #1 ($separator) cannot be empty".to_string())));
+
+        assert_eq!(explode("é", "aébéc", 2).unwrap(), ["a", "béc"]);
+        assert_eq!(explode(b"\xff", b"a\xffb", isize::MAX).unwrap(), ["a", "b"]);
+        assert_eq!(explode(b"\xa9", "é", isize::MAX).unwrap()[0].as_bytes(), b"\xc3");
     }
 }
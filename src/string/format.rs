@@ -0,0 +1,371 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp;
+
+use crate::error::PhpError;
+use crate::string::PhpString;
+use crate::value::{gcvt, Value};
+
+/// The largest width, precision and argument number PHP accepts.
+const INT_MAX: i64 = 2147483647;
+
+/// The precision of floats when none is given.
+const FLOAT_PRECISION: i64 = 6;
+
+/// The largest precision of floats.
+const MAX_FLOAT_PRECISION: i64 = 53;
+
+/// A conversion specification, e.g. %1$'*-10.3f.
+struct Spec {
+    padding: u8,
+    left: bool,
+    always_sign: bool,
+    width: usize,
+    precision: i64,
+    has_precision: bool,
+    explicit_precision: bool,
+}
+
+fn value_error(message: &str) -> PhpError {
+    PhpError::ValueError(message.to_string())
+}
+
+/// Formats the arguments according to the format, like PHP's php_formatted_print().
+///
+/// If the format refers to more arguments than given, the error is created by missing from the
+/// number of arguments required and given, not including the format.
+pub(crate) fn format<F>(bytes: &[u8], args: &[Value], missing: F) -> Result<PhpString, PhpError>
+    where
+        F: FnOnce(usize, usize) -> PhpError {
+
+    let mut result = Vec::new();
+    let mut position = 0;
+    let mut current_arg = 0;
+    let mut max_missing_arg = None;
+
+    let mut next_arg = |argnum: Option<usize>| argnum.unwrap_or_else(|| {
+        current_arg += 1;
+        current_arg - 1
+    });
+
+    while position < bytes.len() {
+        if bytes[position] != b'%' {
+            let end = bytes[position..].iter().position(|byte| *byte == b'%').map_or(bytes.len(), |end| position + end);
+            result.extend_from_slice(&bytes[position..end]);
+            position = end;
+            continue;
+        }
+
+        if bytes.get(position + 1) == Some(&b'%') {
+            result.push(b'%');
+            position += 2;
+            continue;
+        }
+
+        position += 1;
+
+        let argnum = argument_number(bytes, &mut position)?;
+
+        let mut spec = Spec {
+            padding: b' ',
+            left: false,
+            always_sign: false,
+            width: 0,
+            precision: 0,
+            has_precision: false,
+            explicit_precision: false,
+        };
+
+        loop {
+            match bytes.get(position) {
+                Some(b' ') => spec.padding = b' ',
+                Some(b'0') => spec.padding = b'0',
+                Some(b'-') => spec.left = true,
+                Some(b'+') => spec.always_sign = true,
+                Some(b'\'') => match bytes.get(position + 1) {
+                    Some(padding) => {
+                        spec.padding = *padding;
+                        position += 1;
+                    },
+                    None => return Err(value_error("Missing padding character")),
+                },
+                _ => break,
+            }
+
+            position += 1;
+        }
+
+        if bytes.get(position) == Some(&b'*') {
+            position += 1;
+
+            let width_argnum = next_arg(argument_number(bytes, &mut position)?);
+            match args.get(width_argnum) {
+                Some(Value::Int(width)) if (0..=INT_MAX).contains(width) => spec.width = *width as usize,
+                Some(Value::Int(_)) => {
+                    return Err(value_error("Width must be greater than or equal to zero and less than 2147483647"));
+                },
+                Some(_) => return Err(value_error("Width must be an integer")),
+                None => {
+                    max_missing_arg = cmp::max(max_missing_arg, Some(width_argnum));
+                    continue;
+                },
+            }
+        } else if bytes.get(position).is_some_and(u8::is_ascii_digit) {
+            spec.width = number(bytes, &mut position)
+                .ok_or_else(|| value_error("Width must be greater than zero and less than 2147483647"))? as usize;
+        }
+
+        if bytes.get(position) == Some(&b'.') {
+            position += 1;
+            spec.has_precision = true;
+
+            if bytes.get(position) == Some(&b'*') {
+                position += 1;
+
+                let precision_argnum = next_arg(argument_number(bytes, &mut position)?);
+                match args.get(precision_argnum) {
+                    Some(Value::Int(precision)) if (-1..=INT_MAX).contains(precision) => spec.precision = *precision,
+                    Some(Value::Int(_)) => return Err(value_error("Precision must be between -1 and 2147483647")),
+                    Some(_) => return Err(value_error("Precision must be an integer")),
+                    None => {
+                        max_missing_arg = cmp::max(max_missing_arg, Some(precision_argnum));
+                        continue;
+                    },
+                }
+
+                spec.explicit_precision = true;
+            } else if bytes.get(position).is_some_and(u8::is_ascii_digit) {
+                spec.precision = number(bytes, &mut position)
+                    .ok_or_else(|| value_error("Precision must be greater than zero and less than 2147483647"))?;
+                spec.explicit_precision = true;
+            }
+        }
+
+        if bytes.get(position) == Some(&b'l') {
+            position += 1;
+        }
+
+        let argnum = next_arg(argnum);
+        let arg = match args.get(argnum) {
+            Some(arg) => arg,
+            None => {
+                max_missing_arg = cmp::max(max_missing_arg, Some(argnum));
+                continue;
+            },
+        };
+
+        let specifier = match bytes.get(position) {
+            Some(specifier) => *specifier,
+            None => return Err(value_error("Missing format specifier at end of string")),
+        };
+
+        match specifier {
+            b's' => {
+                let max_width = if spec.explicit_precision && spec.precision >= 0 { Some(spec.precision as usize) } else { None };
+                append_string(&mut result, &arg.to_php_string(), spec.width, max_width, spec.padding, spec.left, false, false);
+            },
+            b'd' => append_int(&mut result, arg.to_int(), &spec),
+            b'u' => append_uint(&mut result, arg.to_int() as u64, &spec),
+            b'e' | b'E' | b'f' | b'F' | b'g' | b'G' | b'h' | b'H' => {
+                append_double(&mut result, arg.to_float(), specifier as char, &spec)?
+            },
+            b'c' => result.push(arg.to_int() as u8),
+            b'o' => append_2n(&mut result, arg.to_int(), 8, false, &spec),
+            b'x' => append_2n(&mut result, arg.to_int(), 16, false, &spec),
+            b'X' => append_2n(&mut result, arg.to_int(), 16, true, &spec),
+            b'b' => append_2n(&mut result, arg.to_int(), 2, false, &spec),
+            b'%' => result.push(b'%'),
+            _ => {
+                let specifier = String::from_utf8_lossy(&[specifier]).into_owned();
+                return Err(PhpError::ValueError(format!("Unknown format specifier \"{}\"", specifier)));
+            },
+        }
+
+        position += 1;
+    }
+
+    if let Some(max_missing_arg) = max_missing_arg {
+        return Err(missing(max_missing_arg + 1, args.len()));
+    }
+
+    Ok(PhpString::from(result))
+}
+
+/// Parses an argument number followed by $, returning its zero-based index.
+fn argument_number(format: &[u8], position: &mut usize) -> Result<Option<usize>, PhpError> {
+    let digits = format[*position..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+
+    if format.get(*position + digits) != Some(&b'$') {
+        return Ok(None);
+    }
+
+    match number(format, position) {
+        Some(argnum) if argnum > 0 => {
+            *position += 1;
+            Ok(Some(argnum as usize - 1))
+        },
+        _ => Err(value_error("Argument number specifier must be greater than zero and less than 2147483647")),
+    }
+}
+
+/// Parses a decimal number, which must be less than INT_MAX.
+fn number(format: &[u8], position: &mut usize) -> Option<i64> {
+    let digits = format[*position..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+    let number = format[*position..*position + digits].iter()
+        .fold(0i64, |number, digit| number.saturating_mul(10).saturating_add((digit - b'0') as i64));
+    *position += digits;
+
+    if number >= INT_MAX {
+        return None;
+    }
+
+    Some(number)
+}
+
+/// Appends a string padded to min_width and truncated to max_width bytes, like PHP's
+/// php_sprintf_appendstring().
+///
+/// When padding right-aligned numbers with zeros, the sign is moved in front of the padding.
+#[allow(clippy::too_many_arguments)]
+fn append_string(result: &mut Vec<u8>, add: &[u8], min_width: usize, max_width: Option<usize>, padding: u8, left: bool, neg: bool, always_sign: bool) {
+    let mut copy_len = match max_width {
+        Some(max_width) => cmp::min(max_width, add.len()),
+        None => add.len(),
+    };
+    let npad = min_width.saturating_sub(copy_len);
+    let mut add = add;
+
+    if !left {
+        if (neg || always_sign) && padding == b'0' {
+            result.push(if neg { b'-' } else { b'+' });
+
+            add = &add[1..];
+            copy_len = copy_len.saturating_sub(1);
+        }
+
+        append_padding(result, padding, npad);
+    }
+
+    result.extend_from_slice(&add[..copy_len]);
+
+    if left {
+        append_padding(result, padding, npad);
+    }
+}
+
+/// Appends the padding byte count times.
+fn append_padding(result: &mut Vec<u8>, padding: u8, count: usize) {
+    result.resize(result.len() + count, padding);
+}
+
+/// Zeros cannot be used to pad integers on the right.
+fn int_padding(spec: &Spec) -> u8 {
+    if spec.left && spec.padding == b'0' { b' ' } else { spec.padding }
+}
+
+fn append_int(result: &mut Vec<u8>, number: i64, spec: &Spec) {
+    let neg = number < 0;
+
+    let digits = if neg {
+        format!("-{}", number.unsigned_abs())
+    } else if spec.always_sign {
+        format!("+{}", number)
+    } else {
+        number.to_string()
+    };
+
+    append_string(result, digits.as_bytes(), spec.width, None, int_padding(spec), spec.left, neg, spec.always_sign);
+}
+
+fn append_uint(result: &mut Vec<u8>, number: u64, spec: &Spec) {
+    append_string(result, number.to_string().as_bytes(), spec.width, None, int_padding(spec), spec.left, false, false);
+}
+
+/// Appends the two's complement of the number in binary, octal or hexadecimal notation.
+///
+/// Like PHP, an explicit precision truncates the digits to nothing.
+fn append_2n(result: &mut Vec<u8>, number: i64, radix: u32, upper: bool, spec: &Spec) {
+    let number = number as u64;
+
+    let digits = match (radix, upper) {
+        (2, _) => format!("{:b}", number),
+        (8, _) => format!("{:o}", number),
+        (_, false) => format!("{:x}", number),
+        (_, true) => format!("{:X}", number),
+    };
+
+    let max_width = if spec.explicit_precision { Some(0) } else { None };
+
+    append_string(result, digits.as_bytes(), spec.width, max_width, spec.padding, spec.left, false, false);
+}
+
+fn append_double(result: &mut Vec<u8>, number: f64, specifier: char, spec: &Spec) -> Result<(), PhpError> {
+    let precision = if !spec.has_precision { FLOAT_PRECISION } else { cmp::min(spec.precision, MAX_FLOAT_PRECISION) };
+
+    if number.is_nan() {
+        append_string(result, b"NaN", 3, None, spec.padding, spec.left, false, spec.always_sign);
+        return Ok(());
+    }
+
+    if number.is_infinite() {
+        let neg = number < 0.0;
+        let string = if neg { "-Inf" } else if spec.always_sign { "+Inf" } else { "Inf" };
+        append_string(result, string.as_bytes(), string.len(), None, spec.padding, spec.left, neg, spec.always_sign);
+        return Ok(());
+    }
+
+    let (mut string, neg) = match specifier {
+        'e' | 'E' | 'f' | 'F' => {
+            if precision == -1 {
+                return Err(value_error("Precision -1 is only supported for %g, %G, %h and %H"));
+            }
+
+            let precision = precision as usize;
+            let neg = number < 0.0;
+            let number = number.abs();
+
+            let string = if specifier == 'f' || specifier == 'F' {
+                format!("{:.*}", precision, number)
+            } else {
+                let formatted = format!("{:.*e}", precision, number);
+                let (mantissa, exponent) = formatted.split_at(formatted.find('e').expect("exponential notation"));
+                let exponent: i32 = exponent[1..].parse().expect("exponent");
+
+                format!("{}{}{}{}", mantissa, specifier, if exponent < 0 { '-' } else { '+' }, exponent.abs())
+            };
+
+            (if neg { format!("-{}", string) } else { string }, neg)
+        },
+        _ => {
+            let precision = if precision == 0 { 1 } else { precision };
+            let exp_char = if specifier == 'G' || specifier == 'H' { 'E' } else { 'e' };
+            let string = gcvt(number, precision as i32, exp_char);
+            let neg = string.starts_with('-');
+
+            (string, neg)
+        },
+    };
+
+    if !neg && spec.always_sign {
+        string.insert(0, '+');
+    }
+
+    append_string(result, string.as_bytes(), spec.width, None, spec.padding, spec.left, neg, spec.always_sign);
+
+    Ok(())
+}
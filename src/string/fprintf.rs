@@ -0,0 +1,93 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.strings.php
+
+use std::io::Write;
+
+use crate::error::PhpError;
+use crate::string::sprintf;
+use crate::value::ToValue;
+
+/// Write a formatted string to a stream.
+///
+/// # Description
+///
+/// Write a string produced according to format to the stream resource specified by handle.
+///
+/// Returns the length of the string written, in bytes.
+///
+/// # Errors
+///
+/// Returns the errors of sprintf(), and PhpError::Warning if writing fails.
+///
+/// # Examples
+///
+/// Example #1 fprintf(): zero-padded integers
+///
+/// ```
+/// use phpify::string::fprintf;
+///
+/// let mut fp = Vec::new();
+///
+/// assert_eq!(fprintf(&mut fp, "%04d-%02d-%02d", &[&2020, &4, &9]).unwrap(), 10);
+/// assert_eq!(fp, b"2020-04-09");
+/// ```
+pub fn fprintf<W, F>(handle: &mut W, format: F, values: &[&dyn ToValue]) -> Result<usize, PhpError>
+    where
+        W: Write + ?Sized,
+        F: AsRef<[u8]> {
+
+    let string = sprintf(format, values)?;
+    handle.write_all(&string).map_err(|error| {
+        PhpError::Warning(format!("fprintf(): Write of {} bytes failed: {}", string.len(), error))
+    })?;
+
+    Ok(string.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::error::PhpError;
+    use crate::string::fprintf;
+
+    struct Broken;
+
+    impl Write for Broken {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "broken"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test() {
+        let mut buffer = Vec::new();
+        assert_eq!(fprintf(&mut buffer, "%s\u{e9}", &[&"a"]), Ok(3));
+        assert_eq!(buffer, "a\u{e9}".as_bytes());
+        assert_eq!(fprintf(&mut buffer, "%c", &[&255]), Ok(1));
+        assert_eq!(buffer, b"a\xc3\xa9\xff");
+
+        assert_eq!(fprintf(&mut Broken, "a", &[]), Err(PhpError::Warning("fprintf(): Write of 1 bytes failed: broken".to_string())));
+        assert_eq!(fprintf(&mut buffer, "%s", &[]), Err(PhpError::ArgumentCountError("2 arguments are required, 1 given".to_string())));
+    }
+}
@@ -17,6 +17,8 @@
 
 // https://www.php.net/manual/en/ref.strings.php
 
+use crate::string::PhpString;
+
 /// Join vec elements with a string.
 ///
 /// # Description
@@ -35,11 +37,14 @@
 ///
 /// assert_eq!(comma_separated, "lastname,email,phone");
 /// ```
-pub fn implode<G>(glue: G, pieces: &Vec<String>) -> String
+pub fn implode<G, P>(glue: G, pieces: &[P]) -> PhpString
     where
-        G: AsRef<str> {
+        G: AsRef<[u8]>,
+        P: AsRef<[u8]> {
+
+    let pieces: Vec<&[u8]> = pieces.iter().map(AsRef::as_ref).collect();
 
-    pieces.join(glue.as_ref())
+    PhpString::from(pieces.join(glue.as_ref()))
 }
 
 #[cfg(test)]
@@ -48,8 +53,9 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(implode("|", &vec!["one".to_string(), "two".to_string(), "three".to_string()]), "one|two|three".to_string());
-        assert_eq!(implode("", &vec!["one".to_string(), "two".to_string(), "three".to_string()]), "onetwothree".to_string());
-        assert_eq!(implode("", &vec![]), "".to_string());
+        assert_eq!(implode("|", &["one".to_string(), "two".to_string(), "three".to_string()]), "one|two|three");
+        assert_eq!(implode("", &["one".to_string(), "two".to_string(), "three".to_string()]), "onetwothree");
+        assert_eq!(implode("", &[] as &[&str]), "");
+        assert_eq!(implode(b"\xff", &["a", "b"]).as_bytes(), b"a\xffb");
     }
 }
@@ -15,14 +15,16 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::string::PhpString;
+
 /// Make a string's first character lowercase
 ///
 /// # Description
 ///
 /// Returns a string with the first character of str lowercased if that character is alphabetic.
 ///
-/// Note that 'alphabetic' is determined by the current locale. For instance, in the default "C"
-/// locale characters such as umlaut-a (ä) will not be converted.
+/// Only the ASCII letters a-z and A-Z are converted, like PHP 8 does regardless of the locale. For
+/// instance, characters such as umlaut-a (ä) will not be converted.
 ///
 /// # Examples
 ///
@@ -37,16 +39,17 @@
 /// let bar = "HELLO WORLD!";
 /// assert_eq!(lcfirst(bar), "hELLO WORLD!");
 /// ```
-pub fn lcfirst<S>(str: S) -> String
+pub fn lcfirst<S>(str: S) -> PhpString
     where
-        S: AsRef<str> {
+        S: AsRef<[u8]> {
 
-    let mut chars = str.as_ref().chars();
+    let mut bytes = str.as_ref().to_vec();
 
-    match chars.next() {
-        None => String::new(),
-        Some(char) => char.to_lowercase().collect::<String>() + chars.as_str(),
+    if let Some(byte) = bytes.first_mut() {
+        *byte = byte.to_ascii_lowercase();
     }
+
+    PhpString::from(bytes)
 }
 
 #[cfg(test)]
@@ -56,7 +59,8 @@ mod tests {
     #[test]
     fn test() {
         assert_eq!(lcfirst("ABC"), "aBC");
-        assert_eq!(lcfirst("ÄBC"), "äBC");
-        assert_eq!(lcfirst("ΔΞΠ"), "δΞΠ");
+        assert_eq!(lcfirst("ÄBC"), "ÄBC");
+        assert_eq!(lcfirst("ΔΞΠ"), "ΔΞΠ");
+        assert_eq!(lcfirst(""), "");
     }
 }
@@ -15,20 +15,45 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+mod constants;
 mod explode;
+mod format;
+mod fprintf;
 mod implode;
 mod lcfirst;
+mod natural;
+mod php_string;
+mod printf;
+mod sprintf;
+mod sscanf;
+mod str_shuffle;
 mod stripos;
 mod strlen;
+mod strnatcasecmp;
+mod strnatcmp;
 mod strpos;
 mod substr;
 mod ucfirst;
+mod vsprintf;
 
+pub(crate) use natural::compare_natural;
+pub(crate) use strpos::offset_in;
+
+pub use constants::{STR_PAD_BOTH, STR_PAD_LEFT, STR_PAD_RIGHT};
 pub use explode::explode;
+pub use fprintf::fprintf;
 pub use implode::implode;
 pub use lcfirst::lcfirst;
+pub use php_string::PhpString;
+pub use printf::printf;
+pub use sprintf::sprintf;
+pub use sscanf::sscanf;
+pub use str_shuffle::str_shuffle;
 pub use stripos::stripos;
 pub use strlen::strlen;
+pub use strnatcasecmp::strnatcasecmp;
+pub use strnatcmp::strnatcmp;
 pub use strpos::strpos;
 pub use substr::substr;
 pub use ucfirst::ucfirst;
+pub use vsprintf::vsprintf;
@@ -0,0 +1,181 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+
+/// Compares strings using a "natural order" algorithm, the way PHP's strnatcmp_ex() does.
+///
+/// Runs of digits are compared by their numeric value, leading zeros and whitespace are skipped.
+/// Runs starting with a zero are compared as fractional parts, digit by digit. When fold_case is
+/// true, ASCII letters are compared case insensitively.
+pub(crate) fn compare_natural(a: &[u8], b: &[u8], fold_case: bool) -> Ordering {
+    if a.is_empty() || b.is_empty() {
+        return a.len().cmp(&b.len());
+    }
+
+    // Like the C implementation, reading past the end yields a NUL byte.
+    let at = |string: &[u8], index: usize| string.get(index).copied().unwrap_or(0);
+
+    let mut ai = 0;
+    let mut bi = 0;
+    let mut leading = true;
+
+    loop {
+        if leading {
+            while at(a, ai) == b'0' && at(a, ai + 1).is_ascii_digit() && ai + 1 < a.len() {
+                ai += 1;
+            }
+
+            while at(b, bi) == b'0' && at(b, bi + 1).is_ascii_digit() && bi + 1 < b.len() {
+                bi += 1;
+            }
+
+            leading = false;
+        }
+
+        while is_space(at(a, ai)) {
+            ai += 1;
+        }
+
+        while is_space(at(b, bi)) {
+            bi += 1;
+        }
+
+        let mut ca = at(a, ai);
+        let mut cb = at(b, bi);
+
+        if ca.is_ascii_digit() && cb.is_ascii_digit() {
+            let fractional = ca == b'0' || cb == b'0';
+
+            let ordering = if fractional {
+                compare_left(a, &mut ai, b, &mut bi)
+            } else {
+                compare_right(a, &mut ai, b, &mut bi)
+            };
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+
+            match (ai >= a.len(), bi >= b.len()) {
+                (true, true) => return Ordering::Equal,
+                (true, false) => return Ordering::Less,
+                (false, true) => return Ordering::Greater,
+                (false, false) => {
+                    ca = at(a, ai);
+                    cb = at(b, bi);
+                },
+            }
+        }
+
+        if fold_case {
+            ca = ca.to_ascii_uppercase();
+            cb = cb.to_ascii_uppercase();
+        }
+
+        if ca != cb {
+            return ca.cmp(&cb);
+        }
+
+        ai += 1;
+        bi += 1;
+
+        match (ai >= a.len(), bi >= b.len()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {},
+        }
+    }
+}
+
+/// Compares two right-aligned numbers: the longest run of digits wins, otherwise the first
+/// difference decides.
+fn compare_right(a: &[u8], ai: &mut usize, b: &[u8], bi: &mut usize) -> Ordering {
+    let mut bias = Ordering::Equal;
+
+    loop {
+        let da = a.get(*ai).is_some_and(u8::is_ascii_digit);
+        let db = b.get(*bi).is_some_and(u8::is_ascii_digit);
+
+        match (da, db) {
+            (false, false) => return bias,
+            (false, true) => return Ordering::Less,
+            (true, false) => return Ordering::Greater,
+            (true, true) => {
+                if bias == Ordering::Equal {
+                    bias = a[*ai].cmp(&b[*bi]);
+                }
+            },
+        }
+
+        *ai += 1;
+        *bi += 1;
+    }
+}
+
+/// Compares two left-aligned numbers: the first to have a different value wins.
+fn compare_left(a: &[u8], ai: &mut usize, b: &[u8], bi: &mut usize) -> Ordering {
+    loop {
+        let da = a.get(*ai).is_some_and(u8::is_ascii_digit);
+        let db = b.get(*bi).is_some_and(u8::is_ascii_digit);
+
+        match (da, db) {
+            (false, false) => return Ordering::Equal,
+            (false, true) => return Ordering::Less,
+            (true, false) => return Ordering::Greater,
+            (true, true) => {
+                if a[*ai] != b[*bi] {
+                    return a[*ai].cmp(&b[*bi]);
+                }
+            },
+        }
+
+        *ai += 1;
+        *bi += 1;
+    }
+}
+
+/// Returns true for the bytes C's isspace() accepts.
+fn is_space(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\x0b' | b'\x0c' | b'\r')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::string::natural::compare_natural;
+
+    #[test]
+    fn test() {
+        assert_eq!(compare_natural(b"img12.png", b"img10.png", false), Ordering::Greater);
+        assert_eq!(compare_natural(b"img2.png", b"img10.png", false), Ordering::Less);
+        assert_eq!(compare_natural(b"IMG2", b"img10", true), Ordering::Less);
+        assert_eq!(compare_natural(b"IMG2", b"img10", false), Ordering::Less);
+        assert_eq!(compare_natural(b"a", b"B", true), Ordering::Less);
+        assert_eq!(compare_natural(b"a", b"B", false), Ordering::Greater);
+        assert_eq!(compare_natural(b"0.12", b"0.5", false), Ordering::Greater);
+        assert_eq!(compare_natural(b"0.05", b"0.5", false), Ordering::Less);
+        assert_eq!(compare_natural(b"007", b"7", false), Ordering::Equal);
+        assert_eq!(compare_natural(b"x 1", b"x1", false), Ordering::Equal);
+        assert_eq!(compare_natural(b"", b"a", false), Ordering::Less);
+        assert_eq!(compare_natural(b"a", b"", false), Ordering::Greater);
+        assert_eq!(compare_natural(b"abc", b"abcd", false), Ordering::Less);
+        assert_eq!(compare_natural(b"1", b"1a", false), Ordering::Less);
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::fmt;
+use std::ops::Deref;
+use std::str::Utf8Error;
+use std::string::FromUtf8Error;
+
+/// A binary-safe string, the way PHP implements its strings.
+///
+/// # Description
+///
+/// A PhpString is a sequence of bytes without any encoding, so it can hold data that is not valid
+/// UTF-8. Lengths and offsets of the string functions are counted in bytes, like PHP does.
+///
+/// The string functions accept anything that can be viewed as bytes, such as &str, String, &[u8],
+/// Vec<u8> and PhpString itself. The result can be turned back into a &str or String when it is
+/// valid UTF-8.
+///
+/// # Examples
+///
+/// Example #1 A simple string
+///
+/// ```
+/// use phpify::string::{strlen, PhpString};
+///
+/// let string = PhpString::from("héllo");
+///
+/// assert_eq!(strlen(&string), 6);
+/// assert_eq!(string, "héllo");
+/// assert_eq!(string.to_str().unwrap(), "héllo");
+/// ```
+///
+/// Example #2 Binary data
+///
+/// ```
+/// use phpify::string::PhpString;
+///
+/// let string = PhpString::from(vec![0x61, 0xff, 0x62]);
+///
+/// assert!(string.to_str().is_err());
+/// assert_eq!(string.to_string_lossy(), "a\u{fffd}b");
+/// assert_eq!(format!("{:?}", string), "\"a\\xFFb\"");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PhpString(Vec<u8>);
+
+impl PhpString {
+    /// Creates an empty string.
+    pub fn new() -> Self {
+        PhpString(Vec::new())
+    }
+
+    /// Returns the number of bytes in the string.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the string contains no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the bytes of the string.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Converts the string into its bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// Returns the string as a &str, if it is valid UTF-8.
+    pub fn to_str(&self) -> Result<&str, Utf8Error> {
+        std::str::from_utf8(&self.0)
+    }
+
+    /// Converts the string into a String, if it is valid UTF-8.
+    pub fn into_string(self) -> Result<String, FromUtf8Error> {
+        String::from_utf8(self.0)
+    }
+
+    /// Converts the string into a String, replacing invalid UTF-8 sequences with U+FFFD.
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf8_lossy(&self.0).into_owned()
+    }
+}
+
+impl fmt::Debug for PhpString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+
+        for chunk in self.0.utf8_chunks() {
+            for char in chunk.valid().chars() {
+                write!(f, "{}", char.escape_debug())?;
+            }
+
+            for byte in chunk.invalid() {
+                write!(f, "\\x{:02X}", byte)?;
+            }
+        }
+
+        f.write_str("\"")
+    }
+}
+
+impl fmt::Display for PhpString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&String::from_utf8_lossy(&self.0))
+    }
+}
+
+impl Deref for PhpString {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for PhpString {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<&str> for PhpString {
+    fn from(string: &str) -> Self {
+        PhpString(string.as_bytes().to_vec())
+    }
+}
+
+impl From<String> for PhpString {
+    fn from(string: String) -> Self {
+        PhpString(string.into_bytes())
+    }
+}
+
+impl From<&[u8]> for PhpString {
+    fn from(bytes: &[u8]) -> Self {
+        PhpString(bytes.to_vec())
+    }
+}
+
+impl From<Vec<u8>> for PhpString {
+    fn from(bytes: Vec<u8>) -> Self {
+        PhpString(bytes)
+    }
+}
+
+impl From<PhpString> for Vec<u8> {
+    fn from(string: PhpString) -> Self {
+        string.0
+    }
+}
+
+impl PartialEq<str> for PhpString {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other.as_bytes()
+    }
+}
+
+impl PartialEq<&str> for PhpString {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == other.as_bytes()
+    }
+}
+
+impl PartialEq<String> for PhpString {
+    fn eq(&self, other: &String) -> bool {
+        self.0 == other.as_bytes()
+    }
+}
+
+impl PartialEq<[u8]> for PhpString {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<PhpString> for str {
+    fn eq(&self, other: &PhpString) -> bool {
+        self.as_bytes() == other.0
+    }
+}
+
+impl PartialEq<PhpString> for &str {
+    fn eq(&self, other: &PhpString) -> bool {
+        self.as_bytes() == other.0
+    }
+}
+
+impl PartialEq<PhpString> for String {
+    fn eq(&self, other: &PhpString) -> bool {
+        self.as_bytes() == other.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::string::PhpString;
+
+    #[test]
+    fn test() {
+        let string = PhpString::from("abc");
+        assert_eq!(string, "abc");
+        assert_eq!("abc", string);
+        assert_eq!(string, "abc".to_string());
+        assert_eq!(string.len(), 3);
+        assert_eq!(string[1], b'b');
+        assert_eq!(string.clone().into_string(), Ok("abc".to_string()));
+
+        let binary = PhpString::from(&b"\xe9t\xe9"[..]);
+        assert!(binary.clone().into_string().is_err());
+        assert_eq!(binary.to_string(), "\u{fffd}t\u{fffd}");
+        assert_eq!(format!("{:?}", binary), "\"\\xE9t\\xE9\"");
+        assert_eq!(format!("{:?}", PhpString::from("\"é\n")), "\"\\\"é\\n\"");
+
+        assert!(PhpString::new().is_empty());
+        let (a, b) = (PhpString::from("a"), PhpString::from("b"));
+        assert!(a < b);
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.strings.php
+
+use std::io;
+
+use crate::error::PhpError;
+use crate::string::fprintf;
+use crate::value::ToValue;
+
+/// Output a formatted string.
+///
+/// # Description
+///
+/// Produces output to stdout according to format.
+///
+/// Returns the length of the outputted string, in bytes.
+///
+/// # Errors
+///
+/// Returns the errors of sprintf(), and PhpError::Warning if writing fails.
+///
+/// # Examples
+///
+/// Example #1 printf(): various examples
+///
+/// ```
+/// use phpify::string::printf;
+///
+/// let n = 43951789;
+///
+/// assert_eq!(printf("%%d = '%d'\n", &[&n]).unwrap(), 16);
+/// ```
+pub fn printf<F>(format: F, values: &[&dyn ToValue]) -> Result<usize, PhpError>
+    where
+        F: AsRef<[u8]> {
+
+    fprintf(&mut io::stdout(), format, values)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::string::printf;
+
+    #[test]
+    fn test() {
+        assert_eq!(printf("", &[]), Ok(0));
+        assert_eq!(printf("%s", &[]), Err(PhpError::ArgumentCountError("2 arguments are required, 1 given".to_string())));
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.strings.php
+
+use crate::error::PhpError;
+use crate::string::format::format;
+use crate::string::PhpString;
+use crate::value::{ToValue, Value};
+
+/// Return a formatted string.
+///
+/// # Description
+///
+/// Returns a string produced according to the formatting string format.
+///
+/// The format is read byte for byte, so it need not be valid UTF-8. The result is a PhpString,
+/// because %c and the precision of %s produce bytes that need not be valid UTF-8 either.
+///
+/// # Parameters
+///
+/// **format**
+///
+/// The format string is composed of zero or more directives: ordinary characters (excluding %) that
+/// are copied directly to the result and conversion specifications, each of which results in
+/// fetching its own parameter.
+///
+/// A conversion specification follows this prototype: %[argnum$][flags][width][.precision]specifier.
+///
+/// - argnum - an integer followed by a dollar sign $, to specify which number argument to treat in
+///   the conversion.
+/// - flags - - (left-justify within the given field width), + (prefix positive numbers with a plus
+///   sign), space (pads the result with spaces, the default), 0 (only left-pads numbers with zeros,
+///   with s specifiers this can also right-pad with zeros) and '(char) (pads the result with the
+///   single byte that follows).
+/// - width - an integer that says how many characters (minimum) this conversion should result in,
+///   or * to take it from the arguments.
+/// - precision - a period . followed by an integer, or * to take it from the arguments. For e, E,
+///   f and F specifiers this is the number of digits to be printed after the decimal point (by
+///   default, this is 6). For g, G, h and H specifiers this is the maximum number of significant
+///   digits to be printed. For the s specifier it acts as a cutoff point, setting a maximum
+///   character limit to the string.
+/// - specifier - % (a literal percent character), b (binary), c (the character with that ASCII
+///   value), d (signed decimal), e and E (scientific notation), f and F (float), g, G, h and H
+///   (general format), o (octal), s (string), u (unsigned decimal), x and X (hexadecimal).
+///
+/// **values**
+///
+/// The arguments, converted to the type the specifier requires following PHP's type juggling.
+///
+/// # Errors
+///
+/// Returns PhpError::ArgumentCountError if the format refers to more arguments than given, and
+/// PhpError::ValueError if the format is invalid.
+///
+/// # Examples
+///
+/// Example #1 Argument swapping
+///
+/// ```
+/// use phpify::string::sprintf;
+///
+/// let num = 5;
+/// let location = "tree";
+///
+/// let format = "The %2$s contains %1$d monkeys. That's a nice %2$s full of %1$d monkeys.";
+/// assert_eq!(sprintf(format, &[&num, &location]).unwrap(), "The tree contains 5 monkeys. That's a nice tree full of 5 monkeys.");
+/// ```
+///
+/// Example #2 Specifying padding character
+///
+/// ```
+/// use phpify::string::sprintf;
+///
+/// assert_eq!(sprintf("%'.10d", &[&42]).unwrap(), "........42");
+/// assert_eq!(sprintf("%-10d", &[&42]).unwrap(), "42        ");
+/// assert_eq!(sprintf("%'*10s", &[&"monkey"]).unwrap(), "****monkey");
+/// assert_eq!(sprintf("%-'*10s", &[&"monkey"]).unwrap(), "monkey****");
+/// ```
+///
+/// Example #3 Formatting numbers
+///
+/// ```
+/// use phpify::string::sprintf;
+///
+/// assert_eq!(sprintf("%04d-%02d-%02d", &[&2020, &4, &9]).unwrap(), "2020-04-09");
+/// assert_eq!(sprintf("%01.2f", &[&123.1]).unwrap(), "123.10");
+/// assert_eq!(sprintf("%+d", &[&5]).unwrap(), "+5");
+/// assert_eq!(sprintf("%b", &[&5]).unwrap(), "101");
+/// assert_eq!(sprintf("%e", &[&362525200]).unwrap(), "3.625252e+8");
+/// assert_eq!(sprintf("%u", &[&-1]).unwrap(), "18446744073709551615");
+/// ```
+pub fn sprintf<F>(format_string: F, values: &[&dyn ToValue]) -> Result<PhpString, PhpError>
+    where
+        F: AsRef<[u8]> {
+
+    let values: Vec<Value> = values.iter().map(|value| value.to_value()).collect();
+
+    format(format_string.as_ref(), &values, |required, given| {
+        PhpError::ArgumentCountError(format!("{} arguments are required, {} given", required + 1, given + 1))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::string::{sprintf, PhpString};
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        assert_eq!(sprintf("abc", &[]), Ok("abc".into()));
+        assert_eq!(sprintf("100%%", &[]), Ok("100%".into()));
+        assert_eq!(sprintf("%s-%s", &[&"a", &1.5]), Ok("a-1.5".into()));
+        assert_eq!(sprintf("%s", &[&Value::Null]), Ok("".into()));
+        assert_eq!(sprintf("%5s|%-5s|%05s|%-05s", &[&"ab", &"ab", &"ab", &"ab"]), Ok("   ab|ab   |000ab|ab000".into()));
+        assert_eq!(sprintf("%.2s|%.s|%.0s|%5.1s", &[&"abc", &"abc", &"abc", &"abc"]), Ok("ab|abc||    a".into()));
+        assert_eq!(sprintf("%.1s", &[&"\u{e9}"]), Ok(b"\xc3"[..].into()));
+        assert_eq!(sprintf("[%3s]", &[&PhpString::from(&b"\xff"[..])]), Ok(b"[  \xff]"[..].into()));
+        assert_eq!(sprintf(b"\xfe%s\xff", &[&"a"]), Ok(b"\xfea\xff"[..].into()));
+        assert_eq!(sprintf(b"%'\xff4d|%-'\xc33s", &[&1, &"a"]), Ok(b"\xff\xff\xff1|a\xc3\xc3"[..].into()));
+
+        assert_eq!(sprintf("%d|%d|%d|%d", &[&"12abc", &3.99, &true, &-7]), Ok("12|3|1|-7".into()));
+        assert_eq!(sprintf("%05d|%-05d|%+05d|%+d|%+d", &[&-12, &-12, &12, &0, &-3]), Ok("-0012|-12  |+0012|+0|-3".into()));
+        assert_eq!(sprintf("%'x8d|%'x-8d", &[&123, &123]), Ok("xxxxx123|123xxxxx".into()));
+        assert_eq!(sprintf("%u|%5u", &[&3, &-3]), Ok("3|18446744073709551613".into()));
+        assert_eq!(sprintf("%c%c%c", &[&80, &72, &80]), Ok("PHP".into()));
+        assert_eq!(sprintf("%c|%c|%c", &[&200, &0, &321]), Ok(b"\xc8|\0|A"[..].into()));
+        assert_eq!(sprintf("%b|%o|%x|%X|%08b", &[&10, &8, &255, &255, &5]), Ok("1010|10|ff|FF|00000101".into()));
+        assert_eq!(sprintf("%x", &[&-1]), Ok("ffffffffffffffff".into()));
+        assert_eq!(sprintf("%.2x|%4.2x", &[&255, &255]), Ok("|    ".into()));
+
+        assert_eq!(sprintf("%f|%.2f|%.0f|%.1f", &[&1.5, &1.005, &2.6, &0.05]), Ok("1.500000|1.00|3|0.1".into()));
+        assert_eq!(sprintf("%-08.3f|%08.3f|%+.1f|%+08.2f", &[&1.23456, &-1.23456, &2, &-1.5]), Ok("1.235000|-001.235|+2.0|-0001.50".into()));
+        assert_eq!(sprintf("%.2f|%f", &[&-0.001, &-0.0]), Ok("-0.00|0.000000".into()));
+        assert_eq!(sprintf("%e|%.2E|%.0e|%e", &[&1234.5678, &0.000123, &5, &0]), Ok("1.234568e+3|1.23E-4|5e+0|0.000000e+0".into()));
+        assert_eq!(sprintf("%g|%G|%g|%.3g|%g", &[&0.00001234, &1e20, &100000, &1234.5, &1000000]), Ok("1.234e-5|1.0E+20|100000|1.23e+3|1.0e+6".into()));
+        assert_eq!(sprintf("%f|%f|%+f|%5f|%05f", &[&f64::NAN, &f64::NEG_INFINITY, &f64::INFINITY, &f64::INFINITY, &f64::NAN]), Ok("NaN|-Inf|+Inf|Inf|NaN".into()));
+        assert_eq!(sprintf("%.60f", &[&0.1]), Ok(format!("{:.53}", 0.1).into()));
+
+        assert_eq!(sprintf("%*d|%-*s|%.*f|%.*s", &[&5, &42, &4, &"ab", &1, &2.26, &2, &"abc"]), Ok("   42|ab  |2.3|ab".into()));
+        assert_eq!(sprintf("%.*g|%.*H", &[&-1, &0.1, &-1, &1e100]), Ok("0.1|1.0E+100".into()));
+        assert_eq!(sprintf("%1$s %1$'#5s %s", &[&"a"]), Ok("a ####a a".into()));
+        assert_eq!(sprintf("%ld", &[&5]), Ok("5".into()));
+
+        assert_eq!(sprintf("%d %d", &[&1]), Err(PhpError::ArgumentCountError("3 arguments are required, 2 given".into())));
+        assert_eq!(sprintf("%3$d %d", &[&1]), Err(PhpError::ArgumentCountError("4 arguments are required, 2 given".into())));
+        assert_eq!(sprintf("%*d", &[&1]), Err(PhpError::ArgumentCountError("3 arguments are required, 2 given".into())));
+        assert_eq!(sprintf("%", &[&1]), Err(PhpError::ValueError("Missing format specifier at end of string".into())));
+        assert_eq!(sprintf("%y", &[&1]), Err(PhpError::ValueError("Unknown format specifier \"y\"".into())));
+        assert_eq!(sprintf("%'", &[&1]), Err(PhpError::ValueError("Missing padding character".into())));
+        assert_eq!(sprintf("%'\u{e9}5d", &[&1]), Err(PhpError::ValueError("Unknown format specifier \"\u{fffd}\"".into())));
+        assert_eq!(sprintf("%0$s", &[&1]), Err(PhpError::ValueError("Argument number specifier must be greater than zero and less than 2147483647".into())));
+        assert_eq!(sprintf("%*d", &[&"a", &1]), Err(PhpError::ValueError("Width must be an integer".into())));
+        assert_eq!(sprintf("%*d", &[&-1, &1]), Err(PhpError::ValueError("Width must be greater than or equal to zero and less than 2147483647".into())));
+        assert_eq!(sprintf("%.*f", &[&-1, &1]), Err(PhpError::ValueError("Precision -1 is only supported for %g, %G, %h and %H".into())));
+        assert_eq!(sprintf("%.*f", &[&-2, &1]), Err(PhpError::ValueError("Precision must be between -1 and 2147483647".into())));
+        assert_eq!(sprintf("%99999999999d", &[&1]), Err(PhpError::ValueError("Width must be greater than zero and less than 2147483647".into())));
+    }
+}
@@ -0,0 +1,486 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.strings.php
+
+use crate::error::PhpError;
+use crate::value::Value;
+
+/// A parsed element of a scan format.
+enum Directive {
+    Whitespace,
+    Literal(char),
+    Conversion {
+        index: Option<usize>,
+        width: usize,
+        specifier: char,
+        set: Option<CharSet>,
+    },
+}
+
+/// The characters a %[...] conversion matches.
+struct CharSet {
+    negated: bool,
+    chars: Vec<char>,
+    ranges: Vec<(char, char)>,
+}
+
+impl CharSet {
+    fn matches(&self, char: char) -> bool {
+        let found = self.chars.contains(&char) || self.ranges.iter().any(|(from, to)| (*from..=*to).contains(&char));
+
+        found != self.negated
+    }
+}
+
+fn value_error(message: &str) -> PhpError {
+    PhpError::ValueError(message.to_string())
+}
+
+/// Parses input from a string according to a format.
+///
+/// # Description
+///
+/// Reads from the string string and interprets it according to the specified format. Any
+/// whitespace in the format string matches any whitespace in the input string, which means even a
+/// tab (\t) in the format string can match a single space character in the input string.
+///
+/// Returns the parsed values in the order of the conversions, the values of conversions that were
+/// not reached being null. If the input ends before the first conversion, None is returned.
+///
+/// # Parameters
+///
+/// **format**
+///
+/// The format string is composed of whitespace, ordinary characters that must match the input and
+/// conversion specifications: %[argnum$][*][width]specifier. A * suppresses the assignment of the
+/// conversion, the width limits the number of characters read.
+///
+/// The specifiers are c (a single character), d (a decimal integer), i (an integer in decimal,
+/// octal with a leading 0 or hexadecimal with a leading 0x), o (an octal integer), u (an unsigned
+/// decimal integer), x and X (a hexadecimal integer), e, E, f and g (a float), s (a string up to
+/// the next whitespace), [...] (a string of the characters in the brackets, or not in the brackets
+/// if the first is ^) and n (the number of bytes read so far).
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if the format is invalid.
+///
+/// # Examples
+///
+/// Example #1 sscanf() Example
+///
+/// ```
+/// use phpify::string::sscanf;
+/// use phpify::value::Value;
+///
+/// // getting the serial number
+/// let serial = sscanf("SN/2350001", "SN/%d").unwrap().unwrap();
+/// assert_eq!(serial, [Value::Int(2350001)]);
+///
+/// // and the date of manufacturing
+/// let date = sscanf("January 01 2000", "%s %d %d").unwrap().unwrap();
+/// assert_eq!(date, [Value::from("January"), Value::Int(1), Value::Int(2000)]);
+/// ```
+pub fn sscanf(string: &str, format: &str) -> Result<Option<Vec<Value>>, PhpError> {
+    let (directives, count) = parse_format(format)?;
+
+    let mut values = vec![Value::Null; count];
+    let mut position = 0;
+    let mut conversions = 0;
+    let mut underflow = false;
+
+    for directive in &directives {
+        match directive {
+            Directive::Whitespace => position = skip_whitespace(string, position),
+            Directive::Literal(char) => match string[position..].chars().next() {
+                None => {
+                    underflow = true;
+                    break;
+                },
+                Some(next) if next == *char => position += char.len_utf8(),
+                Some(_) => break,
+            },
+            Directive::Conversion { index, width, specifier, set } => {
+                if *specifier == 'n' {
+                    if let Some(index) = index {
+                        values[*index] = Value::Int(position as i64);
+                    }
+                    conversions += 1;
+                    continue;
+                }
+
+                if *specifier != 'c' && *specifier != '[' {
+                    position = skip_whitespace(string, position);
+                }
+
+                if position == string.len() {
+                    underflow = true;
+                    break;
+                }
+
+                let limit = if *width == 0 { usize::MAX } else { *width };
+                let rest = &string[position..];
+
+                let (value, length) = match specifier {
+                    'c' => {
+                        let char = rest.chars().next().expect("input left");
+                        (Value::from(char.to_string()), char.len_utf8())
+                    },
+                    's' => {
+                        let length = take_while(rest, limit, |char| !char.is_whitespace());
+                        (Value::from(&rest[..length]), length)
+                    },
+                    '[' => {
+                        let set = set.as_ref().expect("character set");
+                        let length = take_while(rest, limit, |char| set.matches(char));
+                        if length == 0 {
+                            break;
+                        }
+                        (Value::from(&rest[..length]), length)
+                    },
+                    'e' | 'E' | 'f' | 'g' => match scan_float(rest, limit) {
+                        Some((float, length)) => (Value::Float(float), length),
+                        None => break,
+                    },
+                    _ => {
+                        let radix = match specifier {
+                            'o' => 8,
+                            'x' | 'X' => 16,
+                            'i' => 0,
+                            _ => 10,
+                        };
+
+                        match scan_int(rest, limit, radix) {
+                            Some((int, length)) if *specifier == 'u' && int < 0 => (Value::from((int as u64).to_string()), length),
+                            Some((int, length)) => (Value::Int(int), length),
+                            None => break,
+                        }
+                    },
+                };
+
+                if let Some(index) = index {
+                    values[*index] = value;
+                }
+
+                position += length;
+                conversions += 1;
+            },
+        }
+    }
+
+    if underflow && conversions == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(values))
+}
+
+/// Parses the format into directives, returning them with the number of values they assign.
+fn parse_format(format: &str) -> Result<(Vec<Directive>, usize), PhpError> {
+    let chars: Vec<char> = format.chars().collect();
+    let mut directives = Vec::new();
+    let mut position = 0;
+    let mut next_index = 0;
+    let mut uses_positional = false;
+    let mut uses_sequential = false;
+    let mut assigned: Vec<bool> = Vec::new();
+
+    while position < chars.len() {
+        let char = chars[position];
+        position += 1;
+
+        if char.is_whitespace() {
+            directives.push(Directive::Whitespace);
+            continue;
+        }
+
+        if char != '%' {
+            directives.push(Directive::Literal(char));
+            continue;
+        }
+
+        if chars.get(position) == Some(&'%') {
+            directives.push(Directive::Literal('%'));
+            position += 1;
+            continue;
+        }
+
+        let mut index = None;
+
+        if chars.get(position) == Some(&'*') {
+            position += 1;
+        } else {
+            let digits = chars[position..].iter().take_while(|char| char.is_ascii_digit()).count();
+
+            if digits > 0 && chars.get(position + digits) == Some(&'$') {
+                let argnum: usize = chars[position..position + digits].iter().collect::<String>().parse().unwrap_or(0);
+                if argnum == 0 {
+                    return Err(value_error("\"%n$\" argument index out of range"));
+                }
+
+                index = Some(argnum - 1);
+                uses_positional = true;
+                position += digits + 1;
+            } else {
+                index = Some(next_index);
+                next_index += 1;
+                uses_sequential = true;
+            }
+
+            if uses_positional && uses_sequential {
+                return Err(value_error("cannot mix \"%\" and \"%n$\" conversion specifiers"));
+            }
+        }
+
+        let digits = chars[position..].iter().take_while(|char| char.is_ascii_digit()).count();
+        let width = chars[position..position + digits].iter().collect::<String>().parse().unwrap_or(0);
+        position += digits;
+
+        if let Some('l') | Some('L') | Some('h') = chars.get(position) {
+            position += 1;
+        }
+
+        let specifier = chars.get(position).copied();
+        position += 1;
+
+        let mut set = None;
+
+        match specifier {
+            Some('n') | Some('d') | Some('D') | Some('i') | Some('o') | Some('x') | Some('X') | Some('u')
+            | Some('f') | Some('e') | Some('E') | Some('g') | Some('s') => {},
+            Some('c') => {
+                if digits > 0 {
+                    return Err(value_error("Field width may not be specified in %c conversion"));
+                }
+            },
+            Some('[') => set = Some(parse_set(&chars, &mut position)?),
+            _ => {
+                let specifier = specifier.map(String::from).unwrap_or_default();
+                return Err(PhpError::ValueError(format!("Bad scan conversion character \"{}\"", specifier)));
+            },
+        }
+
+        if let Some(index) = index {
+            if assigned.len() <= index {
+                assigned.resize(index + 1, false);
+            }
+
+            if assigned[index] {
+                return Err(value_error("Variable is assigned by multiple \"%n$\" conversion specifiers"));
+            }
+
+            assigned[index] = true;
+        }
+
+        directives.push(Directive::Conversion {
+            index,
+            width,
+            specifier: specifier.expect("specifier"),
+            set,
+        });
+    }
+
+    Ok((directives, assigned.len()))
+}
+
+/// Parses the characters of a %[...] conversion, after the opening bracket.
+fn parse_set(chars: &[char], position: &mut usize) -> Result<CharSet, PhpError> {
+    let mut set = CharSet {
+        negated: false,
+        chars: Vec::new(),
+        ranges: Vec::new(),
+    };
+
+    if chars.get(*position) == Some(&'^') {
+        set.negated = true;
+        *position += 1;
+    }
+
+    if chars.get(*position) == Some(&']') {
+        set.chars.push(']');
+        *position += 1;
+    }
+
+    loop {
+        match chars.get(*position) {
+            None => return Err(value_error("Unmatched [ in format string")),
+            Some(']') => {
+                *position += 1;
+                return Ok(set);
+            },
+            Some(&from) => {
+                if chars.get(*position + 1) == Some(&'-') && chars.get(*position + 2).is_some_and(|to| *to != ']') {
+                    let to = chars[*position + 2];
+                    set.ranges.push(if from <= to { (from, to) } else { (to, from) });
+                    *position += 3;
+                } else {
+                    set.chars.push(from);
+                    *position += 1;
+                }
+            },
+        }
+    }
+}
+
+fn skip_whitespace(string: &str, position: usize) -> usize {
+    position + string[position..].len() - string[position..].trim_start().len()
+}
+
+/// Returns the length in bytes of the longest prefix of at most limit matching characters.
+fn take_while<F>(string: &str, limit: usize, predicate: F) -> usize
+    where
+        F: Fn(char) -> bool {
+
+    string.chars().take(limit).take_while(|char| predicate(*char)).map(char::len_utf8).sum()
+}
+
+/// Scans an integer of the radix, or of the radix implied by its prefix if the radix is 0.
+fn scan_int(string: &str, limit: usize, radix: u32) -> Option<(i64, usize)> {
+    let bytes = string.as_bytes();
+    let limit = limit.min(bytes.len());
+    let mut position = 0;
+    let mut radix = radix;
+
+    if position < limit && (bytes[position] == b'-' || bytes[position] == b'+') {
+        position += 1;
+    }
+
+    let sign_length = position;
+
+    if (radix == 0 || radix == 16) && position < limit && bytes[position] == b'0' {
+        let has_prefix = position + 2 < limit
+            && (bytes[position + 1] == b'x' || bytes[position + 1] == b'X')
+            && bytes[position + 2].is_ascii_hexdigit();
+
+        if has_prefix {
+            radix = 16;
+            position += 2;
+        } else if radix == 0 {
+            radix = 8;
+        }
+    }
+
+    if radix == 0 {
+        radix = 10;
+    }
+
+    let start = position;
+    while position < limit && (bytes[position] as char).is_digit(radix) {
+        position += 1;
+    }
+
+    if position == start {
+        return None;
+    }
+
+    let negative = sign_length > 0 && bytes[0] == b'-';
+    let digits = &string[start..position];
+    let int = match u64::from_str_radix(digits, radix) {
+        Ok(magnitude) if negative => if magnitude > i64::MIN.unsigned_abs() { i64::MIN } else { (magnitude as i64).wrapping_neg() },
+        Ok(magnitude) => if magnitude > i64::MAX as u64 { i64::MAX } else { magnitude as i64 },
+        Err(_) => if negative { i64::MIN } else { i64::MAX },
+    };
+
+    Some((int, position))
+}
+
+/// Scans a float: an optional sign, digits with an optional decimal point and an optional exponent.
+fn scan_float(string: &str, limit: usize) -> Option<(f64, usize)> {
+    let bytes = string.as_bytes();
+    let limit = limit.min(bytes.len());
+    let mut position = 0;
+
+    let digits = |position: &mut usize| {
+        let start = *position;
+        while *position < limit && bytes[*position].is_ascii_digit() {
+            *position += 1;
+        }
+        *position - start
+    };
+
+    if position < limit && (bytes[position] == b'-' || bytes[position] == b'+') {
+        position += 1;
+    }
+
+    let mut mantissa_digits = digits(&mut position);
+
+    if position < limit && bytes[position] == b'.' {
+        position += 1;
+        mantissa_digits += digits(&mut position);
+    }
+
+    if mantissa_digits == 0 {
+        return None;
+    }
+
+    if position < limit && (bytes[position] == b'e' || bytes[position] == b'E') {
+        let mut exponent = position + 1;
+
+        if exponent < limit && (bytes[exponent] == b'-' || bytes[exponent] == b'+') {
+            exponent += 1;
+        }
+
+        if digits(&mut exponent) > 0 {
+            position = exponent;
+        }
+    }
+
+    string[..position].parse().ok().map(|float| (float, position))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::PhpError;
+    use crate::string::sscanf;
+    use crate::value::Value;
+
+    #[test]
+    fn test() {
+        assert_eq!(sscanf("age: 25 name: Bob", "age: %d name: %s"), Ok(Some(vec![Value::Int(25), Value::from("Bob")])));
+        assert_eq!(sscanf("12 apples", "%d %s %s"), Ok(Some(vec![Value::Int(12), Value::from("apples"), Value::Null])));
+        assert_eq!(sscanf("12", "%s-%s"), Ok(Some(vec![Value::from("12"), Value::Null])));
+        assert_eq!(sscanf("abc", "%d"), Ok(Some(vec![Value::Null])));
+        assert_eq!(sscanf("", "%d"), Ok(None));
+        assert_eq!(sscanf("   ", "%d"), Ok(None));
+        assert_eq!(sscanf("x", "x%d"), Ok(None));
+
+        assert_eq!(sscanf("-12 +7 0x1f 017 1f 17 -1", "%d %d %i %i %x %o %u"), Ok(Some(vec![
+            Value::Int(-12), Value::Int(7), Value::Int(31), Value::Int(15), Value::Int(31), Value::Int(15), Value::from("18446744073709551615"),
+        ])));
+        assert_eq!(sscanf("99999999999999999999", "%d"), Ok(Some(vec![Value::Int(i64::MAX)])));
+        assert_eq!(sscanf("1.5e3 -.5 2.", "%f %e %g"), Ok(Some(vec![Value::Float(1500.0), Value::Float(-0.5), Value::Float(2.0)])));
+        assert_eq!(sscanf("1e", "%f%s"), Ok(Some(vec![Value::Float(1.0), Value::from("e")])));
+
+        assert_eq!(sscanf("12345", "%2d%3d"), Ok(Some(vec![Value::Int(12), Value::Int(345)])));
+        assert_eq!(sscanf("hello world", "%3s%s %c"), Ok(Some(vec![Value::from("hel"), Value::from("lo"), Value::from("w")])));
+        assert_eq!(sscanf("a b", "%c%c"), Ok(Some(vec![Value::from("a"), Value::from(" ")])));
+        assert_eq!(sscanf("key=value;", "%[^=]=%[a-z]"), Ok(Some(vec![Value::from("key"), Value::from("value")])));
+        assert_eq!(sscanf("]]a", "%[]]"), Ok(Some(vec![Value::from("]]")])));
+        assert_eq!(sscanf("abc def", "%*s %s%n"), Ok(Some(vec![Value::from("def"), Value::Int(7)])));
+        assert_eq!(sscanf("a b", "%2$s %1$s"), Ok(Some(vec![Value::from("b"), Value::from("a")])));
+        assert_eq!(sscanf("100%", "%d%%"), Ok(Some(vec![Value::Int(100)])));
+        assert_eq!(sscanf("h\u{e9}llo", "%2s%s"), Ok(Some(vec![Value::from("h\u{e9}"), Value::from("llo")])));
+
+        assert_eq!(sscanf("a", "%q"), Err(PhpError::ValueError("Bad scan conversion character \"q\"".to_string())));
+        assert_eq!(sscanf("a", "%"), Err(PhpError::ValueError("Bad scan conversion character \"\"".to_string())));
+        assert_eq!(sscanf("a", "%[a"), Err(PhpError::ValueError("Unmatched [ in format string".to_string())));
+        assert_eq!(sscanf("a", "%2c"), Err(PhpError::ValueError("Field width may not be specified in %c conversion".to_string())));
+        assert_eq!(sscanf("a", "%1$s %s"), Err(PhpError::ValueError("cannot mix \"%\" and \"%n$\" conversion specifiers".to_string())));
+        assert_eq!(sscanf("a", "%1$s %1$s"), Err(PhpError::ValueError("Variable is assigned by multiple \"%n$\" conversion specifiers".to_string())));
+    }
+}
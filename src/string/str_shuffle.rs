@@ -0,0 +1,73 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.strings.php
+
+use crate::array::shuffle_slice;
+use crate::string::PhpString;
+
+/// Randomly shuffles a string.
+///
+/// # Description
+///
+/// str_shuffle() shuffles a string. One permutation of all possible is created. The bytes of the
+/// string are shuffled, so multibyte characters are broken up.
+///
+/// It uses the Mersenne Twister shared with mt_rand(), so after mt_srand() it shuffles like PHP
+/// does.
+///
+/// # Examples
+///
+/// Example #1 str_shuffle() example
+///
+/// ```
+/// use phpify::string::{str_shuffle, strlen};
+///
+/// let str = "abcdef";
+/// let shuffled = str_shuffle(str);
+///
+/// assert_eq!(strlen(&shuffled), 6);
+/// ```
+pub fn str_shuffle<S>(string: S) -> PhpString
+    where
+        S: AsRef<[u8]> {
+
+    let mut bytes = string.as_ref().to_vec();
+    shuffle_slice(&mut bytes);
+
+    PhpString::from(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::shuffle;
+    use crate::random::{mt_srand, MT_RAND_MT19937};
+    use crate::string::str_shuffle;
+
+    #[test]
+    fn test() {
+        mt_srand(3, MT_RAND_MT19937);
+        let shuffled = str_shuffle("abcdefgh");
+
+        mt_srand(3, MT_RAND_MT19937);
+        let mut bytes = b"abcdefgh".to_vec();
+        shuffle(&mut bytes);
+
+        assert_eq!(shuffled.as_bytes(), &bytes[..]);
+        assert_eq!(str_shuffle(""), "");
+    }
+}
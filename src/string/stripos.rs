@@ -15,7 +15,8 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::string::strpos;
+use crate::error::PhpError;
+use crate::string::strpos::{find, offset_in};
 
 /// Find the position of the first occurrence of a case-insensitive substring in a string.
 ///
@@ -23,14 +24,19 @@ use crate::string::strpos;
 ///
 /// Find the numeric position of the first occurrence of needle in the haystack string.
 ///
-/// Unlike the strpos(), stripos() is case-insensitive.
+/// Unlike the strpos(), stripos() is case-insensitive. Only the ASCII letters A-Z are folded, other
+/// bytes have to match exactly.
 ///
 /// # Parameters
 ///
 /// **offset**
 ///
-/// If the offset is negative, the search will start this number of characters counted from the end
-/// of the string.
+/// If the offset is negative, the search will start this number of bytes counted from the end of
+/// the string.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if offset is not contained in haystack.
 ///
 /// # Examples
 ///
@@ -41,24 +47,33 @@ use crate::string::strpos;
 ///
 /// let mystring = "ABC";
 /// let findme = "a";
-/// let pos = stripos(mystring, findme, 0).unwrap();
+/// let pos = stripos(mystring, findme, 0).unwrap().unwrap();
 ///
 /// assert_eq!(pos, 0);
 /// ```
-pub fn stripos<H, N>(haystack: H, needle: N, offset: isize) -> Option<usize>
+pub fn stripos<H, N>(haystack: H, needle: N, offset: isize) -> Result<Option<usize>, PhpError>
     where
-        H: AsRef<str>,
-        N: AsRef<str> {
+        H: AsRef<[u8]>,
+        N: AsRef<[u8]> {
+
+    let haystack = haystack.as_ref().to_ascii_lowercase();
+    let needle = needle.as_ref().to_ascii_lowercase();
+    let offset = offset_in("stripos", haystack.len(), offset)?;
 
-    strpos(haystack.as_ref().to_lowercase(), needle.as_ref().to_lowercase(), offset)
+    Ok(find(&haystack[offset..], &needle).map(|position| position + offset))
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::error::PhpError;
     use crate::string::stripos;
 
     #[test]
     fn test() {
-        assert_eq!(stripos("HELLO WORLD", "world", 0), Some(6));
+        assert_eq!(stripos("HELLO WORLD", "world", 0), Ok(Some(6)));
+        assert_eq!(stripos("HELLO WORLD", "world", -4), Ok(None));
+        assert_eq!(stripos("HELLO WORLD", "world", -12), Err(PhpError::ValueError("stripos(): Argument #3 ($offset) must be contained in argument #1 ($haystack)".to_string())));
+        assert_eq!(stripos("ÄBC", "bc", 0), Ok(Some(2)));
+        assert_eq!(stripos("ÄBC", "ä", 0), Ok(None));
     }
 }
@@ -33,6 +33,9 @@
 ///
 /// let str = " ab cd ";
 /// assert_eq!(strlen(str), 7);
+///
+/// let str = "héllo";
+/// assert_eq!(strlen(str), 6);
 /// ```
 ///
 /// # notes
@@ -40,8 +43,8 @@
 /// strlen() returns the number of bytes rather than the number of characters in a string.
 pub fn strlen<S>(string: S) -> usize
     where
-        S: AsRef<str>
-{
+        S: AsRef<[u8]> {
+
     string.as_ref().len()
 }
 
@@ -53,5 +56,7 @@ mod tests {
     fn test() {
         assert_eq!(strlen("Hello World"), 11);
         assert_eq!(strlen(""), 0);
+        assert_eq!(strlen("\u{1f600}"), 4);
+        assert_eq!(strlen(b"\xff\x00"), 2);
     }
 }
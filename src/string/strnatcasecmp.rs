@@ -0,0 +1,64 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.strings.php
+
+use std::cmp::Ordering;
+
+use crate::string::compare_natural;
+
+/// Case insensitive string comparisons using a "natural order" algorithm.
+///
+/// # Description
+///
+/// This function implements a comparison algorithm that orders alphanumeric strings in the way a
+/// human being would. The behaviour of this function is similar to strnatcmp(), except that the
+/// comparison is not case sensitive.
+///
+/// # Examples
+///
+/// Example #1 strnatcasecmp() example
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use phpify::string::strnatcasecmp;
+///
+/// assert_eq!(strnatcasecmp("IMG12.png", "img10.png"), Ordering::Greater);
+/// assert_eq!(strnatcasecmp("Img2.png", "img2.png"), Ordering::Equal);
+/// ```
+pub fn strnatcasecmp<S1, S2>(string1: S1, string2: S2) -> Ordering
+    where
+        S1: AsRef<[u8]>,
+        S2: AsRef<[u8]> {
+
+    compare_natural(string1.as_ref(), string2.as_ref(), true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::string::strnatcasecmp;
+
+    #[test]
+    fn test() {
+        assert_eq!(strnatcasecmp("Img2", "img2"), Ordering::Equal);
+        assert_eq!(strnatcasecmp("IMG2", "img10"), Ordering::Less);
+        assert_eq!(strnatcasecmp("a", "B"), Ordering::Less);
+        assert_eq!(strnatcasecmp("b", "A"), Ordering::Greater);
+    }
+}
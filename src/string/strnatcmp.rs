@@ -0,0 +1,82 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.strings.php
+
+use std::cmp::Ordering;
+
+use crate::string::compare_natural;
+
+/// String comparisons using a "natural order" algorithm.
+///
+/// # Description
+///
+/// This function implements a comparison algorithm that orders alphanumeric strings in the way a
+/// human being would, this is described as a "natural ordering". Note that this comparison is case
+/// sensitive.
+///
+/// Leading zeros of the first number and whitespace are skipped. A number starting with a zero is
+/// compared digit by digit, as the fractional part of a decimal number would be.
+///
+/// # Examples
+///
+/// Example #1 strnatcmp() example
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use phpify::string::strnatcmp;
+///
+/// assert_eq!(strnatcmp("img12.png", "img10.png"), Ordering::Greater);
+/// assert_eq!(strnatcmp("img2.png", "img10.png"), Ordering::Less);
+/// ```
+///
+/// Example #2 Sorting with strnatcmp()
+///
+/// ```
+/// use phpify::array::usort;
+/// use phpify::string::strnatcmp;
+///
+/// let mut files = vec!["img12.png", "img10.png", "img2.png", "img1.png"];
+/// usort(&mut files, |a, b| strnatcmp(a, b));
+///
+/// assert_eq!(files, ["img1.png", "img2.png", "img10.png", "img12.png"]);
+/// ```
+pub fn strnatcmp<S1, S2>(string1: S1, string2: S2) -> Ordering
+    where
+        S1: AsRef<[u8]>,
+        S2: AsRef<[u8]> {
+
+    compare_natural(string1.as_ref(), string2.as_ref(), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::string::strnatcmp;
+
+    #[test]
+    fn test() {
+        assert_eq!(strnatcmp("img12", "img2"), Ordering::Greater);
+        assert_eq!(strnatcmp("Img2", "img2"), Ordering::Less);
+        assert_eq!(strnatcmp("007", "7"), Ordering::Equal);
+        assert_eq!(strnatcmp("  x2", "x10"), Ordering::Less);
+        assert_eq!(strnatcmp("1.010", "1.01"), Ordering::Greater);
+        assert_eq!(strnatcmp("", ""), Ordering::Equal);
+        assert_eq!(strnatcmp(b"a\xff", b"a\xfe"), Ordering::Greater);
+    }
+}
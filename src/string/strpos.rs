@@ -15,18 +15,26 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::error::PhpError;
+
 /// Find the position of the first occurrence of a substring in a string.
 ///
 /// # Description
 ///
 /// Find the numeric position of the first occurrence of needle in the haystack string.
 ///
+/// Positions are counted in bytes from the beginning of haystack, regardless of offset.
+///
 /// # Parameters
 ///
 /// **offset**
 ///
-/// If the offset is negative, the search will start this number of characters counted from the end
-/// of the string.
+/// If the offset is negative, the search will start this number of bytes counted from the end of
+/// the string.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if offset is not contained in haystack.
 ///
 /// # Examples
 ///
@@ -37,7 +45,7 @@
 ///
 /// let mystring = "abc";
 /// let findme = "a";
-/// let pos = strpos(mystring, findme, 0).unwrap();
+/// let pos = strpos(mystring, findme, 0).unwrap().unwrap();
 ///
 /// assert_eq!(pos, 0);
 /// ```
@@ -48,46 +56,52 @@
 /// use phpify::string::strpos;
 ///
 /// let newstring = "abcdef abcdef";
-/// let pos = strpos(newstring, "a", 1).unwrap();
+/// let pos = strpos(newstring, "a", 1).unwrap().unwrap();
 ///
 /// assert_eq!(pos, 7);
 /// ```
 ///
-pub fn strpos<H, N>(haystack: H, needle: N, offset: isize) -> Option<usize>
+/// Example #3 multibyte haystack
+///
+/// ```
+/// use phpify::string::strpos;
+///
+/// assert_eq!(strpos("héllo wörld", "w", 3), Ok(Some(7)));
+/// ```
+pub fn strpos<H, N>(haystack: H, needle: N, offset: isize) -> Result<Option<usize>, PhpError>
     where
-        H: AsRef<str>,
-        N: AsRef<str> {
+        H: AsRef<[u8]>,
+        N: AsRef<[u8]> {
 
-    let mut haystack = haystack.as_ref().to_string();
-    let needle = needle.as_ref();
-    let mut offset = offset;
-    let haystack_length = haystack.len() as isize;
+    let haystack = haystack.as_ref();
+    let offset = offset_in("strpos", haystack.len(), offset)?;
 
-    if offset > 0 && offset > haystack_length {
-        return None;
-    }
+    Ok(find(&haystack[offset..], needle.as_ref()).map(|position| position + offset))
+}
 
-    if offset < 0 {
-        if offset < (0 - haystack_length) {
-            return None;
-        }
-        offset = haystack_length + offset;
-    }
+/// Resolves a possibly negative offset into a haystack of length bytes or characters.
+pub(crate) fn offset_in(function: &str, length: usize, offset: isize) -> Result<usize, PhpError> {
+    let length = length as isize;
 
-    if offset == 0 {
-        return haystack.find(needle);
+    if offset > length || offset < -length {
+        return Err(PhpError::ValueError(format!("{}(): Argument #3 ($offset) must be contained in argument #1 ($haystack)", function)));
     }
 
-    haystack = haystack.chars().skip(offset as usize).collect();
+    Ok(if offset < 0 { offset + length } else { offset } as usize)
+}
 
-    match haystack.find(needle) {
-        None => None,
-        Some(position) => Some(position + offset as usize),
+/// Returns the position of the first occurrence of needle in haystack.
+pub(crate) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
     }
+
+    haystack.windows(needle.len()).position(|window| window == needle)
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::error::PhpError;
     use crate::string::strpos;
 
     #[test]
@@ -95,12 +109,19 @@ mod tests {
         let haystack = "Hello World";
         let needle = "World";
 
-        assert_eq!(strpos(haystack, needle, 0), Some(6));
-        assert_eq!(strpos(haystack, needle, 6), Some(6));
-        assert_eq!(strpos(haystack, needle, -5), Some(6));
-        assert_eq!(strpos(haystack, needle, -11), Some(6));
-        assert_eq!(strpos(haystack, needle, 7), None);
-        assert_eq!(strpos(haystack, needle, 11), None);
-        assert_eq!(strpos(haystack, needle, -12), None);
+        assert_eq!(strpos(haystack, needle, 0), Ok(Some(6)));
+        assert_eq!(strpos(haystack, needle, 6), Ok(Some(6)));
+        assert_eq!(strpos(haystack, needle, -5), Ok(Some(6)));
+        assert_eq!(strpos(haystack, needle, -11), Ok(Some(6)));
+        assert_eq!(strpos(haystack, needle, 7), Ok(None));
+        assert_eq!(strpos(haystack, needle, 11), Ok(None));
+        assert!(strpos(haystack, needle, 12).is_err());
+        assert_eq!(strpos(haystack, needle, -12), Err(PhpError::ValueError("strpos(): Argument #3 ($offset) must be contained in argument #1 ($haystack)".to_string())));
+        assert_eq!(strpos(haystack, "", 11), Ok(Some(11)));
+
+        assert_eq!(strpos("日本語", "語", 0), Ok(Some(6)));
+        assert_eq!(strpos("日本語", "語", 4), Ok(Some(6)));
+        assert_eq!(strpos("日本語", "本", -5), Ok(None));
+        assert_eq!(strpos(b"\xff\x00\xff", b"\xff", 1), Ok(Some(2)));
     }
 }
@@ -15,12 +15,17 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::string::PhpString;
+
 /// Return part of a string.
 ///
 /// # Description
 ///
 /// Returns the portion of string specified by the start and length parameters.
 ///
+/// Positions and lengths are counted in bytes, so a multibyte character can be cut in half. Use
+/// the result as bytes or convert it lossily in that case.
+///
 /// # Parameters
 ///
 /// **start**
@@ -32,7 +37,7 @@
 /// If start is negative, the returned string will start at the start'th character from the end of
 /// string.
 ///
-/// If string is less than start characters long, *None* will be returned.
+/// If string is less than start characters long, an empty string will be returned.
 ///
 /// **length**
 ///
@@ -41,10 +46,12 @@
 ///
 /// If length is given and is negative, then that many characters will be omitted from the end of
 /// string (after the start position has been calculated when a start is negative). If start denotes
-/// the position of this truncation or beyond, *NONE* will be returned.
+/// the position of this truncation or beyond, an empty string will be returned.
 ///
 /// If length is given and is 0, an empty string will be returned.
 ///
+/// Like PHP 8, substr() never fails: out of range positions result in an empty string.
+///
 /// # Examples
 ///
 /// Example #1 substr() examples
@@ -52,11 +59,11 @@
 /// ```
 /// use phpify::string::substr;
 ///
-/// assert_eq!(substr("abcdef", 1, std::isize::MAX).unwrap(), "bcdef");
-/// assert_eq!(substr("abcdef", 1, 3).unwrap(), "bcd");
-/// assert_eq!(substr("abcdef", 0, 4).unwrap(), "abcd");
-/// assert_eq!(substr("abcdef", 0, 8).unwrap(), "abcdef");
-/// assert_eq!(substr("abcdef", -1, 1).unwrap(), "f");
+/// assert_eq!(substr("abcdef", 1, std::isize::MAX), "bcdef");
+/// assert_eq!(substr("abcdef", 1, 3), "bcd");
+/// assert_eq!(substr("abcdef", 0, 4), "abcd");
+/// assert_eq!(substr("abcdef", 0, 8), "abcdef");
+/// assert_eq!(substr("abcdef", -1, 1), "f");
 /// ```
 ///
 /// Example #2 using a negative start
@@ -64,9 +71,9 @@
 /// ```
 /// use phpify::string::substr;
 ///
-/// assert_eq!(substr("abcdef", -1, std::isize::MAX).unwrap(), "f");
-/// assert_eq!(substr("abcdef", -2, std::isize::MAX).unwrap(), "ef");
-/// assert_eq!(substr("abcdef", -3, 1).unwrap(), "d");
+/// assert_eq!(substr("abcdef", -1, std::isize::MAX), "f");
+/// assert_eq!(substr("abcdef", -2, std::isize::MAX), "ef");
+/// assert_eq!(substr("abcdef", -3, 1), "d");
 /// ```
 ///
 /// Example #3 using a negative length
@@ -74,47 +81,40 @@
 /// ```
 /// use phpify::string::substr;
 ///
-/// assert_eq!(substr("abcdef", 0, -1).unwrap(), "abcde");
-/// assert_eq!(substr("abcdef", 2, -1).unwrap(), "cde");
-/// assert_eq!(substr("abcdef", 4, -4), None);
-/// assert_eq!(substr("abcdef", -3, -1).unwrap(), "de");
+/// assert_eq!(substr("abcdef", 0, -1), "abcde");
+/// assert_eq!(substr("abcdef", 2, -1), "cde");
+/// assert_eq!(substr("abcdef", 4, -4), "");
+/// assert_eq!(substr("abcdef", -3, -1), "de");
+/// ```
+///
+/// Example #4 multibyte strings
+///
+/// ```
+/// use phpify::string::substr;
+///
+/// assert_eq!(substr("héllo", 1, 2), "é");
+/// assert_eq!(substr("héllo", 2, 2).as_bytes(), b"\xa9l");
 /// ```
-pub fn substr<S>(string: S, start: isize, length: isize) -> Option<String>
+pub fn substr<S>(string: S, start: isize, length: isize) -> PhpString
     where
-        S: AsRef<str> {
+        S: AsRef<[u8]> {
 
     let string = string.as_ref();
-    let mut start = start;
-    let mut length = length;
     let string_length = string.len() as isize;
 
-    if start > 0 && start >= string_length {
-        return None;
-    }
-
-    if length == 0 {
-        return None;
-    }
-
-    if start < 0 {
-        start = string_length + start;
-        if start < 0 {
-            start = 0;
-        }
+    if start > string_length {
+        return PhpString::new();
     }
 
-    if length < 0 {
-        if start > string_length + length {
-            return None;
-        }
-        length = string_length + length - start;
-    }
+    let start = if start < 0 { (string_length + start).max(0) } else { start };
 
-    if start == 0 && length == string_length {
-        return Some(string.to_string());
-    }
+    let length = if length < 0 {
+        (string_length - start + length).max(0)
+    } else {
+        length.min(string_length - start)
+    };
 
-    Some(string.chars().skip(start as usize).take(length as usize).collect::<String>())
+    PhpString::from(&string[start as usize..(start + length) as usize])
 }
 
 #[cfg(test)]
@@ -125,15 +125,21 @@ mod tests {
     fn test() {
         let string = &"Hello World".to_string();
 
-        assert_eq!(substr(string, 0, 5), Some("Hello".to_string()));
-        assert_eq!(substr(string, 6, 5), Some("World".to_string()));
-        assert_eq!(substr(string, 0, 20), Some("Hello World".to_string()));
-        assert_eq!(substr(string, 0, -6), Some("Hello".to_string()));
-        assert_eq!(substr(string, 3, -3), Some("lo Wo".to_string()));
-        assert_eq!(substr(string, -11, 11), Some("Hello World".to_string()));
-        assert_eq!(substr(string, -20, 11), Some("Hello World".to_string()));
-        assert_eq!(substr(string, 0, 0), None);
-        assert_eq!(substr(string, 11, 1), None);
-        assert_eq!(substr(string, 7, -5), None);
+        assert_eq!(substr(string, 0, 5), "Hello");
+        assert_eq!(substr(string, 6, 5), "World");
+        assert_eq!(substr(string, 0, 20), "Hello World");
+        assert_eq!(substr(string, 0, -6), "Hello");
+        assert_eq!(substr(string, 3, -3), "lo Wo");
+        assert_eq!(substr(string, -11, 11), "Hello World");
+        assert_eq!(substr(string, -20, 11), "Hello World");
+        assert_eq!(substr(string, 0, 0), "");
+        assert_eq!(substr(string, 11, 1), "");
+        assert_eq!(substr(string, 7, -5), "");
+        assert_eq!(substr(string, 12, 1), "");
+
+        assert_eq!(substr("日本語", 3, 3), "本");
+        assert_eq!(substr("日本語", -3, 10), "語");
+        assert_eq!(substr("日本語", 1, 1).as_bytes(), b"\x97");
+        assert_eq!(substr(b"\x00\xff\x00", 1, 1).as_bytes(), b"\xff");
     }
 }
@@ -15,18 +15,20 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::string::PhpString;
+
 /// Make a string's first character uppercase
 ///
 /// # Description
 ///
 /// Returns a string with the first character of str capitalized, if that character is alphabetic.
 ///
-/// Note that 'alphabetic' is determined by the current locale. For instance, in the default "C"
-/// locale characters such as umlaut-a (ä) will not be converted.
+/// Only the ASCII letters a-z and A-Z are converted, like PHP 8 does regardless of the locale. For
+/// instance, characters such as umlaut-a (ä) will not be converted.
 ///
 /// # Examples
 ///
-/// Example #1 lcfirst() example
+/// Example #1 ucfirst() example
 ///
 /// ```
 /// use phpify::string::ucfirst;
@@ -34,16 +36,17 @@
 /// let foo = "hello world!";
 /// assert_eq!(ucfirst(foo), "Hello world!");
 /// ```
-pub fn ucfirst<S>(str: S) -> String
+pub fn ucfirst<S>(str: S) -> PhpString
     where
-        S: AsRef<str> {
+        S: AsRef<[u8]> {
 
-    let mut chars = str.as_ref().chars();
+    let mut bytes = str.as_ref().to_vec();
 
-    match chars.next() {
-        None => String::new(),
-        Some(char) => char.to_uppercase().collect::<String>() + chars.as_str(),
+    if let Some(byte) = bytes.first_mut() {
+        *byte = byte.to_ascii_uppercase();
     }
+
+    PhpString::from(bytes)
 }
 
 #[cfg(test)]
@@ -53,7 +56,9 @@ mod tests {
     #[test]
     fn test() {
         assert_eq!(ucfirst("abc"), "Abc");
-        assert_eq!(ucfirst("äbc"), "Äbc");
-        assert_eq!(ucfirst("δξπ"), "Δξπ");
+        assert_eq!(ucfirst("äbc"), "äbc");
+        assert_eq!(ucfirst("δξπ"), "δξπ");
+        assert_eq!(ucfirst(""), "");
+        assert_eq!(ucfirst(b"a\xff").as_bytes(), b"A\xff");
     }
 }
@@ -0,0 +1,77 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// https://www.php.net/manual/en/ref.strings.php
+
+use crate::array::ArrayLike;
+use crate::error::PhpError;
+use crate::string::format::format;
+use crate::string::PhpString;
+use crate::value::{ToValue, Value};
+
+/// Return a formatted string.
+///
+/// # Description
+///
+/// Operates as sprintf() but accepts an array of arguments, rather than a variable number of
+/// arguments.
+///
+/// # Errors
+///
+/// Returns PhpError::ValueError if the format is invalid or if the array has fewer values than the
+/// format refers to.
+///
+/// # Examples
+///
+/// Example #1 vsprintf(): zero-padded integers
+///
+/// ```
+/// use phpify::string::{explode, vsprintf};
+///
+/// let date = explode("-", "1988-8-1", isize::MAX).unwrap();
+///
+/// assert_eq!(vsprintf("%04d-%02d-%02d", &date).unwrap(), "1988-08-01");
+/// ```
+pub fn vsprintf<F, A>(format_string: F, values: &A) -> Result<PhpString, PhpError>
+    where
+        F: AsRef<[u8]>,
+        A: ArrayLike + ?Sized,
+        A::Value: ToValue {
+
+    let values: Vec<Value> = values.entries().map(|(_, value)| value.to_value()).collect();
+
+    format(format_string.as_ref(), &values, |required, given| {
+        PhpError::ValueError(format!("The arguments array must contain {} items, {} given", required, given))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::PhpArray;
+    use crate::error::PhpError;
+    use crate::string::vsprintf;
+
+    #[test]
+    fn test() {
+        assert_eq!(vsprintf("%s=%d", &vec!["a", "5"]), Ok("a=5".into()));
+
+        let array: PhpArray<i32> = vec![("x", 1), ("y", 2)].into_iter().collect();
+        assert_eq!(vsprintf("%2$d,%1$d", &array), Ok("2,1".into()));
+
+        assert_eq!(vsprintf("%s %s", &vec![1]), Err(PhpError::ValueError("The arguments array must contain 2 items, 1 given".into())));
+    }
+}
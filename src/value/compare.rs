@@ -0,0 +1,284 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::cmp::Ordering;
+
+use crate::array::PhpArray;
+use crate::value::numeric::{parse_numeric_whole, Number};
+use crate::value::Value;
+
+impl Value {
+    /// Loose comparison (==).
+    ///
+    /// # Description
+    ///
+    /// Returns true if the values are equal after type juggling, following the comparison rules of
+    /// PHP 8:
+    ///
+    /// - bool or null compared with anything converts both sides to bool.
+    /// - numeric strings are compared as numbers, with each other and with integers and floats.
+    /// - non-numeric strings are compared with integers and floats as strings.
+    /// - arrays are equal when they have the same key/value pairs, compared loosely.
+    ///
+    /// # Examples
+    ///
+    /// Example #1 Loose comparisons
+    ///
+    /// ```
+    /// use phpify::value::Value;
+    ///
+    /// assert!(Value::from("1").loose_eq(&Value::from("01")));
+    /// assert!(Value::from("10").loose_eq(&Value::from("1e1")));
+    /// assert!(Value::from(100).loose_eq(&Value::from("1e2")));
+    /// assert!(!Value::from("abc").loose_eq(&Value::from(0)));
+    /// assert!(Value::Null.loose_eq(&Value::from(false)));
+    /// assert!(Value::from("0").loose_eq(&Value::from(false)));
+    /// ```
+    pub fn loose_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Float(a), Value::Float(b)) => a == b,
+            (Value::Float(a), Value::Int(b)) | (Value::Int(b), Value::Float(a)) => *a == *b as f64,
+            _ => self.compare(other) == Ordering::Equal,
+        }
+    }
+
+    /// Strict comparison (===).
+    ///
+    /// Returns true if the values are equal and of the same type. This is the same as PartialEq.
+    pub fn strict_eq(&self, other: &Value) -> bool {
+        self == other
+    }
+
+    /// Three-way comparison (<=>).
+    ///
+    /// # Description
+    ///
+    /// Compares the values after type juggling, following the comparison rules of PHP 8. Values
+    /// that cannot be compared, like arrays with different keys or objects of different classes,
+    /// compare as Greater.
+    ///
+    /// Arrays with fewer members are smaller, otherwise they are compared value by value. Arrays
+    /// are always greater than other types, except bool and null.
+    ///
+    /// # Examples
+    ///
+    /// Example #1 Three-way comparisons
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use phpify::value::Value;
+    ///
+    /// assert_eq!(Value::from(1).compare(&Value::from(2)), Ordering::Less);
+    /// assert_eq!(Value::from("a").compare(&Value::from("b")), Ordering::Less);
+    /// assert_eq!(Value::from("10").compare(&Value::from("9")), Ordering::Greater);
+    /// assert_eq!(Value::from("abc").compare(&Value::from(1)), Ordering::Greater);
+    /// assert_eq!(Value::from(vec![1, 2, 3]).compare(&Value::from(vec![1, 2, 4])), Ordering::Less);
+    /// ```
+    pub fn compare(&self, other: &Value) -> Ordering {
+        match (self, other) {
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::Int(a), Value::Float(b)) => compare_floats(*a as f64, *b),
+            (Value::Float(a), Value::Int(b)) => compare_floats(*a, *b as f64),
+            (Value::Float(a), Value::Float(b)) => compare_floats(*a, *b),
+            (Value::String(a), Value::String(b)) => compare_strings(a.as_bytes(), b.as_bytes()),
+            (Value::Array(a), Value::Array(b)) => compare_arrays(a, b),
+            (Value::Null, Value::String(b)) => if b.is_empty() { Ordering::Equal } else { Ordering::Less },
+            (Value::String(a), Value::Null) => if a.is_empty() { Ordering::Equal } else { Ordering::Greater },
+            (Value::Int(a), Value::String(b)) => compare_int_to_string(*a, b),
+            (Value::String(a), Value::Int(b)) => compare_int_to_string(*b, a).reverse(),
+            (Value::Float(a), Value::String(b)) => compare_float_to_string(*a, b),
+            (Value::String(a), Value::Float(b)) => compare_string_to_float(a, *b),
+            (Value::Object(a), Value::Object(b)) => {
+                if a.class_name() != b.class_name() {
+                    return Ordering::Greater;
+                }
+                compare_arrays(a.properties(), b.properties())
+            },
+            (Value::Object(_), other) => compare_object_to(other),
+            (other, Value::Object(_)) => compare_object_to(other).reverse(),
+            (Value::Null, other) | (Value::Bool(false), other) => {
+                if other.to_bool() { Ordering::Less } else { Ordering::Equal }
+            },
+            (Value::Bool(true), other) => {
+                if other.to_bool() { Ordering::Equal } else { Ordering::Greater }
+            },
+            (other, Value::Null) | (other, Value::Bool(false)) => {
+                if other.to_bool() { Ordering::Greater } else { Ordering::Equal }
+            },
+            (other, Value::Bool(true)) => {
+                if other.to_bool() { Ordering::Equal } else { Ordering::Less }
+            },
+            (Value::Array(_), _) => Ordering::Greater,
+            (_, Value::Array(_)) => Ordering::Less,
+        }
+    }
+}
+
+/// Compares floats, NaN compares as Greater.
+fn compare_floats(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Greater)
+}
+
+/// Compares strings as numbers when both are numeric, and byte by byte otherwise.
+pub(crate) fn compare_strings(a: &[u8], b: &[u8]) -> Ordering {
+    match (parse_numeric_whole(a), parse_numeric_whole(b)) {
+        (Some(Number::Int(a)), Some(Number::Int(b))) => a.cmp(&b),
+        (Some(a), Some(b)) => compare_floats(a.to_float(), b.to_float()),
+        _ => a.cmp(b),
+    }
+}
+
+fn compare_int_to_string(int: i64, string: &[u8]) -> Ordering {
+    match parse_numeric_whole(string) {
+        Some(Number::Int(number)) => int.cmp(&number),
+        Some(Number::Float(number)) => compare_floats(int as f64, number),
+        None => int.to_string().as_bytes().cmp(string),
+    }
+}
+
+fn compare_float_to_string(float: f64, string: &[u8]) -> Ordering {
+    match parse_numeric_whole(string) {
+        Some(number) => compare_floats(float, number.to_float()),
+        None => Value::Float(float).to_string().as_bytes().cmp(string),
+    }
+}
+
+/// Compares a string with a float, the string being on the left. Not the reverse of
+/// compare_float_to_string(), as NaN compares as Greater on either side.
+fn compare_string_to_float(string: &[u8], float: f64) -> Ordering {
+    match parse_numeric_whole(string) {
+        Some(number) => compare_floats(number.to_float(), float),
+        None => string.cmp(Value::Float(float).to_string().as_bytes()),
+    }
+}
+
+/// Compares an object with a value of another type, the object being on the left.
+fn compare_object_to(other: &Value) -> Ordering {
+    match other {
+        Value::Bool(_) => Value::Bool(true).compare(other),
+        Value::Int(_) => Value::Int(1).compare(other),
+        Value::Float(_) => Value::Float(1.0).compare(other),
+        _ => Ordering::Greater,
+    }
+}
+
+fn compare_arrays(a: &PhpArray<Value>, b: &PhpArray<Value>) -> Ordering {
+    if a.len() != b.len() {
+        return a.len().cmp(&b.len());
+    }
+
+    for (key, a) in a {
+        let ordering = match b.get(key) {
+            Some(b) => a.compare(b),
+            None => return Ordering::Greater,
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::array::PhpArray;
+    use crate::string::PhpString;
+    use crate::value::{Object, Value};
+
+    fn eq<A: Into<Value>, B: Into<Value>>(a: A, b: B) -> bool {
+        a.into().loose_eq(&b.into())
+    }
+
+    #[test]
+    fn test_loose_eq() {
+        assert!(eq("1", "01"));
+        assert!(eq("10", "1e1"));
+        assert!(eq(100, "1e2"));
+        assert!(eq(" 1", 1));
+        assert!(eq("1 ", 1));
+        assert!(!eq("abc", 0));
+        assert!(!eq("1abc", 1));
+        assert!(eq("abc", "abc"));
+        assert!(!eq("abc", "ABC"));
+        assert!(!eq(PhpString::from(&b"\xff"[..]), PhpString::from(&b"\xfe"[..])));
+        assert!(eq(1.5, "1.5"));
+        assert!(eq(1, 1.0));
+        assert!(!eq(f64::NAN, f64::NAN));
+        assert!(eq(f64::NAN, "NAN"));
+        assert!(eq("NAN", f64::NAN));
+        assert!(!eq(f64::NAN, "1"));
+        assert!(eq((), false));
+        assert!(eq((), 0));
+        assert!(eq((), ""));
+        assert!(!eq((), "0"));
+        assert!(eq(false, "0"));
+        assert!(eq(true, "abc"));
+        assert!(eq((), PhpArray::new()));
+        assert!(!eq(vec![1], 1));
+        assert!(eq(vec!["1", "2"], vec![1, 2]));
+        assert!(!eq(vec![1, 2], vec![2, 1]));
+
+        let a: PhpArray<Value> = vec![("a", Value::from(1)), ("b", Value::from(2))].into_iter().collect();
+        let b: PhpArray<Value> = vec![("b", Value::from(2)), ("a", Value::from(1))].into_iter().collect();
+        assert!(eq(a.clone(), b.clone()));
+        assert!(!Value::from(a).strict_eq(&Value::from(b)));
+
+        let mut object = Object::new("stdClass");
+        object.set("a", 1);
+        assert!(eq(object.clone(), object.clone()));
+        assert!(eq(object.clone(), true));
+        assert!(!eq(object.clone(), Object::new("Foo")));
+        assert!(!eq(object, ()));
+    }
+
+    #[test]
+    fn test_compare() {
+        fn cmp<A: Into<Value>, B: Into<Value>>(a: A, b: B) -> Ordering {
+            a.into().compare(&b.into())
+        }
+
+        assert_eq!(cmp(1, 2), Ordering::Less);
+        assert_eq!(cmp(2.5, 2), Ordering::Greater);
+        assert_eq!(cmp("10", "9"), Ordering::Greater);
+        assert_eq!(cmp("10", "9a"), Ordering::Less);
+        assert_eq!(cmp("abc", 1), Ordering::Greater);
+        assert_eq!(cmp(1, "abc"), Ordering::Less);
+        assert_eq!(cmp(1.5, "abc"), Ordering::Less);
+        assert_eq!(cmp((), "a"), Ordering::Less);
+        assert_eq!(cmp((), -1), Ordering::Less);
+        assert_eq!(cmp(true, 0), Ordering::Greater);
+        assert_eq!(cmp(vec![1], vec![1, 2]), Ordering::Less);
+        assert_eq!(cmp(vec![1], 100), Ordering::Greater);
+        assert_eq!(cmp("abc", vec![1]), Ordering::Less);
+        assert_eq!(cmp(f64::NAN, 1), Ordering::Greater);
+        assert_eq!(cmp(1, f64::NAN), Ordering::Greater);
+        assert_eq!(cmp(f64::NAN, "NAN"), Ordering::Equal);
+        assert_eq!(cmp(f64::NAN, "abc"), Ordering::Less);
+        assert_eq!(cmp("abc", f64::NAN), Ordering::Greater);
+        assert_eq!(cmp(f64::NAN, "1"), Ordering::Greater);
+        assert_eq!(cmp("1", f64::NAN), Ordering::Greater);
+
+        let a: PhpArray<Value> = vec![("a", Value::from(1))].into_iter().collect();
+        let b: PhpArray<Value> = vec![("b", Value::from(1))].into_iter().collect();
+        assert_eq!(cmp(a.clone(), b.clone()), Ordering::Greater);
+        assert_eq!(cmp(b, a), Ordering::Greater);
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Formats a float the way PHP's zend_gcvt() does.
+///
+/// A precision of -1 uses the shortest representation that round-trips, like serialize_precision
+/// does by default. Other precisions use that many significant digits, like the precision ini
+/// setting does. The exponential format is used when the exponent is less than -4 or not less than
+/// the precision (17 for the shortest representation).
+pub(crate) fn gcvt(value: f64, precision: i32, exp_char: char) -> String {
+    if value.is_nan() {
+        return "NAN".to_string();
+    }
+
+    if value.is_infinite() {
+        return if value < 0.0 { "-INF" } else { "INF" }.to_string();
+    }
+
+    let (digits, exponent) = if precision < 0 {
+        split_exponential(&format!("{:e}", value.abs()))
+    } else {
+        let precision = precision.max(1) as usize;
+        split_exponential(&format!("{:.*e}", precision - 1, value.abs()))
+    };
+
+    let digits = match digits.trim_end_matches('0') {
+        "" => "0",
+        digits => digits,
+    };
+
+    let precision = if precision < 0 { 17 } else { precision.max(1) };
+    let decimal_point = exponent + 1;
+
+    let mut buffer = String::new();
+
+    if value.is_sign_negative() {
+        buffer.push('-');
+    }
+
+    if if decimal_point < 0 { decimal_point < -3 } else { decimal_point > precision } {
+        buffer.push_str(&digits[..1]);
+        buffer.push('.');
+
+        if digits.len() == 1 {
+            buffer.push('0');
+        } else {
+            buffer.push_str(&digits[1..]);
+        }
+
+        buffer.push(exp_char);
+        buffer.push(if exponent < 0 { '-' } else { '+' });
+        buffer.push_str(&exponent.abs().to_string());
+    } else if decimal_point <= 0 {
+        buffer.push_str("0.");
+        buffer.push_str(&"0".repeat(decimal_point.unsigned_abs() as usize));
+        buffer.push_str(digits);
+    } else {
+        let decimal_point = decimal_point as usize;
+
+        if digits.len() <= decimal_point {
+            buffer.push_str(digits);
+            buffer.push_str(&"0".repeat(decimal_point - digits.len()));
+        } else {
+            buffer.push_str(&digits[..decimal_point]);
+            buffer.push('.');
+            buffer.push_str(&digits[decimal_point..]);
+        }
+    }
+
+    buffer
+}
+
+/// Splits Rust's exponential notation into its significant digits and its exponent.
+fn split_exponential(formatted: &str) -> (String, i32) {
+    let (mantissa, exponent) = formatted.split_at(formatted.find('e').expect("exponential notation"));
+
+    (mantissa.replace('.', ""), exponent[1..].parse().expect("exponent"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::value::gcvt::gcvt;
+
+    #[test]
+    fn test() {
+        assert_eq!(gcvt(0.1 + 0.2, 14, 'E'), "0.3");
+        assert_eq!(gcvt(0.1 + 0.2, -1, 'E'), "0.30000000000000004");
+        assert_eq!(gcvt(1.0, 14, 'E'), "1");
+        assert_eq!(gcvt(-1.5, 14, 'E'), "-1.5");
+        assert_eq!(gcvt(0.0, -1, 'E'), "0");
+        assert_eq!(gcvt(-0.0, -1, 'E'), "-0");
+        assert_eq!(gcvt(100.0, 14, 'E'), "100");
+        assert_eq!(gcvt(1e14, 14, 'E'), "1.0E+14");
+        assert_eq!(gcvt(1e13, 14, 'E'), "10000000000000");
+        assert_eq!(gcvt(1e25, -1, 'E'), "1.0E+25");
+        assert_eq!(gcvt(1.5e25, -1, 'e'), "1.5e+25");
+        assert_eq!(gcvt(1e16, -1, 'E'), "10000000000000000");
+        assert_eq!(gcvt(1e17, -1, 'E'), "1.0E+17");
+        assert_eq!(gcvt(0.0001, 14, 'E'), "0.0001");
+        assert_eq!(gcvt(0.00001, 14, 'E'), "1.0E-5");
+        assert_eq!(gcvt(0.5, 14, 'E'), "0.5");
+        assert_eq!(gcvt(1.0 / 3.0, 14, 'E'), "0.33333333333333");
+        assert_eq!(gcvt(123456.789, 4, 'E'), "1.235E+5");
+        assert_eq!(gcvt(f64::INFINITY, 14, 'E'), "INF");
+        assert_eq!(gcvt(f64::NEG_INFINITY, 14, 'E'), "-INF");
+        assert_eq!(gcvt(f64::NAN, 14, 'E'), "NAN");
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// A number parsed from a numeric string.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+impl Number {
+    pub(crate) fn to_float(self) -> f64 {
+        match self {
+            Number::Int(int) => int as f64,
+            Number::Float(float) => float,
+        }
+    }
+}
+
+/// Parses the leading numeric part of a string, the way PHP does.
+///
+/// Leading and trailing whitespace is allowed. Returns the number, and whether the whole string was
+/// numeric (true) or only a prefix of it (false). Integers that do not fit in an i64 are returned
+/// as floats.
+pub(crate) fn parse_numeric(string: &[u8]) -> Option<(Number, bool)> {
+    let length = string.len();
+    let byte = |index: usize| string.get(index).copied().unwrap_or(0);
+
+    let mut index = 0;
+
+    while is_whitespace(byte(index)) {
+        index += 1;
+    }
+
+    let start = index;
+
+    if byte(index) == b'-' || byte(index) == b'+' {
+        index += 1;
+    }
+
+    let integer_start = index;
+
+    while byte(index).is_ascii_digit() {
+        index += 1;
+    }
+
+    let integer_digits = index - integer_start;
+    let mut is_float = false;
+
+    if byte(index) == b'.' && (integer_digits > 0 || byte(index + 1).is_ascii_digit()) {
+        index += 1;
+        is_float = true;
+
+        while byte(index).is_ascii_digit() {
+            index += 1;
+        }
+    }
+
+    if integer_digits == 0 && !is_float {
+        return None;
+    }
+
+    if byte(index) == b'e' || byte(index) == b'E' {
+        let mut exponent = index + 1;
+
+        if byte(exponent) == b'-' || byte(exponent) == b'+' {
+            exponent += 1;
+        }
+
+        if byte(exponent).is_ascii_digit() {
+            index = exponent;
+            is_float = true;
+
+            while byte(index).is_ascii_digit() {
+                index += 1;
+            }
+        }
+    }
+
+    let lexeme = std::str::from_utf8(&string[start..index]).expect("ascii lexeme");
+    let end = index;
+
+    while is_whitespace(byte(index)) {
+        index += 1;
+    }
+
+    let whole = index >= length && end > start;
+
+    let number = match (is_float, lexeme.parse::<i64>()) {
+        (false, Ok(int)) => Number::Int(int),
+        _ => Number::Float(lexeme.parse().expect("float lexeme")),
+    };
+
+    Some((number, whole))
+}
+
+/// Returns the number if the whole string is numeric.
+pub(crate) fn parse_numeric_whole(string: &[u8]) -> Option<Number> {
+    match parse_numeric(string) {
+        Some((number, true)) => Some(number),
+        _ => None,
+    }
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r' | 0x0b | 0x0c)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::value::numeric::{parse_numeric, Number};
+
+    #[test]
+    fn test() {
+        assert_eq!(parse_numeric(b"12"), Some((Number::Int(12), true)));
+        assert_eq!(parse_numeric(b" 12 "), Some((Number::Int(12), true)));
+        assert_eq!(parse_numeric(b"-12"), Some((Number::Int(-12), true)));
+        assert_eq!(parse_numeric(b"+12"), Some((Number::Int(12), true)));
+        assert_eq!(parse_numeric(b"12abc"), Some((Number::Int(12), false)));
+        assert_eq!(parse_numeric(b"1e3"), Some((Number::Float(1000.0), true)));
+        assert_eq!(parse_numeric(b"1e"), Some((Number::Int(1), false)));
+        assert_eq!(parse_numeric(b"1."), Some((Number::Float(1.0), true)));
+        assert_eq!(parse_numeric(b".5"), Some((Number::Float(0.5), true)));
+        assert_eq!(parse_numeric(b"-.5e-1"), Some((Number::Float(-0.05), true)));
+        assert_eq!(parse_numeric(b"9223372036854775808"), Some((Number::Float(9223372036854775808.0), true)));
+        assert_eq!(parse_numeric(b"0x1A"), Some((Number::Int(0), false)));
+        assert_eq!(parse_numeric(b"."), None);
+        assert_eq!(parse_numeric(b"-"), None);
+        assert_eq!(parse_numeric(b"abc"), None);
+        assert_eq!(parse_numeric(b""), None);
+        assert_eq!(parse_numeric(b" "), None);
+    }
+}
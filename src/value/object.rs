@@ -0,0 +1,131 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{Key, PhpArray};
+use crate::string::PhpString;
+use crate::value::Value;
+
+/// An instance of a class.
+///
+/// # Description
+///
+/// Objects are represented by the name of their class and their properties. There is no notion of
+/// methods or object identity: objects are compared, cloned and dropped by value.
+///
+/// Objects of classes implementing Serializable are represented by their serialized data instead,
+/// as it is up to the class to interpret it.
+///
+/// # Examples
+///
+/// Example #1 Creating a stdClass object
+///
+/// ```
+/// use phpify::value::{Object, Value};
+///
+/// let mut object = Object::new("stdClass");
+/// object.set("foo", "bar");
+///
+/// assert_eq!(object.class_name(), "stdClass");
+/// assert_eq!(object.get("foo"), Some(&Value::from("bar")));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Object {
+    class_name: String,
+    properties: PhpArray<Value>,
+    serialized_data: Option<PhpString>,
+}
+
+impl Object {
+    /// Creates an object of the class without any properties.
+    pub fn new<S>(class_name: S) -> Self
+        where
+            S: Into<String> {
+
+        Object {
+            class_name: class_name.into(),
+            properties: PhpArray::new(),
+            serialized_data: None,
+        }
+    }
+
+    /// Creates an object of the class with the properties.
+    pub fn with_properties<S>(class_name: S, properties: PhpArray<Value>) -> Self
+        where
+            S: Into<String> {
+
+        Object {
+            class_name: class_name.into(),
+            properties,
+            serialized_data: None,
+        }
+    }
+
+    /// Creates an object of a class implementing Serializable from its serialized data.
+    pub fn custom<S, D>(class_name: S, serialized_data: D) -> Self
+        where
+            S: Into<String>,
+            D: Into<PhpString> {
+
+        Object {
+            class_name: class_name.into(),
+            properties: PhpArray::new(),
+            serialized_data: Some(serialized_data.into()),
+        }
+    }
+
+    /// Returns the name of the class.
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// Returns the serialized data, if the object was created from it.
+    pub fn serialized_data(&self) -> Option<&PhpString> {
+        self.serialized_data.as_ref()
+    }
+
+    /// Returns the properties.
+    pub fn properties(&self) -> &PhpArray<Value> {
+        &self.properties
+    }
+
+    /// Returns the properties for modification.
+    pub fn properties_mut(&mut self) -> &mut PhpArray<Value> {
+        &mut self.properties
+    }
+
+    /// Consumes the object, returning its properties.
+    pub fn into_properties(self) -> PhpArray<Value> {
+        self.properties
+    }
+
+    /// Returns the value of a property.
+    pub fn get<K>(&self, name: K) -> Option<&Value>
+        where
+            K: Into<Key> {
+
+        self.properties.get(name)
+    }
+
+    /// Sets the value of a property.
+    pub fn set<K, V>(&mut self, name: K, value: V)
+        where
+            K: Into<Key>,
+            V: Into<Value> {
+
+        self.properties.insert(name, value.into());
+    }
+}
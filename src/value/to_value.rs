@@ -0,0 +1,143 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{Key, PhpArray};
+use crate::string::PhpString;
+use crate::value::{Object, Value};
+
+/// A type that can be converted to a Value by reference.
+///
+/// # Description
+///
+/// The functions that follow PHP's comparison and conversion rules, like array_search() or
+/// array_unique(), accept any value type that implements ToValue. It is implemented for Value
+/// itself, the scalar types, strings, keys, vectors and arrays of such types, and references to
+/// them.
+///
+/// # Examples
+///
+/// Example #1 ToValue example
+///
+/// ```
+/// use phpify::value::{ToValue, Value};
+///
+/// assert_eq!("abc".to_value(), Value::from("abc"));
+/// assert_eq!(5u8.to_value(), Value::Int(5));
+/// assert_eq!(vec![1, 2].to_value(), Value::from(vec![1, 2]));
+/// ```
+pub trait ToValue {
+    /// Converts to a Value.
+    fn to_value(&self) -> Value;
+}
+
+impl ToValue for Value {
+    fn to_value(&self) -> Value {
+        self.clone()
+    }
+}
+
+impl<T> ToValue for &T
+    where
+        T: ToValue + ?Sized {
+
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+macro_rules! impl_to_value_for_copy {
+    ($($type:ty),*) => {
+        $(
+            impl ToValue for $type {
+                fn to_value(&self) -> Value {
+                    Value::from(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_value_for_copy!(bool, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+impl ToValue for str {
+    fn to_value(&self) -> Value {
+        Value::from(self)
+    }
+}
+
+impl ToValue for String {
+    fn to_value(&self) -> Value {
+        Value::from(self.as_str())
+    }
+}
+
+impl ToValue for PhpString {
+    fn to_value(&self) -> Value {
+        Value::from(self)
+    }
+}
+
+impl ToValue for Key {
+    fn to_value(&self) -> Value {
+        Value::from(self.clone())
+    }
+}
+
+impl ToValue for Object {
+    fn to_value(&self) -> Value {
+        Value::Object(self.clone())
+    }
+}
+
+impl<T> ToValue for Option<T>
+    where
+        T: ToValue {
+
+    fn to_value(&self) -> Value {
+        match self {
+            Some(value) => value.to_value(),
+            None => Value::Null,
+        }
+    }
+}
+
+impl<T> ToValue for [T]
+    where
+        T: ToValue {
+
+    fn to_value(&self) -> Value {
+        Value::Array(PhpArray::from(self.iter().map(ToValue::to_value).collect::<Vec<Value>>()))
+    }
+}
+
+impl<T> ToValue for Vec<T>
+    where
+        T: ToValue {
+
+    fn to_value(&self) -> Value {
+        self.as_slice().to_value()
+    }
+}
+
+impl<T> ToValue for PhpArray<T>
+    where
+        T: ToValue {
+
+    fn to_value(&self) -> Value {
+        Value::Array(self.iter().map(|(key, value)| (key, value.to_value())).collect())
+    }
+}
@@ -0,0 +1,470 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::array::{Key, PhpArray};
+use crate::string::PhpString;
+use crate::value::gcvt::gcvt;
+use crate::value::numeric::{parse_numeric, Number};
+use crate::value::Object;
+
+/// The number of significant digits displayed in floating point numbers
+///
+/// # Description
+///
+/// The default of the precision ini setting of PHP. Value uses it when a float is converted to a
+/// string without a precision given, by to_php_string(), Display and the functions built on them.
+/// Format a value with a precision, like {:.17}, to display more or fewer digits.
+///
+/// # Examples
+///
+/// Example #1 Floats displayed with PRECISION significant digits
+///
+/// ```
+/// use phpify::value::{Value, PRECISION};
+///
+/// assert_eq!(PRECISION, 14);
+/// assert_eq!(Value::from(1.0 / 3.0).to_string(), "0.33333333333333");
+/// assert_eq!(Value::from(1e15).to_string(), "1.0E+15");
+/// ```
+pub const PRECISION: usize = 14;
+
+/// A value of any PHP type.
+///
+/// # Description
+///
+/// PHP variables do not have a fixed type, the type is determined at runtime by the value they
+/// hold. Value models such a variable, and converts between the types following the type juggling
+/// rules of PHP.
+///
+/// Strings are binary-safe PhpStrings, like PHP strings. Converting a value to a PHP string is done
+/// by to_php_string(); Display converts it to UTF-8 text, replacing invalid sequences with U+FFFD.
+///
+/// Value implements PartialEq as identity (===): two values are equal when they have the same type
+/// and the same value, and arrays hold the same key/value pairs in the same order.
+///
+/// # Examples
+///
+/// Example #1 Type juggling
+///
+/// ```
+/// use phpify::value::Value;
+///
+/// assert_eq!(Value::from("12abc").to_int(), 12);
+/// assert_eq!(Value::from("1e3").to_int(), 1000);
+/// assert_eq!(Value::from("0").to_bool(), false);
+/// assert_eq!(Value::from("0.0").to_bool(), true);
+/// assert_eq!(Value::from(" 1.5 ").to_float(), 1.5);
+/// assert_eq!(Value::from(0.1 + 0.2).to_string(), "0.3");
+/// assert_eq!(Value::from(true).to_string(), "1");
+/// ```
+///
+/// Example #2 Converting floats to strings with a custom precision
+///
+/// ```
+/// use phpify::value::Value;
+///
+/// let value = Value::from(0.1 + 0.2);
+///
+/// assert_eq!(format!("{}", value), "0.3");
+/// assert_eq!(format!("{:.17}", value), "0.30000000000000004");
+/// ```
+///
+/// Example #3 Binary strings
+///
+/// ```
+/// use phpify::string::PhpString;
+/// use phpify::value::Value;
+///
+/// let value = Value::from(PhpString::from(vec![0x61, 0xff]));
+///
+/// assert_eq!(value.to_php_string(), PhpString::from(vec![0x61, 0xff]));
+/// assert_eq!(value.to_string(), "a\u{fffd}");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Value {
+    /// The null value, the value of a variable that has no value.
+    #[default]
+    Null,
+    /// A boolean, true or false.
+    Bool(bool),
+    /// A signed 64-bit integer.
+    Int(i64),
+    /// A double precision floating point number.
+    Float(f64),
+    /// A binary-safe string of bytes.
+    String(PhpString),
+    /// An ordered map of keys to values.
+    Array(PhpArray<Value>),
+    /// An instance of a class.
+    Object(Object),
+}
+
+impl Value {
+    /// Returns true if the value is null.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    /// Converts the value to a boolean.
+    ///
+    /// The following values are considered false: false itself, the integer 0, the floats 0.0 and
+    /// -0.0, the empty string and the string "0", an array with zero elements, and null. Every
+    /// other value is considered true.
+    pub fn to_bool(&self) -> bool {
+        match self {
+            Value::Null => false,
+            Value::Bool(bool) => *bool,
+            Value::Int(int) => *int != 0,
+            Value::Float(float) => *float != 0.0,
+            Value::String(string) => !(string.is_empty() || *string == "0"),
+            Value::Array(array) => !array.is_empty(),
+            Value::Object(_) => true,
+        }
+    }
+
+    /// Converts the value to an integer.
+    ///
+    /// Floats are rounded towards zero, NaN and Infinity become zero. Strings are converted using
+    /// their leading numeric part, or zero when they do not start with a number. Empty arrays
+    /// become 0 and other arrays and objects become 1.
+    pub fn to_int(&self) -> i64 {
+        match self {
+            Value::Null => 0,
+            Value::Bool(bool) => *bool as i64,
+            Value::Int(int) => *int,
+            Value::Float(float) => float_to_int(*float),
+            Value::String(string) => match parse_numeric(string.as_bytes()) {
+                Some((Number::Int(int), _)) => int,
+                Some((Number::Float(float), _)) => float_to_int_capped(float),
+                None => 0,
+            },
+            Value::Array(array) => !array.is_empty() as i64,
+            Value::Object(_) => 1,
+        }
+    }
+
+    /// Converts the value to a float.
+    ///
+    /// Strings are converted using their leading numeric part, or zero when they do not start with
+    /// a number. Other values are converted to an integer first.
+    pub fn to_float(&self) -> f64 {
+        match self {
+            Value::Float(float) => *float,
+            Value::String(string) => match parse_numeric(string.as_bytes()) {
+                Some((number, _)) => number.to_float(),
+                None => 0.0,
+            },
+            value => value.to_int() as f64,
+        }
+    }
+
+    /// Converts the value to a string.
+    ///
+    /// Strings are returned as they are, byte for byte. Other values are converted the way Display
+    /// converts them.
+    pub fn to_php_string(&self) -> PhpString {
+        match self {
+            Value::String(string) => string.clone(),
+            value => PhpString::from(value.to_string()),
+        }
+    }
+
+    /// Converts the value to an array key.
+    ///
+    /// Bools and floats are cast to integers, null is cast to the empty string. Arrays and objects
+    /// are illegal offsets and return *None*.
+    pub fn to_key(&self) -> Option<Key> {
+        match self {
+            Value::Null => Some(Key::from("")),
+            Value::Bool(bool) => Some(Key::from(*bool)),
+            Value::Int(int) => Some(Key::Int(*int)),
+            Value::Float(float) => Some(Key::Int(float_to_int(*float))),
+            Value::String(string) => Some(Key::from(string)),
+            Value::Array(_) | Value::Object(_) => None,
+        }
+    }
+}
+
+/// Converts the value to a string.
+///
+/// Strings that are not valid UTF-8 are displayed with U+FFFD replacing the invalid sequences. Null
+/// and false become the empty string, true becomes "1". Floats are displayed with PRECISION
+/// significant digits, or with the precision of the formatter when one is given. Arrays become
+/// "Array" and objects become "Object".
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Null | Value::Bool(false) => Ok(()),
+            Value::Bool(true) => f.write_str("1"),
+            Value::Int(int) => write!(f, "{}", int),
+            Value::Float(float) => {
+                let precision = f.precision().unwrap_or(PRECISION) as i32;
+                f.write_str(&gcvt(*float, precision, 'E'))
+            },
+            Value::String(string) => write!(f, "{}", string),
+            Value::Array(_) => f.write_str("Array"),
+            Value::Object(_) => f.write_str("Object"),
+        }
+    }
+}
+
+impl From<()> for Value {
+    fn from(_: ()) -> Self {
+        Value::Null
+    }
+}
+
+impl From<bool> for Value {
+    fn from(bool: bool) -> Self {
+        Value::Bool(bool)
+    }
+}
+
+macro_rules! impl_from_int_for_value {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Value {
+                fn from(int: $int) -> Self {
+                    Value::Int(int as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int_for_value!(i8, i16, i32, i64, u8, u16, u32);
+
+macro_rules! impl_from_wide_int_for_value {
+    ($($int:ty),*) => {
+        $(
+            /// Integers that do not fit in an i64 overflow to a float.
+            impl From<$int> for Value {
+                fn from(int: $int) -> Self {
+                    match i64::try_from(int) {
+                        Ok(int) => Value::Int(int),
+                        Err(_) => Value::Float(int as f64),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_wide_int_for_value!(isize, usize, u64);
+
+impl From<f32> for Value {
+    fn from(float: f32) -> Self {
+        Value::Float(float as f64)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(float: f64) -> Self {
+        Value::Float(float)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(string: &str) -> Self {
+        Value::String(PhpString::from(string))
+    }
+}
+
+impl From<String> for Value {
+    fn from(string: String) -> Self {
+        Value::String(PhpString::from(string))
+    }
+}
+
+impl From<&String> for Value {
+    fn from(string: &String) -> Self {
+        Value::String(PhpString::from(string.as_str()))
+    }
+}
+
+impl From<PhpString> for Value {
+    fn from(string: PhpString) -> Self {
+        Value::String(string)
+    }
+}
+
+impl From<&PhpString> for Value {
+    fn from(string: &PhpString) -> Self {
+        Value::String(string.clone())
+    }
+}
+
+impl From<Key> for Value {
+    fn from(key: Key) -> Self {
+        match key {
+            Key::Int(int) => Value::Int(int),
+            Key::Str(string) => Value::String(string),
+        }
+    }
+}
+
+impl From<PhpArray<Value>> for Value {
+    fn from(array: PhpArray<Value>) -> Self {
+        Value::Array(array)
+    }
+}
+
+impl<T> From<Vec<T>> for Value
+    where
+        T: Into<Value> {
+
+    fn from(vec: Vec<T>) -> Self {
+        Value::Array(PhpArray::from(vec.into_iter().map(Into::into).collect::<Vec<Value>>()))
+    }
+}
+
+impl From<Object> for Value {
+    fn from(object: Object) -> Self {
+        Value::Object(object)
+    }
+}
+
+impl<T> From<Option<T>> for Value
+    where
+        T: Into<Value> {
+
+    fn from(option: Option<T>) -> Self {
+        match option {
+            Some(value) => value.into(),
+            None => Value::Null,
+        }
+    }
+}
+
+/// Converts a float to an integer, wrapping around when it is out of range.
+pub(crate) fn float_to_int(float: f64) -> i64 {
+    if !float.is_finite() {
+        return 0;
+    }
+
+    if (-9223372036854775808.0..9223372036854775808.0).contains(&float) {
+        return float as i64;
+    }
+
+    let two_pow_64 = 18446744073709551616.0;
+    let mut modulo = float % two_pow_64;
+
+    if modulo < 0.0 {
+        modulo += two_pow_64;
+    }
+
+    if modulo >= 9223372036854775808.0 {
+        modulo -= two_pow_64;
+    }
+
+    modulo as i64
+}
+
+/// Converts a float to an integer, saturating when it is out of range.
+fn float_to_int_capped(float: f64) -> i64 {
+    if float.is_nan() {
+        return 0;
+    }
+
+    float as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{Key, PhpArray};
+    use crate::string::PhpString;
+    use crate::value::{Object, Value};
+
+    #[test]
+    fn test_to_bool() {
+        assert!(!Value::Null.to_bool());
+        assert!(!Value::from(0).to_bool());
+        assert!(!Value::from(-0.0).to_bool());
+        assert!(Value::from(f64::NAN).to_bool());
+        assert!(!Value::from("").to_bool());
+        assert!(!Value::from("0").to_bool());
+        assert!(Value::from("00").to_bool());
+        assert!(Value::from(" ").to_bool());
+        assert!(!Value::from(PhpArray::new()).to_bool());
+        assert!(Value::from(vec![0]).to_bool());
+        assert!(Value::from(Object::new("stdClass")).to_bool());
+    }
+
+    #[test]
+    fn test_to_int() {
+        assert_eq!(Value::Null.to_int(), 0);
+        assert_eq!(Value::from(true).to_int(), 1);
+        assert_eq!(Value::from(-3.99).to_int(), -3);
+        assert_eq!(Value::from(f64::NAN).to_int(), 0);
+        assert_eq!(Value::from(f64::INFINITY).to_int(), 0);
+        assert_eq!(Value::from(1e19).to_int(), -8446744073709551616);
+        assert_eq!(Value::from("12abc").to_int(), 12);
+        assert_eq!(Value::from("  -7 ").to_int(), -7);
+        assert_eq!(Value::from("1.9e1").to_int(), 19);
+        assert_eq!(Value::from("1e100").to_int(), i64::MAX);
+        assert_eq!(Value::from("abc").to_int(), 0);
+        assert_eq!(Value::from(vec![1, 2]).to_int(), 1);
+    }
+
+    #[test]
+    fn test_to_float() {
+        assert_eq!(Value::from("1.5abc").to_float(), 1.5);
+        assert_eq!(Value::from(".5").to_float(), 0.5);
+        assert_eq!(Value::from("abc").to_float(), 0.0);
+        assert_eq!(Value::from(3).to_float(), 3.0);
+        assert_eq!(Value::from(true).to_float(), 1.0);
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(Value::Null.to_string(), "");
+        assert_eq!(Value::from(false).to_string(), "");
+        assert_eq!(Value::from(-12).to_string(), "-12");
+        assert_eq!(Value::from(1.0).to_string(), "1");
+        assert_eq!(Value::from(-0.0).to_string(), "-0");
+        assert_eq!(Value::from(1e25).to_string(), "1.0E+25");
+        assert_eq!(Value::from(0.00001).to_string(), "1.0E-5");
+        assert_eq!(Value::from(f64::INFINITY).to_string(), "INF");
+        assert_eq!(Value::from(vec![1]).to_string(), "Array");
+        assert_eq!(format!("{:.3}", Value::from(1.23456)), "1.23");
+        assert_eq!(Value::from(PhpString::from(&b"a\xff"[..])).to_string(), "a\u{fffd}");
+        assert_eq!(Value::from(PhpString::from(&b"a\xff"[..])).to_php_string(), b"a\xff"[..]);
+        assert_eq!(Value::from(1.5).to_php_string(), "1.5");
+    }
+
+    #[test]
+    fn test_to_key() {
+        assert_eq!(Value::Null.to_key(), Some(Key::from("")));
+        assert_eq!(Value::from(true).to_key(), Some(Key::Int(1)));
+        assert_eq!(Value::from(1.7).to_key(), Some(Key::Int(1)));
+        assert_eq!(Value::from("08").to_key(), Some(Key::from("08")));
+        assert_eq!(Value::from("8").to_key(), Some(Key::Int(8)));
+        assert_eq!(Value::from(vec![1]).to_key(), None);
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Value::from(u64::MAX), Value::Float(18446744073709551615.0));
+        assert_eq!(Value::from(Some(1)), Value::Int(1));
+        assert_eq!(Value::from(None::<i32>), Value::Null);
+        assert_eq!(Value::from(Key::from("a")), Value::from("a"));
+        assert_ne!(Value::from(1), Value::from(1.0));
+    }
+}
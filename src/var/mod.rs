@@ -0,0 +1,39 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Variable handling functions.
+//!
+//! A Value owns its contents, so an array or object cannot contain itself. The *RECURSION*
+//! markers of print_r() and var_dump() and the circular reference warning of var_export() are
+//! therefore not implemented.
+
+mod print_r;
+mod property_name;
+mod serialize;
+mod unserialize;
+mod unserialize_error;
+mod unserialize_options;
+mod var_dump;
+mod var_export;
+
+pub use print_r::print_r;
+pub use serialize::serialize;
+pub use unserialize::unserialize;
+pub use unserialize_error::UnserializeError;
+pub use unserialize_options::{AllowedClasses, UnserializeOptions};
+pub use var_dump::{var_dump, var_dump_string};
+pub use var_export::var_export;
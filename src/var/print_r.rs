@@ -0,0 +1,179 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::io::{self, Write};
+
+use crate::array::{Key, PhpArray};
+use crate::string::PhpString;
+use crate::value::{ToValue, Value};
+use crate::var::property_name::unmangle_property_name;
+
+/// Prints human-readable information about a variable
+///
+/// # Description
+///
+/// print_r() displays information about a variable in a way that's readable by humans.
+///
+/// Strings, integers and floats are printed as their string conversion. Arrays and objects are
+/// presented in a format that shows keys and elements, with private and protected properties
+/// marked as such.
+///
+/// If return is true, print_r() returns the information rather than printing it.
+///
+/// # Examples
+///
+/// Example #1 print_r() example
+///
+/// ```
+/// use phpify::array::PhpArray;
+/// use phpify::value::Value;
+/// use phpify::var::print_r;
+///
+/// let mut a = PhpArray::new();
+/// a.insert("a", Value::from("apple"));
+/// a.insert("b", Value::from("banana"));
+/// a.insert("c", Value::from(vec!["x", "y", "z"]));
+///
+/// assert_eq!(print_r(&a, true).unwrap(), "Array
+/// (
+///     [a] => apple
+///     [b] => banana
+///     [c] => Array
+///         (
+///             [0] => x
+///             [1] => y
+///             [2] => z
+///         )
+///
+/// )
+/// ");
+/// ```
+///
+/// Example #2 return parameter example
+///
+/// ```
+/// use phpify::var::print_r;
+///
+/// let b = vec!["m", "foo"];
+/// let results = print_r(&b, true);
+///
+/// assert_eq!(results.unwrap(), "Array\n(\n    [0] => m\n    [1] => foo\n)\n");
+/// assert_eq!(print_r(&b, false), None);
+/// ```
+pub fn print_r<T>(value: &T, r#return: bool) -> Option<PhpString>
+    where
+        T: ToValue + ?Sized {
+
+    let mut buffer = Vec::new();
+    print_value(&mut buffer, &value.to_value(), 0);
+
+    if r#return {
+        return Some(PhpString::from(buffer));
+    }
+
+    io::stdout().write_all(&buffer).expect("failed printing to stdout");
+
+    None
+}
+
+fn print_value(buffer: &mut Vec<u8>, value: &Value, indent: usize) {
+    match value {
+        Value::Array(array) => {
+            buffer.extend_from_slice(b"Array\n");
+            print_hash(buffer, array, indent, false);
+        },
+        Value::Object(object) => {
+            buffer.extend_from_slice(object.class_name().as_bytes());
+            buffer.extend_from_slice(b" Object\n");
+            print_hash(buffer, object.properties(), indent, true);
+        },
+        _ => buffer.extend_from_slice(&value.to_php_string()),
+    }
+}
+
+fn print_hash(buffer: &mut Vec<u8>, array: &PhpArray<Value>, indent: usize, is_object: bool) {
+    buffer.extend(std::iter::repeat_n(b' ', indent));
+    buffer.extend_from_slice(b"(\n");
+
+    for (key, value) in array {
+        buffer.extend(std::iter::repeat_n(b' ', indent + 4));
+        buffer.push(b'[');
+
+        match key {
+            Key::Int(int) => buffer.extend_from_slice(int.to_string().as_bytes()),
+            Key::Str(name) if is_object => match unmangle_property_name(name) {
+                (Some(b"*"), name) => {
+                    buffer.extend_from_slice(name);
+                    buffer.extend_from_slice(b":protected");
+                },
+                (Some(class_name), name) => {
+                    buffer.extend_from_slice(name);
+                    buffer.push(b':');
+                    buffer.extend_from_slice(class_name);
+                    buffer.extend_from_slice(b":private");
+                },
+                (None, name) => buffer.extend_from_slice(name),
+            },
+            Key::Str(name) => buffer.extend_from_slice(name),
+        }
+
+        buffer.extend_from_slice(b"] => ");
+        print_value(buffer, value, indent + 8);
+        buffer.push(b'\n');
+    }
+
+    buffer.extend(std::iter::repeat_n(b' ', indent));
+    buffer.extend_from_slice(b")\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::value::{Object, Value};
+    use crate::var::print_r;
+
+    #[test]
+    fn test() {
+        assert_eq!(print_r(&Value::Null, true), Some("".into()));
+        assert_eq!(print_r(&false, true), Some("".into()));
+        assert_eq!(print_r(&true, true), Some("1".into()));
+        assert_eq!(print_r(&0.1, true), Some("0.1".into()));
+        assert_eq!(print_r(&(1.0 / 3.0), true), Some("0.33333333333333".into()));
+        assert_eq!(print_r("abc", true), Some("abc".into()));
+        assert_eq!(print_r(&Vec::<i32>::new(), true), Some("Array\n(\n)\n".into()));
+
+        let mut object = Object::new("Foo");
+        object.set("a", Value::Null);
+        object.set("\0*\0b", vec![1]);
+        object.set("\0Foo\0c", Object::new("Bar"));
+
+        assert_eq!(print_r(&object, true).unwrap(), concat!(
+            "Foo Object\n",
+            "(\n",
+            "    [a] => \n",
+            "    [b:protected] => Array\n",
+            "        (\n",
+            "            [0] => 1\n",
+            "        )\n",
+            "\n",
+            "    [c:Foo:private] => Bar Object\n",
+            "        (\n",
+            "        )\n",
+            "\n",
+            ")\n",
+        ));
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Splits a property name into the class it is private to, or * if it is protected, and its
+/// unmangled name.
+///
+/// Private and protected properties are stored under mangled names, i.e. "\0Class\0name" and
+/// "\0*\0name".
+pub(crate) fn unmangle_property_name(name: &[u8]) -> (Option<&[u8]>, &[u8]) {
+    if let Some(mangled) = name.strip_prefix(b"\0") {
+        if let Some(separator) = mangled.iter().position(|byte| *byte == b'\0') {
+            return (Some(&mangled[..separator]), &mangled[separator + 1..]);
+        }
+    }
+
+    (None, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::var::property_name::unmangle_property_name;
+
+    #[test]
+    fn test() {
+        assert_eq!(unmangle_property_name(b"a"), (None, &b"a"[..]));
+        assert_eq!(unmangle_property_name(b"\0*\0a"), (Some(&b"*"[..]), &b"a"[..]));
+        assert_eq!(unmangle_property_name(b"\0Foo\0a"), (Some(&b"Foo"[..]), &b"a"[..]));
+        assert_eq!(unmangle_property_name(b"\0a"), (None, &b"\0a"[..]));
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{Key, PhpArray};
+use crate::string::PhpString;
+use crate::value::{gcvt, ToValue, Value};
+
+/// Generates a storable representation of a value
+///
+/// # Description
+///
+/// This is useful for storing or passing PHP values around without losing their type and
+/// structure.
+///
+/// To make the serialized string into a PHP value again, use unserialize().
+///
+/// Floats are serialized with the shortest representation that round-trips, like the default
+/// serialize_precision of -1 does. Objects created from serialized data with Object::custom() are
+/// serialized with the C: format, other objects with the O: format.
+///
+/// # Examples
+///
+/// Example #1 serialize() example
+///
+/// ```
+/// use phpify::array::PhpArray;
+/// use phpify::value::Value;
+/// use phpify::var::serialize;
+///
+/// let mut array = PhpArray::new();
+/// array.insert(0, Value::from("foo"));
+/// array.insert("bar", Value::from(1.5));
+/// array.insert("baz", Value::Null);
+///
+/// assert_eq!(serialize(&array), r#"a:3:{i:0;s:3:"foo";s:3:"bar";d:1.5;s:3:"baz";N;}"#);
+/// ```
+pub fn serialize<T>(value: &T) -> PhpString
+    where
+        T: ToValue + ?Sized {
+
+    let mut buffer = Vec::new();
+    serialize_value(&mut buffer, &value.to_value());
+    PhpString::from(buffer)
+}
+
+fn serialize_value(buffer: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::Null => buffer.extend_from_slice(b"N;"),
+        Value::Bool(bool) => {
+            buffer.extend_from_slice(if *bool { b"b:1;" } else { b"b:0;" });
+        },
+        Value::Int(int) => {
+            buffer.extend_from_slice(format!("i:{};", int).as_bytes());
+        },
+        Value::Float(float) => {
+            buffer.extend_from_slice(format!("d:{};", gcvt(*float, -1, 'E')).as_bytes());
+        },
+        Value::String(string) => serialize_string(buffer, string),
+        Value::Array(array) => {
+            buffer.extend_from_slice(format!("a:{}:", array.len()).as_bytes());
+            serialize_entries(buffer, array);
+        },
+        Value::Object(object) => match object.serialized_data() {
+            Some(data) => {
+                buffer.extend_from_slice(format!("C:{}:\"{}\":{}:{{", object.class_name().len(), object.class_name(), data.len()).as_bytes());
+                buffer.extend_from_slice(data);
+                buffer.push(b'}');
+            },
+            None => {
+                buffer.extend_from_slice(format!("O:{}:\"{}\":{}:", object.class_name().len(), object.class_name(), object.properties().len()).as_bytes());
+                serialize_entries(buffer, object.properties());
+            },
+        },
+    }
+}
+
+fn serialize_string(buffer: &mut Vec<u8>, string: &[u8]) {
+    buffer.extend_from_slice(format!("s:{}:\"", string.len()).as_bytes());
+    buffer.extend_from_slice(string);
+    buffer.extend_from_slice(b"\";");
+}
+
+fn serialize_entries(buffer: &mut Vec<u8>, array: &PhpArray<Value>) {
+    buffer.push(b'{');
+
+    for (key, value) in array {
+        match key {
+            Key::Int(int) => buffer.extend_from_slice(format!("i:{};", int).as_bytes()),
+            Key::Str(string) => serialize_string(buffer, string),
+        }
+
+        serialize_value(buffer, value);
+    }
+
+    buffer.push(b'}');
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::PhpArray;
+    use crate::string::PhpString;
+    use crate::value::{Object, Value};
+    use crate::var::serialize;
+
+    #[test]
+    fn test() {
+        assert_eq!(serialize(&Value::Null), "N;");
+        assert_eq!(serialize(&true), "b:1;");
+        assert_eq!(serialize(&false), "b:0;");
+        assert_eq!(serialize(&-5), "i:-5;");
+        assert_eq!(serialize(&0.1), "d:0.1;");
+        assert_eq!(serialize(&1.0), "d:1;");
+        assert_eq!(serialize(&1e25), "d:1.0E+25;");
+        assert_eq!(serialize(&-0.0), "d:-0;");
+        assert_eq!(serialize(&f64::INFINITY), "d:INF;");
+        assert_eq!(serialize(&f64::NAN), "d:NAN;");
+        assert_eq!(serialize("héllo"), "s:6:\"héllo\";");
+        assert_eq!(serialize(&vec![1, 2]), "a:2:{i:0;i:1;i:1;i:2;}");
+        assert_eq!(serialize(&Vec::<i32>::new()), "a:0:{}");
+        assert_eq!(serialize(&vec![vec!["a"]]), "a:1:{i:0;a:1:{i:0;s:1:\"a\";}}");
+
+        let array: PhpArray<i32> = vec![("5", 1), ("05", 2)].into_iter().collect();
+        assert_eq!(serialize(&array), "a:2:{i:5;i:1;s:2:\"05\";i:2;}");
+
+        let mut object = Object::new("Foo");
+        object.set("bar", 1);
+        assert_eq!(serialize(&object), "O:3:\"Foo\":1:{s:3:\"bar\";i:1;}");
+        assert_eq!(serialize(&Object::custom("Foo", "abc")), "C:3:\"Foo\":3:{abc}");
+
+        let binary = PhpString::from(&b"\xff\0"[..]);
+        assert_eq!(serialize(&binary), b"s:2:\"\xff\0\";"[..]);
+        assert_eq!(serialize(&vec![(binary.clone(), 1)].into_iter().collect::<PhpArray<i32>>()), b"a:1:{s:2:\"\xff\0\";i:1;}"[..]);
+    }
+}
@@ -0,0 +1,534 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::array::{Key, PhpArray};
+use crate::string::PhpString;
+use crate::value::{Object, Value};
+use crate::var::{UnserializeError, UnserializeOptions};
+
+/// The class of objects whose class is not allowed.
+const INCOMPLETE_CLASS: &str = "__PHP_Incomplete_Class";
+
+/// The property holding the original class name of incomplete objects.
+const INCOMPLETE_CLASS_NAME: &str = "__PHP_Incomplete_Class_Name";
+
+/// Creates a PHP value from a stored representation
+///
+/// # Description
+///
+/// Takes a single serialized variable and converts it back into a PHP value.
+///
+/// References (r: and R:) resolve to a copy of the value they refer to, as values are not shared.
+/// A reference to an array or object that is still being unserialized, i.e. a recursive
+/// structure, is an error.
+///
+/// Objects of classes implementing Serializable (C:) are created with Object::custom(), keeping
+/// their serialized data as is.
+///
+/// # Errors
+///
+/// Returns an UnserializeError holding the offset of the malformed input, or of the array or object
+/// that is nested deeper than the max_depth option permits.
+///
+/// # Examples
+///
+/// Example #1 unserialize() example
+///
+/// ```
+/// use phpify::array::PhpArray;
+/// use phpify::value::Value;
+/// use phpify::var::{unserialize, UnserializeOptions};
+///
+/// let value = unserialize(r#"a:2:{i:0;s:3:"foo";s:3:"bar";d:1.5;}"#, &UnserializeOptions::default()).unwrap();
+///
+/// let mut expected = PhpArray::new();
+/// expected.insert(0, Value::from("foo"));
+/// expected.insert("bar", Value::from(1.5));
+///
+/// assert_eq!(value, Value::Array(expected));
+/// ```
+///
+/// Example #2 Using allowed_classes
+///
+/// ```
+/// use phpify::value::Value;
+/// use phpify::var::{unserialize, AllowedClasses, UnserializeOptions};
+///
+/// let options = UnserializeOptions { allowed_classes: AllowedClasses::None, ..UnserializeOptions::default() };
+///
+/// match unserialize(r#"O:3:"Foo":0:{}"#, &options).unwrap() {
+///     Value::Object(object) => assert_eq!(object.class_name(), "__PHP_Incomplete_Class"),
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn unserialize<D>(data: D, options: &UnserializeOptions) -> Result<Value, UnserializeError>
+    where
+        D: AsRef<[u8]> {
+
+    let mut unserializer = Unserializer {
+        bytes: data.as_ref(),
+        position: 0,
+        slots: Vec::new(),
+        options,
+    };
+
+    unserializer.value()
+}
+
+/// An array or object whose entries are being unserialized.
+struct Container<'a> {
+    /// The class name of an object, None for an array.
+    class_name: Option<&'a str>,
+    entries: PhpArray<Value>,
+    remaining: usize,
+    key: Option<Key>,
+    slot: usize,
+}
+
+struct Unserializer<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    slots: Vec<Option<Value>>,
+    options: &'a UnserializeOptions,
+}
+
+impl<'a> Unserializer<'a> {
+    fn error(&self, offset: usize) -> UnserializeError {
+        UnserializeError::new(offset, self.bytes.len())
+    }
+
+    /// Unserializes a value, registering it and every value inside it so they can be referred to.
+    ///
+    /// The arrays and objects being unserialized are kept on a stack instead of recursing, so deeply
+    /// nested input is limited by max_depth alone.
+    fn value(&mut self) -> Result<Value, UnserializeError> {
+        let mut containers: Vec<Container<'a>> = Vec::new();
+
+        loop {
+            if let Some(container) = containers.last_mut() {
+                container.key = Some(self.key()?);
+            }
+
+            let mut value = match self.next(&mut containers)? {
+                Some(value) => value,
+                None => continue,
+            };
+
+            loop {
+                let container = match containers.last_mut() {
+                    Some(container) => container,
+                    None => return Ok(value),
+                };
+
+                let key = container.key.take().expect("the key is read before the value");
+                container.entries.insert(key, value);
+                container.remaining -= 1;
+
+                if container.remaining > 0 {
+                    break;
+                }
+
+                let container = containers.pop().expect("the container was just inspected");
+                value = self.close(container)?;
+            }
+        }
+    }
+
+    /// Unserializes a scalar, a reference or a custom object, or opens an array or object. Returns
+    /// None when an array or object with entries was opened.
+    fn next(&mut self, containers: &mut Vec<Container<'a>>) -> Result<Option<Value>, UnserializeError> {
+        let start = self.position;
+
+        if self.bytes.get(start) == Some(&b'R') {
+            return self.reference().map(Some);
+        }
+
+        self.slots.push(None);
+        let slot = self.slots.len() - 1;
+
+        let value = match self.bytes.get(start) {
+            Some(b'N') => {
+                let mut cursor = start;
+                self.expect(&mut cursor, b"N;").ok_or_else(|| self.error(start))?;
+                self.position = cursor;
+                Value::Null
+            },
+            Some(b'b') => {
+                let mut cursor = start;
+                self.expect(&mut cursor, b"b:").ok_or_else(|| self.error(start))?;
+                let bool = match self.bytes.get(cursor) {
+                    Some(b'0') => false,
+                    Some(b'1') => true,
+                    _ => return Err(self.error(start)),
+                };
+                cursor += 1;
+                self.expect(&mut cursor, b";").ok_or_else(|| self.error(start))?;
+                self.position = cursor;
+                Value::Bool(bool)
+            },
+            Some(b'i') => Value::Int(self.int()?),
+            Some(b'd') => Value::Float(self.float()?),
+            Some(b's') => Value::String(self.string()?),
+            Some(b'r') => self.reference()?,
+            Some(b'a') | Some(b'O') => {
+                let (class_name, count) = self.container_header()?;
+
+                if containers.len() >= self.options.max_depth {
+                    return Err(self.error(self.position));
+                }
+
+                let container = Container { class_name, entries: PhpArray::new(), remaining: count, key: None, slot };
+
+                if count > 0 {
+                    containers.push(container);
+                    return Ok(None);
+                }
+
+                return self.close(container).map(Some);
+            },
+            Some(b'C') => Value::Object(self.custom_object()?),
+            _ => return Err(self.error(start)),
+        };
+
+        self.slots[slot] = Some(value.clone());
+
+        Ok(Some(value))
+    }
+
+    /// Unserializes an array key, which cannot be referred to.
+    fn key(&mut self) -> Result<Key, UnserializeError> {
+        match self.bytes.get(self.position) {
+            Some(b'i') => Ok(Key::Int(self.int()?)),
+            Some(b's') => Ok(Key::from(self.string()?)),
+            _ => Err(self.error(self.position)),
+        }
+    }
+
+    fn int(&mut self) -> Result<i64, UnserializeError> {
+        let start = self.position;
+        let mut cursor = start;
+        self.expect(&mut cursor, b"i:").ok_or_else(|| self.error(start))?;
+        let number = self.until(&mut cursor, b';').ok_or_else(|| self.error(start))?;
+
+        let digits = number.strip_prefix(|c| c == '-' || c == '+').unwrap_or(number);
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(self.error(start));
+        }
+
+        let int = number.parse().map_err(|_| self.error(start))?;
+        self.position = cursor;
+
+        Ok(int)
+    }
+
+    fn float(&mut self) -> Result<f64, UnserializeError> {
+        let start = self.position;
+        let mut cursor = start;
+        self.expect(&mut cursor, b"d:").ok_or_else(|| self.error(start))?;
+        let number = self.until(&mut cursor, b';').ok_or_else(|| self.error(start))?;
+
+        let float = match number {
+            "NAN" => f64::NAN,
+            "INF" => f64::INFINITY,
+            "-INF" => f64::NEG_INFINITY,
+            _ if !number.is_empty() && number.bytes().all(|byte| b"0123456789.eE+-".contains(&byte)) => {
+                number.parse().map_err(|_| self.error(start))?
+            },
+            _ => return Err(self.error(start)),
+        };
+
+        self.position = cursor;
+
+        Ok(float)
+    }
+
+    fn string(&mut self) -> Result<PhpString, UnserializeError> {
+        let start = self.position;
+        let mut cursor = start;
+        self.expect(&mut cursor, b"s:").ok_or_else(|| self.error(start))?;
+        let length = self.length(&mut cursor).ok_or_else(|| self.error(start))?;
+        self.expect(&mut cursor, b":\"").ok_or_else(|| self.error(start))?;
+
+        if length > self.bytes.len() - cursor {
+            return Err(self.error(start + 2));
+        }
+
+        let end = cursor + length;
+        let string = match self.bytes.get(cursor..end) {
+            Some(string) if self.bytes.get(end) == Some(&b'"') => string,
+            _ => return Err(self.error(end)),
+        };
+
+        if self.bytes.get(end + 1) != Some(&b';') {
+            return Err(self.error(end + 1));
+        }
+
+        self.position = end + 2;
+
+        Ok(PhpString::from(string))
+    }
+
+    fn reference(&mut self) -> Result<Value, UnserializeError> {
+        let start = self.position;
+        let mut cursor = start + 1;
+        self.expect(&mut cursor, b":").ok_or_else(|| self.error(start))?;
+        let id = self.length(&mut cursor).ok_or_else(|| self.error(start))?;
+        self.expect(&mut cursor, b";").ok_or_else(|| self.error(start))?;
+
+        let value = match id.checked_sub(1).and_then(|id| self.slots.get(id)) {
+            Some(Some(value)) => value.clone(),
+            _ => return Err(self.error(start)),
+        };
+
+        self.position = cursor;
+
+        Ok(value)
+    }
+
+    /// Parses the header of an array or object up to and including the opening brace, returning
+    /// the class name of an object and the number of entries.
+    fn container_header(&mut self) -> Result<(Option<&'a str>, usize), UnserializeError> {
+        let start = self.position;
+        let mut cursor = start;
+
+        let class_name = if self.bytes[start] == b'O' {
+            self.expect(&mut cursor, b"O:").ok_or_else(|| self.error(start))?;
+            let class_name = self.class_name(&mut cursor).ok_or_else(|| self.error(start))?;
+            self.expect(&mut cursor, b":").ok_or_else(|| self.error(start))?;
+            Some(class_name)
+        } else {
+            self.expect(&mut cursor, b"a:").ok_or_else(|| self.error(start))?;
+            None
+        };
+
+        let count = self.length(&mut cursor).ok_or_else(|| self.error(start))?;
+        self.expect(&mut cursor, b":{").ok_or_else(|| self.error(start))?;
+        self.position = cursor;
+
+        Ok((class_name, count))
+    }
+
+    /// Finishes an array or object at its closing brace, registering it so it can be referred to.
+    fn close(&mut self, container: Container<'a>) -> Result<Value, UnserializeError> {
+        if self.bytes.get(self.position) != Some(&b'}') {
+            return Err(self.error(self.position));
+        }
+
+        self.position += 1;
+
+        let value = match container.class_name {
+            None => Value::Array(container.entries),
+            Some(class_name) if self.options.allowed_classes.allows(class_name) => {
+                Value::Object(Object::with_properties(class_name, container.entries))
+            },
+            Some(class_name) => {
+                let mut object = Object::new(INCOMPLETE_CLASS);
+                object.set(INCOMPLETE_CLASS_NAME, class_name);
+                object.properties_mut().extend(container.entries);
+                Value::Object(object)
+            },
+        };
+
+        self.slots[container.slot] = Some(value.clone());
+
+        Ok(value)
+    }
+
+    fn custom_object(&mut self) -> Result<Object, UnserializeError> {
+        let start = self.position;
+        let mut cursor = start;
+        self.expect(&mut cursor, b"C:").ok_or_else(|| self.error(start))?;
+        let class_name = self.class_name(&mut cursor).ok_or_else(|| self.error(start))?;
+        self.expect(&mut cursor, b":").ok_or_else(|| self.error(start))?;
+        let length = self.length(&mut cursor).ok_or_else(|| self.error(start))?;
+        self.expect(&mut cursor, b":{").ok_or_else(|| self.error(start))?;
+
+        let end = cursor.checked_add(length).filter(|end| *end < self.bytes.len()).ok_or_else(|| self.error(start))?;
+        if self.bytes[end] != b'}' {
+            return Err(self.error(start));
+        }
+        let data = &self.bytes[cursor..end];
+
+        self.position = end + 1;
+
+        if self.options.allowed_classes.allows(class_name) {
+            return Ok(Object::custom(class_name, data));
+        }
+
+        let mut object = Object::new(INCOMPLETE_CLASS);
+        object.set(INCOMPLETE_CLASS_NAME, class_name);
+
+        Ok(object)
+    }
+
+    /// Parses a quoted class name, preceded by its length.
+    fn class_name(&self, cursor: &mut usize) -> Option<&'a str> {
+        let length = self.length(cursor)?;
+        self.expect(cursor, b":\"")?;
+
+        let end = cursor.checked_add(length)?;
+        let class_name = std::str::from_utf8(self.bytes.get(*cursor..end)?).ok()?;
+
+        let valid = |byte: u8| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'\\' || byte >= 0x80;
+        if class_name.is_empty() || !class_name.bytes().all(valid) {
+            return None;
+        }
+
+        *cursor = end;
+        self.expect(cursor, b"\"")?;
+
+        Some(class_name)
+    }
+
+    /// Parses an unsigned decimal number.
+    fn length(&self, cursor: &mut usize) -> Option<usize> {
+        let digits = self.bytes[*cursor..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+        let length = std::str::from_utf8(&self.bytes[*cursor..*cursor + digits]).ok()?.parse().ok()?;
+        *cursor += digits;
+
+        Some(length)
+    }
+
+    /// Returns the text up to the terminator, moving the cursor past the terminator.
+    fn until(&self, cursor: &mut usize, terminator: u8) -> Option<&'a str> {
+        let length = self.bytes[*cursor..].iter().position(|byte| *byte == terminator)?;
+        let text = std::str::from_utf8(&self.bytes[*cursor..*cursor + length]).ok()?;
+        *cursor += length + 1;
+
+        Some(text)
+    }
+
+    fn expect(&self, cursor: &mut usize, expected: &[u8]) -> Option<()> {
+        if !self.bytes[*cursor..].starts_with(expected) {
+            return None;
+        }
+
+        *cursor += expected.len();
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::PhpArray;
+    use crate::string::PhpString;
+    use crate::value::{Object, Value};
+    use crate::var::{serialize, unserialize, AllowedClasses, UnserializeOptions};
+
+    fn parse<D: AsRef<[u8]>>(data: D) -> Result<Value, String> {
+        unserialize(data, &UnserializeOptions::default()).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(parse("N;"), Ok(Value::Null));
+        assert_eq!(parse("b:1;"), Ok(Value::Bool(true)));
+        assert_eq!(parse("i:-42;"), Ok(Value::Int(-42)));
+        assert_eq!(parse("d:0.5;"), Ok(Value::Float(0.5)));
+        assert_eq!(parse("d:1.0E+25;"), Ok(Value::Float(1e25)));
+        assert_eq!(parse("d:-INF;"), Ok(Value::Float(f64::NEG_INFINITY)));
+        assert!(matches!(parse("d:NAN;"), Ok(Value::Float(float)) if float.is_nan()));
+        assert_eq!(parse("s:6:\"héllo\";"), Ok(Value::from("héllo")));
+        assert_eq!(parse("s:0:\"\";"), Ok(Value::from("")));
+        let array: PhpArray<Value> = vec![(0, Value::from(1)), (5, Value::from(2))].into_iter().collect();
+        assert_eq!(parse("a:2:{i:0;i:1;s:1:\"5\";i:2;}"), Ok(Value::Array(array)));
+        assert_eq!(parse("a:2:{i:0;a:1:{i:0;i:7;}i:1;r:2;}"), Ok(Value::from(vec![vec![7], vec![7]])));
+        assert_eq!(parse("a:2:{i:0;s:1:\"a\";i:1;r:2;}"), Ok(Value::from(vec!["a", "a"])));
+        assert_eq!(parse("a:2:{i:0;i:5;i:1;R:2;}"), Ok(Value::from(vec![5, 5])));
+        let expected = Value::from(vec![Value::from(5), Value::from(5), Value::from("x"), Value::from("x")]);
+        assert_eq!(parse("a:4:{i:0;i:5;i:1;R:2;i:2;s:1:\"x\";i:3;r:3;}"), Ok(expected));
+
+        let mut object = Object::new("Foo");
+        object.set("bar", vec![1]);
+        assert_eq!(parse(serialize(&object)), Ok(Value::Object(object)));
+        assert_eq!(parse("C:3:\"Foo\":6:{a:0:{}}"), Ok(Value::Object(Object::custom("Foo", "a:0:{}"))));
+
+        let value = Value::from(vec![Value::from(1.5), Value::from("x"), Value::from(vec![true])]);
+        assert_eq!(parse(serialize(&value)), Ok(value));
+
+        let binary = Value::from(PhpString::from(&b"\xff\0\xfe"[..]));
+        assert_eq!(parse(b"s:3:\"\xff\0\xfe\";"), Ok(binary.clone()));
+        assert_eq!(parse(serialize(&vec![binary.clone()])), Ok(Value::from(vec![binary])));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse(""), Err("Error at offset 0 of 0 bytes".to_string()));
+        assert_eq!(parse("x"), Err("Error at offset 0 of 1 bytes".to_string()));
+        assert_eq!(parse("i:1"), Err("Error at offset 0 of 3 bytes".to_string()));
+        assert_eq!(parse("i:1a;"), Err("Error at offset 0 of 5 bytes".to_string()));
+        assert_eq!(parse("i:99999999999999999999;"), Err("Error at offset 0 of 23 bytes".to_string()));
+        assert_eq!(parse("b:2;"), Err("Error at offset 0 of 4 bytes".to_string()));
+        assert_eq!(parse("s:9:\"abc\";"), Err("Error at offset 2 of 10 bytes".to_string()));
+        assert_eq!(parse("s:5:\"abc\";"), Err("Error at offset 10 of 10 bytes".to_string()));
+        assert_eq!(parse("s:2:\"abc\";"), Err("Error at offset 7 of 10 bytes".to_string()));
+        assert_eq!(parse("s:3:\"abc\"x"), Err("Error at offset 9 of 10 bytes".to_string()));
+        assert_eq!(parse("a:1:{i:0;x}"), Err("Error at offset 9 of 11 bytes".to_string()));
+        assert_eq!(parse("a:2:{i:0;i:1;}"), Err("Error at offset 13 of 14 bytes".to_string()));
+        assert_eq!(parse("a:1:{i:0;i:1;i:1;i:2;}"), Err("Error at offset 13 of 22 bytes".to_string()));
+        assert_eq!(parse("a:1:{d:0;i:1;}"), Err("Error at offset 5 of 14 bytes".to_string()));
+        assert_eq!(parse("a:1:{i:0;r:1;}"), Err("Error at offset 9 of 14 bytes".to_string()));
+        assert_eq!(parse("r:1;"), Err("Error at offset 0 of 4 bytes".to_string()));
+        assert_eq!(parse("O:3:\"F-o\":0:{}"), Err("Error at offset 0 of 14 bytes".to_string()));
+    }
+
+    #[test]
+    fn test_options() {
+        let options = UnserializeOptions {
+            allowed_classes: AllowedClasses::Only(vec!["foo".to_string()]),
+            max_depth: 2,
+        };
+
+        let mut expected = Object::new("Foo");
+        expected.set("a", 1);
+        assert_eq!(unserialize("O:3:\"Foo\":1:{s:1:\"a\";i:1;}", &options), Ok(Value::Object(expected)));
+
+        let mut expected = Object::new("__PHP_Incomplete_Class");
+        expected.set("__PHP_Incomplete_Class_Name", "Bar");
+        expected.set("a", 1);
+        assert_eq!(unserialize("O:3:\"Bar\":1:{s:1:\"a\";i:1;}", &options), Ok(Value::Object(expected)));
+
+        let mut expected = Object::new("__PHP_Incomplete_Class");
+        expected.set("__PHP_Incomplete_Class_Name", "Bar");
+        assert_eq!(unserialize("C:3:\"Bar\":2:{xy}", &options), Ok(Value::Object(expected)));
+
+        assert!(unserialize("a:1:{i:0;a:0:{}}", &options).is_ok());
+        assert_eq!(unserialize("a:1:{i:0;a:1:{i:0;a:0:{}}}", &options).map_err(|error| error.offset()), Err(23));
+
+        let options = UnserializeOptions { max_depth: 0, ..options };
+        assert_eq!(unserialize("i:1;", &options), Ok(Value::Int(1)));
+        assert_eq!(unserialize("a:0:{}", &options).map_err(|error| error.offset()), Err(5));
+    }
+
+    #[test]
+    fn test_depth() {
+        let nested = |depth: usize| "a:1:{i:0;".repeat(depth) + "N;" + &"}".repeat(depth);
+
+        let mut expected = Value::Null;
+        for _ in 0..512 {
+            expected = Value::from(vec![expected]);
+        }
+        assert_eq!(parse(nested(512)), Ok(expected));
+
+        assert_eq!(parse(nested(513)).map_err(|_| ()), Err(()));
+        assert_eq!(parse(nested(100_000)).map_err(|_| ()), Err(()));
+
+        let options = UnserializeOptions { max_depth: 100_000, ..UnserializeOptions::default() };
+        assert_eq!(unserialize("a:1:{i:0;".repeat(100_000) + "x;", &options).map_err(|error| error.offset()), Err(900_000));
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::error::Error;
+use std::fmt;
+
+/// The error returned by unserialize() for malformed input.
+///
+/// # Description
+///
+/// Holds the byte offset at which the input could not be parsed and the length of the input, like
+/// the notice PHP raises.
+///
+/// # Examples
+///
+/// Example #1 UnserializeError example
+///
+/// ```
+/// use phpify::var::{unserialize, UnserializeOptions};
+///
+/// let error = unserialize("s:9:\"abc\";", &UnserializeOptions::default()).unwrap_err();
+///
+/// assert_eq!(error.offset(), 2);
+/// assert_eq!(error.to_string(), "Error at offset 2 of 10 bytes");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnserializeError {
+    offset: usize,
+    length: usize,
+}
+
+impl UnserializeError {
+    pub(crate) fn new(offset: usize, length: usize) -> Self {
+        UnserializeError { offset, length }
+    }
+
+    /// Returns the byte offset at which the input could not be parsed.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the length of the input in bytes.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+}
+
+impl fmt::Display for UnserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Error at offset {} of {} bytes", self.offset, self.length)
+    }
+}
+
+impl Error for UnserializeError {}
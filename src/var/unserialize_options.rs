@@ -0,0 +1,90 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// The classes unserialize() may create objects of.
+///
+/// # Description
+///
+/// Objects of classes that are not allowed are created as objects of the class
+/// __PHP_Incomplete_Class instead. Class names are matched case-insensitively.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub enum AllowedClasses {
+    /// All classes are allowed.
+    #[default]
+    All,
+
+    /// No classes are allowed.
+    None,
+
+    /// Only the listed classes are allowed.
+    Only(Vec<String>),
+}
+
+impl AllowedClasses {
+    /// Returns true if objects of the class may be created.
+    pub fn allows(&self, class_name: &str) -> bool {
+        match self {
+            AllowedClasses::All => true,
+            AllowedClasses::None => false,
+            AllowedClasses::Only(class_names) => {
+                class_names.iter().any(|allowed| allowed.eq_ignore_ascii_case(class_name))
+            },
+        }
+    }
+}
+
+/// The options of unserialize().
+///
+/// # Description
+///
+/// - allowed_classes - the classes that may be unserialized, all classes by default. Passing
+///   AllowedClasses::None is recommended when unserializing untrusted input.
+/// - max_depth - the maximum depth of structures permitted during unserialization, 512 by
+///   default. Nested arrays and objects deeper than this are an error. Unlike PHP, which defaults
+///   to 4096 and takes 0 to disable the limit, a depth of 0 permits no arrays or objects at all:
+///   values are cloned, compared and dropped recursively, so the depth of untrusted input must be
+///   bounded for the stack to hold it.
+///
+/// # Examples
+///
+/// Example #1 UnserializeOptions example
+///
+/// ```
+/// use phpify::var::{AllowedClasses, UnserializeOptions};
+///
+/// let options = UnserializeOptions {
+///     allowed_classes: AllowedClasses::Only(vec!["Foo".to_string()]),
+///     ..UnserializeOptions::default()
+/// };
+///
+/// assert!(options.allowed_classes.allows("foo"));
+/// assert!(!options.allowed_classes.allows("Bar"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnserializeOptions {
+    pub allowed_classes: AllowedClasses,
+    pub max_depth: usize,
+}
+
+impl Default for UnserializeOptions {
+    fn default() -> Self {
+        UnserializeOptions {
+            allowed_classes: AllowedClasses::All,
+            max_depth: 512,
+        }
+    }
+}
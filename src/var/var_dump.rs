@@ -0,0 +1,227 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::io::{self, Write};
+
+use crate::array::{Key, PhpArray};
+use crate::string::PhpString;
+use crate::value::{gcvt, ToValue, Value};
+use crate::var::property_name::unmangle_property_name;
+
+/// Dumps information about a variable
+///
+/// # Description
+///
+/// This function displays structured information about one or more expressions that includes its
+/// type and value. Arrays and objects are explored recursively with values indented to show
+/// structure.
+///
+/// All public, private and protected properties of objects will be returned in the output. As
+/// objects have no identity, each object is numbered in order of appearance.
+///
+/// # Examples
+///
+/// Example #1 var_dump() example
+///
+/// ```
+/// use phpify::value::Value;
+/// use phpify::var::var_dump;
+///
+/// let a = Value::from(vec![Value::from(1), Value::from(2), Value::from(vec!["a", "b", "c"])]);
+/// var_dump(&a);
+/// ```
+pub fn var_dump<T>(value: &T)
+    where
+        T: ToValue + ?Sized {
+
+    io::stdout().write_all(&var_dump_string(value)).expect("failed printing to stdout");
+}
+
+/// Returns what var_dump() outputs
+///
+/// # Description
+///
+/// Renders the value exactly like var_dump() does, for comparing with output produced by PHP.
+///
+/// # Examples
+///
+/// Example #1 var_dump_string() example
+///
+/// ```
+/// use phpify::value::Value;
+/// use phpify::var::var_dump_string;
+///
+/// let a = Value::from(vec![Value::from(1), Value::from(2), Value::from(vec!["a", "b", "c"])]);
+///
+/// assert_eq!(var_dump_string(&a), r#"array(3) {
+///   [0]=>
+///   int(1)
+///   [1]=>
+///   int(2)
+///   [2]=>
+///   array(3) {
+///     [0]=>
+///     string(1) "a"
+///     [1]=>
+///     string(1) "b"
+///     [2]=>
+///     string(1) "c"
+///   }
+/// }
+/// "#);
+///
+/// assert_eq!(var_dump_string(&3.1), "float(3.1)\n");
+/// assert_eq!(var_dump_string(&1e25), "float(1.0E+25)\n");
+/// assert_eq!(var_dump_string(&true), "bool(true)\n");
+/// ```
+pub fn var_dump_string<T>(value: &T) -> PhpString
+    where
+        T: ToValue + ?Sized {
+
+    let mut buffer = Vec::new();
+    let mut object_id = 0;
+
+    dump(&mut buffer, &value.to_value(), 1, &mut object_id);
+
+    PhpString::from(buffer)
+}
+
+fn dump(buffer: &mut Vec<u8>, value: &Value, level: usize, object_id: &mut usize) {
+    if level > 1 {
+        indent(buffer, level - 1);
+    }
+
+    match value {
+        Value::Null => buffer.extend_from_slice(b"NULL\n"),
+        Value::Bool(bool) => buffer.extend_from_slice(format!("bool({})\n", bool).as_bytes()),
+        Value::Int(int) => buffer.extend_from_slice(format!("int({})\n", int).as_bytes()),
+        Value::Float(float) => buffer.extend_from_slice(format!("float({})\n", gcvt(*float, -1, 'E')).as_bytes()),
+        Value::String(string) => {
+            buffer.extend_from_slice(format!("string({}) \"", string.len()).as_bytes());
+            buffer.extend_from_slice(string);
+            buffer.extend_from_slice(b"\"\n");
+        },
+        Value::Array(array) => {
+            buffer.extend_from_slice(format!("array({}) {{\n", array.len()).as_bytes());
+            dump_entries(buffer, array, level, false, object_id);
+        },
+        Value::Object(object) => {
+            *object_id += 1;
+            buffer.extend_from_slice(format!("object({})#{} ({}) {{\n", object.class_name(), object_id, object.properties().len()).as_bytes());
+            dump_entries(buffer, object.properties(), level, true, object_id);
+        },
+    }
+}
+
+fn dump_entries(buffer: &mut Vec<u8>, array: &PhpArray<Value>, level: usize, is_object: bool, object_id: &mut usize) {
+    for (key, value) in array {
+        indent(buffer, level + 1);
+
+        match key {
+            Key::Int(int) => buffer.extend_from_slice(format!("[{}]=>\n", int).as_bytes()),
+            Key::Str(name) if is_object => match unmangle_property_name(name) {
+                (Some(b"*"), name) => dump_name(buffer, &[name], b":protected"),
+                (Some(class_name), name) => dump_name(buffer, &[name, class_name], b":private"),
+                (None, name) => dump_name(buffer, &[name], b""),
+            },
+            Key::Str(name) => dump_name(buffer, &[name], b""),
+        }
+
+        dump(buffer, value, level + 2, object_id);
+    }
+
+    if level > 1 {
+        indent(buffer, level - 1);
+    }
+
+    buffer.extend_from_slice(b"}\n");
+}
+
+/// Appends a key like ["name":"Class":private]=>, quoting each part.
+fn dump_name(buffer: &mut Vec<u8>, parts: &[&[u8]], suffix: &[u8]) {
+    buffer.push(b'[');
+
+    for (index, part) in parts.iter().enumerate() {
+        if index > 0 {
+            buffer.push(b':');
+        }
+        buffer.push(b'"');
+        buffer.extend_from_slice(part);
+        buffer.push(b'"');
+    }
+
+    buffer.extend_from_slice(suffix);
+    buffer.extend_from_slice(b"]=>\n");
+}
+
+fn indent(buffer: &mut Vec<u8>, spaces: usize) {
+    buffer.extend(std::iter::repeat_n(b' ', spaces));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::PhpArray;
+    use crate::string::PhpString;
+    use crate::value::{Object, Value};
+    use crate::var::var_dump_string;
+
+    #[test]
+    fn test() {
+        assert_eq!(var_dump_string(&Value::Null), "NULL\n");
+        assert_eq!(var_dump_string(&false), "bool(false)\n");
+        assert_eq!(var_dump_string(&-1), "int(-1)\n");
+        assert_eq!(var_dump_string(&1.0), "float(1)\n");
+        assert_eq!(var_dump_string(&0.1), "float(0.1)\n");
+        assert_eq!(var_dump_string(&-0.0), "float(-0)\n");
+        assert_eq!(var_dump_string(&f64::NAN), "float(NAN)\n");
+        assert_eq!(var_dump_string(&f64::NEG_INFINITY), "float(-INF)\n");
+        assert_eq!(var_dump_string("h\u{e9}"), "string(3) \"h\u{e9}\"\n");
+        assert_eq!(var_dump_string(&PhpArray::<Value>::new()), "array(0) {\n}\n");
+        assert_eq!(var_dump_string(&PhpString::from(&b"\xff"[..])), b"string(1) \"\xff\"\n"[..]);
+
+        let mut array: PhpArray<Value> = PhpArray::new();
+        array.insert("a", Value::from(vec![Value::Null]));
+        array.insert(5, Value::from(PhpArray::new()));
+        assert_eq!(var_dump_string(&array), "array(2) {\n  [\"a\"]=>\n  array(1) {\n    [0]=>\n    NULL\n  }\n  [5]=>\n  array(0) {\n  }\n}\n");
+
+        let mut inner = Object::new("Bar");
+        inner.set(0, true);
+
+        let mut object = Object::new("Foo");
+        object.set("public", 1);
+        object.set("\0*\0protected", 2);
+        object.set("\0Foo\0private", inner);
+        object.set("other", Object::new("stdClass"));
+
+        assert_eq!(var_dump_string(&object), concat!(
+            "object(Foo)#1 (4) {\n",
+            "  [\"public\"]=>\n",
+            "  int(1)\n",
+            "  [\"protected\":protected]=>\n",
+            "  int(2)\n",
+            "  [\"private\":\"Foo\":private]=>\n",
+            "  object(Bar)#2 (1) {\n",
+            "    [0]=>\n",
+            "    bool(true)\n",
+            "  }\n",
+            "  [\"other\"]=>\n",
+            "  object(stdClass)#3 (0) {\n",
+            "  }\n",
+            "}\n",
+        ));
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright (c) 2020 DarkWeb Design
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::io::{self, Write};
+
+use crate::array::{Key, PhpArray};
+use crate::string::PhpString;
+use crate::value::{gcvt, ToValue, Value};
+use crate::var::property_name::unmangle_property_name;
+
+/// Outputs or returns a parsable string representation of a variable
+///
+/// # Description
+///
+/// var_export() gets structured information about the given variable. It is similar to var_dump()
+/// with one exception: the returned representation is valid PHP code.
+///
+/// Objects of the class stdClass are exported as an array cast to object, objects of other classes
+/// as a call to their __set_state() method.
+///
+/// If return is true, var_export() returns the variable representation instead of outputting it.
+///
+/// # Examples
+///
+/// Example #1 var_export() Examples
+///
+/// ```
+/// use phpify::value::Value;
+/// use phpify::var::var_export;
+///
+/// let a = Value::from(vec![Value::from(1), Value::from(2), Value::from(vec!["a", "b", "c"])]);
+///
+/// assert_eq!(var_export(&a, true).unwrap(), "array (
+///   0 => 1,
+///   1 => 2,
+///   2 => 
+///   array (
+///     0 => 'a',
+///     1 => 'b',
+///     2 => 'c',
+///   ),
+/// )");
+/// ```
+///
+/// Example #2 Exporting floats
+///
+/// ```
+/// use phpify::var::var_export;
+///
+/// assert_eq!(var_export(&1.0, true).unwrap(), "1.0");
+/// ```
+pub fn var_export<T>(value: &T, r#return: bool) -> Option<PhpString>
+    where
+        T: ToValue + ?Sized {
+
+    let mut buffer = Vec::new();
+    export(&mut buffer, &value.to_value(), 1);
+
+    if r#return {
+        return Some(PhpString::from(buffer));
+    }
+
+    io::stdout().write_all(&buffer).expect("failed printing to stdout");
+
+    None
+}
+
+fn export(buffer: &mut Vec<u8>, value: &Value, level: usize) {
+    match value {
+        Value::Null => buffer.extend_from_slice(b"NULL"),
+        Value::Bool(bool) => buffer.extend_from_slice(if *bool { b"true" } else { b"false" }),
+        Value::Int(int) if *int == i64::MIN => {
+            buffer.extend_from_slice(format!("{}-1", i64::MIN + 1).as_bytes());
+        },
+        Value::Int(int) => buffer.extend_from_slice(int.to_string().as_bytes()),
+        Value::Float(float) => {
+            let formatted = gcvt(*float, -1, 'E');
+            buffer.extend_from_slice(formatted.as_bytes());
+
+            if float.is_finite() && !formatted.contains('.') {
+                buffer.extend_from_slice(b".0");
+            }
+        },
+        Value::String(string) => export_string(buffer, string),
+        Value::Array(array) => {
+            if level > 1 {
+                buffer.push(b'\n');
+                indent(buffer, level - 1);
+            }
+
+            buffer.extend_from_slice(b"array (\n");
+
+            for (key, value) in array {
+                indent(buffer, level + 1);
+                export_key(buffer, key);
+                buffer.extend_from_slice(b" => ");
+                export(buffer, value, level + 2);
+                buffer.extend_from_slice(b",\n");
+            }
+
+            if level > 1 {
+                indent(buffer, level - 1);
+            }
+
+            buffer.push(b')');
+        },
+        Value::Object(object) => {
+            if level > 1 {
+                buffer.push(b'\n');
+                indent(buffer, level - 1);
+            }
+
+            let is_std_class = object.class_name() == "stdClass";
+
+            if is_std_class {
+                buffer.extend_from_slice(b"(object) array(\n");
+            } else {
+                buffer.push(b'\\');
+                buffer.extend_from_slice(object.class_name().as_bytes());
+                buffer.extend_from_slice(b"::__set_state(array(\n");
+            }
+
+            export_properties(buffer, object.properties(), level);
+
+            if level > 1 {
+                indent(buffer, level - 1);
+            }
+
+            buffer.extend_from_slice(if is_std_class { b")" } else { b"))" });
+        },
+    }
+}
+
+fn export_properties(buffer: &mut Vec<u8>, properties: &PhpArray<Value>, level: usize) {
+    for (key, value) in properties {
+        indent(buffer, level + 2);
+
+        match key {
+            Key::Int(int) => buffer.extend_from_slice(int.to_string().as_bytes()),
+            Key::Str(name) => {
+                buffer.push(b'\'');
+                escape(buffer, unmangle_property_name(name).1);
+                buffer.push(b'\'');
+            },
+        }
+
+        buffer.extend_from_slice(b" => ");
+        export(buffer, value, level + 2);
+        buffer.extend_from_slice(b",\n");
+    }
+}
+
+fn export_key(buffer: &mut Vec<u8>, key: &Key) {
+    match key {
+        Key::Int(int) => buffer.extend_from_slice(int.to_string().as_bytes()),
+        Key::Str(string) => export_string(buffer, string),
+    }
+}
+
+/// Exports a string as a single quoted literal, concatenating NUL bytes as "\0".
+fn export_string(buffer: &mut Vec<u8>, string: &[u8]) {
+    buffer.push(b'\'');
+
+    for (index, part) in string.split(|byte| *byte == b'\0').enumerate() {
+        if index > 0 {
+            buffer.extend_from_slice(b"' . \"\\0\" . '");
+        }
+        escape(buffer, part);
+    }
+
+    buffer.push(b'\'');
+}
+
+/// Appends the string with backslashes and single quotes escaped.
+fn escape(buffer: &mut Vec<u8>, string: &[u8]) {
+    for byte in string {
+        if *byte == b'\\' || *byte == b'\'' {
+            buffer.push(b'\\');
+        }
+        buffer.push(*byte);
+    }
+}
+
+fn indent(buffer: &mut Vec<u8>, spaces: usize) {
+    buffer.extend(std::iter::repeat_n(b' ', spaces));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::PhpArray;
+    use crate::string::PhpString;
+    use crate::value::{Object, Value};
+    use crate::var::var_export;
+
+    #[test]
+    fn test() {
+        assert_eq!(var_export(&Value::Null, true), Some("NULL".into()));
+        assert_eq!(var_export(&false, true), Some("false".into()));
+        assert_eq!(var_export(&i64::MIN, true), Some("-9223372036854775807-1".into()));
+        assert_eq!(var_export(&0.1, true), Some("0.1".into()));
+        assert_eq!(var_export(&-0.0, true), Some("-0.0".into()));
+        assert_eq!(var_export(&1e25, true), Some("1.0E+25".into()));
+        assert_eq!(var_export(&f64::INFINITY, true), Some("INF".into()));
+        assert_eq!(var_export(&f64::NAN, true), Some("NAN".into()));
+        assert_eq!(var_export("it's a \\ test", true), Some("'it\\'s a \\\\ test'".into()));
+        assert_eq!(var_export("a\0b", true), Some("'a' . \"\\0\" . 'b'".into()));
+        assert_eq!(var_export(&PhpString::from(&b"\xff'"[..]), true), Some(b"'\xff\\''"[..].into()));
+        assert_eq!(var_export(&Vec::<i32>::new(), true), Some("array (\n)".into()));
+
+        let mut array: PhpArray<Value> = PhpArray::new();
+        array.insert("it's", Value::from(1));
+        assert_eq!(var_export(&array, true), Some("array (\n  'it\\'s' => 1,\n)".into()));
+
+        let mut object = Object::new("stdClass");
+        object.set("a", 1);
+        object.set("\0*\0b", Object::new("Foo"));
+
+        let value = Value::from(vec![Value::Object(object)]);
+        assert_eq!(var_export(&value, true).unwrap(), concat!(
+            "array (\n",
+            "  0 => \n",
+            "  (object) array(\n",
+            "     'a' => 1,\n",
+            "     'b' => \n",
+            "    \\Foo::__set_state(array(\n",
+            "    )),\n",
+            "  ),\n",
+            ")",
+        ));
+    }
+}